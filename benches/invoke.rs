@@ -0,0 +1,101 @@
+use blob_event::Event;
+use std::hint::black_box;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const ITERATIONS: u32 = 200_000;
+
+fn bench(name: &str, subscribers: usize) {
+    let event = event_with(subscribers);
+
+    // Warm up so the event has reached its steady state.
+    for i in 0..1_000 {
+        event.invoke(i);
+    }
+
+    let start = Instant::now();
+    for i in 0..ITERATIONS as u64 {
+        event.invoke(black_box(i));
+    }
+    let elapsed = start.elapsed();
+
+    report(name, elapsed);
+}
+
+// Delivers the same number of items as `bench`, but in batches of `batch_size`.
+fn bench_batch(name: &str, subscribers: usize, batch_size: usize) {
+    let event = event_with(subscribers);
+    let batch: Vec<u64> = (0..batch_size as u64).collect();
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS as usize / batch_size {
+        event.invoke_batch(black_box(&batch));
+    }
+    let elapsed = start.elapsed();
+
+    report(name, elapsed);
+}
+
+// Invokes the same event from several threads at once, the read-heavy case where invokes
+// contend with each other rather than with subscription changes.
+fn bench_contended(name: &str, subscribers: usize, threads: usize) {
+    let event = event_with(subscribers);
+
+    let start = Instant::now();
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                for i in 0..ITERATIONS as u64 {
+                    event.invoke(black_box(i));
+                }
+            });
+        }
+    });
+    let elapsed = start.elapsed();
+
+    report(name, elapsed);
+}
+
+// Subscribing and unsubscribing copy the subscription list, so they get slower as it grows.
+fn bench_subscribe(name: &str, subscribers: usize) {
+    let event = event_with(subscribers);
+
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        let sub = event.subscribe(|x| {
+            black_box(x);
+        });
+        event.unsubscribe(sub);
+    }
+    let elapsed = start.elapsed();
+
+    report(name, elapsed);
+}
+
+fn event_with(subscribers: usize) -> Event<u64> {
+    let event = Event::<u64>::new();
+    for _ in 0..subscribers {
+        event.subscribe(|x| {
+            black_box(x);
+        });
+    }
+    event
+}
+
+fn report(name: &str, elapsed: Duration) {
+    let per_iteration = elapsed / ITERATIONS;
+    println!("{name:<36} {per_iteration:>10.2?} / iteration");
+}
+
+fn main() {
+    bench("invoke, 0 subscribers", 0);
+    bench("invoke, 1 subscriber", 1);
+    bench("invoke, 8 subscribers", 8);
+    bench("invoke, 64 subscribers", 64);
+    bench_batch("invoke_batch of 100, 8 subscribers", 8, 100);
+    bench_contended("invoke x4 threads, 0 subscribers", 0, 4);
+    bench_contended("invoke x4 threads, 8 subscribers", 8, 4);
+    bench_subscribe("subscribe + unsubscribe, 0 others", 0);
+    bench_subscribe("subscribe + unsubscribe, 64 others", 64);
+    bench_subscribe("subscribe + unsubscribe, 256 others", 256);
+}
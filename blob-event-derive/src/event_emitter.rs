@@ -0,0 +1,112 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields, GenericArgument, PathArguments, Type};
+
+use crate::reject_generics;
+
+// How an `Event` field hands out its arguments.
+enum Delivery {
+    Cloned,
+    Borrowed,
+}
+
+// Generates `subscribe_x`, `unsubscribe_x`, and `emit_x` for every `Event` field `x`, and
+// `unsubscribe_all_events`.
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream, Error> {
+    reject_generics(&input, "EventEmitter")?;
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`EventEmitter` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(named) = &data.fields else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`EventEmitter` can only be derived for structs with named fields",
+        ));
+    };
+    let name = &input.ident;
+    let vis = &input.vis;
+
+    let mut methods = Vec::new();
+    let mut fields = Vec::new();
+    for field in &named.named {
+        let Some((args, delivery)) = event_args(&field.ty) else {
+            continue;
+        };
+        let field = field.ident.as_ref().unwrap();
+        let bare = field.to_string();
+        let bare = bare.strip_prefix("r#").unwrap_or(&bare);
+        let subscribe = format_ident!("subscribe_{}", bare);
+        let unsubscribe = format_ident!("unsubscribe_{}", bare);
+        let emit = format_ident!("emit_{}", bare);
+        let subscribe_doc = format!("Subscribes `handler` to `{}`.", bare);
+        let unsubscribe_doc = format!(
+            "Unsubscribes `id` from `{}`, returning `true` if it was subscribed.",
+            bare
+        );
+        let emit_doc = format!("Invokes `{}` with `args`.", bare);
+        let (handler, emitted) = match delivery {
+            Delivery::Cloned => (quote!(FnMut(#args)), quote!(#args)),
+            Delivery::Borrowed => (quote!(FnMut(&#args)), quote!(&#args)),
+        };
+        methods.push(quote! {
+            #[doc = #subscribe_doc]
+            #vis fn #subscribe<F>(&self, handler: F) -> ::blob_event::Subscription
+            where
+                F: #handler + ::core::marker::Send + 'static,
+            {
+                self.#field.subscribe(handler)
+            }
+
+            #[doc = #unsubscribe_doc]
+            #vis fn #unsubscribe(&self, id: ::blob_event::Subscription) -> bool {
+                self.#field.unsubscribe(id)
+            }
+
+            #[doc = #emit_doc]
+            #vis fn #emit(&self, args: #emitted) {
+                self.#field.invoke(args);
+            }
+        });
+        fields.push(field);
+    }
+
+    return Ok(quote! {
+        impl #name {
+            #(#methods)*
+
+            /// Unsubscribes every handler from every event of this emitter.
+            #vis fn unsubscribe_all_events(&self) {
+                #(self.#fields.unsubscribe_all();)*
+            }
+        }
+    });
+}
+
+// Returns the arguments and delivery mode of `ty` if it is an `Event`.
+fn event_args(ty: &Type) -> Option<(&Type, Delivery)> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Event" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(generics) = &segment.arguments else {
+        return None;
+    };
+    let mut types = generics.args.iter().filter_map(|arg| match arg {
+        GenericArgument::Type(ty) => Some(ty),
+        _ => None,
+    });
+    let args = types.next()?;
+    let delivery = match types.next() {
+        None => Delivery::Cloned,
+        Some(Type::Path(mode)) if mode.path.segments.last()?.ident == "Cloned" => Delivery::Cloned,
+        Some(Type::Path(mode)) if mode.path.segments.last()?.ident == "Borrowed" => Delivery::Borrowed,
+        Some(_) => return None,
+    };
+    return Some((args, delivery));
+}
@@ -0,0 +1,103 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Error, Fields};
+
+use crate::{reject_generics, snake_case};
+
+// Generates `<Enum>Events`, holding one event per variant, and its `dispatch`.
+pub(crate) fn expand(input: DeriveInput) -> Result<TokenStream, Error> {
+    reject_generics(&input, "EventSet")?;
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`EventSet` can only be derived for enums",
+        ));
+    };
+    let name = &input.ident;
+    let vis = &input.vis;
+    let set = format_ident!("{}Events", name);
+
+    let mut fields = Vec::new();
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        let variant_name = &variant.ident;
+        let field = snake_case(variant_name);
+        // The pattern binding the variant's fields, the event's argument type, and the
+        // argument built from the bindings. Variants with several fields become tuples.
+        let (pattern, ty, args) = match &variant.fields {
+            Fields::Unit => (quote!(#name::#variant_name), quote!(()), quote!(())),
+            Fields::Unnamed(unnamed) => {
+                let bindings: Vec<_> = (0..unnamed.unnamed.len()).map(|i| format_ident!("_{}", i)).collect();
+                let types: Vec<_> = unnamed.unnamed.iter().map(|field| &field.ty).collect();
+                let pattern = quote!(#name::#variant_name(#(#bindings),*));
+                match (bindings.as_slice(), types.as_slice()) {
+                    ([binding], [ty]) => (pattern, quote!(#ty), quote!(#binding)),
+                    _ => (pattern, quote!((#(#types,)*)), quote!((#(#bindings,)*))),
+                }
+            }
+            Fields::Named(named) => {
+                let bindings: Vec<_> = named.named.iter().map(|field| field.ident.as_ref().unwrap()).collect();
+                let types: Vec<_> = named.named.iter().map(|field| &field.ty).collect();
+                let pattern = quote!(#name::#variant_name { #(#bindings),* });
+                match (bindings.as_slice(), types.as_slice()) {
+                    ([binding], [ty]) => (pattern, quote!(#ty), quote!(#binding)),
+                    _ => (pattern, quote!((#(#types,)*)), quote!((#(#bindings,)*))),
+                }
+            }
+        };
+        let doc = format!("Invoked for [`{}::{}`].", name, variant_name);
+        fields.push((field, ty, doc));
+        arms.push((pattern, args));
+    }
+
+    let set_doc = format!(
+        "One event per variant of [`{}`], generated by `#[derive(EventSet)]`.",
+        name
+    );
+    let field_names: Vec<_> = fields.iter().map(|(field, _, _)| field).collect();
+    let field_types = fields.iter().map(|(_, ty, _)| ty);
+    let field_docs = fields.iter().map(|(_, _, doc)| doc);
+    let patterns = arms.iter().map(|(pattern, _)| pattern);
+    let args = arms.iter().map(|(_, args)| args);
+    return Ok(quote! {
+        #[doc = #set_doc]
+        #vis struct #set {
+            #(
+                #[doc = #field_docs]
+                pub #field_names: ::blob_event::Event<#field_types>,
+            )*
+        }
+
+        impl #set {
+            /// Creates the events, with no subscribers.
+            pub fn new() -> Self {
+                Self {
+                    #(#field_names: ::blob_event::Event::new(),)*
+                }
+            }
+
+            /// Invokes the event of `event`'s variant with the variant's fields, as a tuple
+            /// if it has several.
+            pub fn dispatch(&self, event: #name) {
+                match event {
+                    #(#patterns => self.#field_names.invoke(#args),)*
+                }
+            }
+        }
+
+        impl ::core::default::Default for #set {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        // Clones share the events, like cloning an `Event` does.
+        impl ::core::clone::Clone for #set {
+            fn clone(&self) -> Self {
+                Self {
+                    #(#field_names: ::core::clone::Clone::clone(&self.#field_names),)*
+                }
+            }
+        }
+    });
+}
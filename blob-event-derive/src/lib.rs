@@ -0,0 +1,116 @@
+//! Derive macros for `blob-event`, re-exported by it with the `derive` feature. See the
+//! documentation there.
+
+#![allow(clippy::needless_return)]
+
+use proc_macro::TokenStream;
+use proc_macro2::{Ident, Span};
+use quote::format_ident;
+use syn::{DeriveInput, Error, parse_macro_input};
+
+mod event_emitter;
+mod event_set;
+
+/// Generates a struct holding one `Event` per variant of an enum, named after the enum with
+/// an `Events` suffix.
+///
+/// Each event is a public field named after its variant in `snake_case`. Its arguments are
+/// the variant's field, a tuple of its fields if it has several, or `()` if it has none. The
+/// generated `dispatch` method invokes the event matching a value of the enum, which lets
+/// one place receive every kind of event while each subscriber listens to only the ones it
+/// cares about. Cloning the struct shares the events.
+///
+/// ```
+/// use blob_event::EventSet;
+///
+/// #[derive(EventSet)]
+/// enum AppEvent {
+///     Clicked(i32, i32),
+///     KeyPressed { key: char },
+///     Closed,
+/// }
+///
+/// let events = AppEventEvents::new();
+/// events.clicked.subscribe(|(x, y)| println!("clicked at {}, {}", x, y));
+/// events.closed.subscribe(|()| println!("closed"));
+///
+/// events.dispatch(AppEvent::Clicked(3, 4)); // Prints: clicked at 3, 4
+/// events.dispatch(AppEvent::KeyPressed { key: 'q' }); // No subscribers
+/// ```
+#[proc_macro_derive(EventSet)]
+pub fn derive_event_set(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    return event_set::expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into();
+}
+
+/// Generates wrapper methods for the `Event` fields of a struct with named fields.
+///
+/// For every field `x` of type `Event<T>`, it generates `subscribe_x`, `unsubscribe_x`,
+/// and `emit_x`, which subscribe to, unsubscribe from, and invoke the event. On fields of
+/// type `Event<T, Borrowed>`, the handlers and `emit_x` take `&T` instead. It also generates
+/// `unsubscribe_all_events`, which clears every one of the events. The methods have the
+/// struct's visibility, so the fields themselves can stay private. Other fields are left
+/// alone.
+///
+/// ```
+/// use blob_event::{Event, EventEmitter};
+///
+/// #[derive(EventEmitter, Default)]
+/// struct Button {
+///     label: String,
+///     clicked: Event<(i32, i32)>,
+///     hovered: Event<bool>,
+/// }
+///
+/// let button = Button::default();
+/// let sub = button.subscribe_clicked(|(x, y)| println!("clicked at {}, {}", x, y));
+/// button.emit_clicked((3, 4)); // Prints: clicked at 3, 4
+/// button.unsubscribe_clicked(sub);
+/// button.unsubscribe_all_events();
+/// ```
+#[proc_macro_derive(EventEmitter)]
+pub fn derive_event_emitter(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    return event_emitter::expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into();
+}
+
+// Turns a `CamelCase` name into a `snake_case` identifier, made raw if it is a keyword.
+fn snake_case(name: &Ident) -> Ident {
+    let name = name.to_string();
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() {
+            let after_lower = i > 0 && (chars[i - 1].is_lowercase() || chars[i - 1].is_ascii_digit());
+            // The last capital of an acronym starts the next word, as in `HTTPRequest`.
+            let ends_acronym =
+                i > 0 && chars[i - 1].is_uppercase() && chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if after_lower || ends_acronym {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    if syn::parse_str::<Ident>(&snake).is_err() {
+        return Ident::new_raw(&snake, Span::call_site());
+    }
+    return format_ident!("{}", snake);
+}
+
+// Rejects generic items, whose events would need the generics threaded through every
+// generated item.
+fn reject_generics(input: &DeriveInput, derive: &str) -> Result<(), Error> {
+    if input.generics.params.is_empty() && input.generics.where_clause.is_none() {
+        return Ok(());
+    }
+    return Err(Error::new_spanned(
+        &input.generics,
+        format!("`{}` can't be derived for generic types", derive),
+    ));
+}
@@ -0,0 +1,216 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::sync::{Lock, Mutex};
+use crate::{Borrowed, Event, EventHandler, Subscription};
+
+type HandlerFuture<E> = Pin<Box<dyn Future<Output = Result<(), E>> + Send>>;
+
+/// How [`AsyncEvent::invoke`] runs the futures returned by the handlers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum AsyncDispatch {
+    /// Each handler's future runs to completion before the next one is polled, in dispatch
+    /// order.
+    #[default]
+    Sequential,
+    /// Every handler's future is polled concurrently, and the invocation completes once all
+    /// of them have finished.
+    Concurrent,
+}
+
+/// An event whose handlers are async.
+///
+/// Handlers return a future, and awaiting [`invoke`](Self::invoke) runs all of them,
+/// one after another or concurrently as chosen with [`AsyncDispatch`], completing once all
+/// have finished. As with [`FallibleEvent`](crate::FallibleEvent), the futures return
+/// `Result<(), E>`, and the errors are collected rather than cutting the dispatch short.
+///
+/// No executor or runtime is assumed: the handlers' futures are polled by the future
+/// returned by `invoke`, on whatever executor awaits it.
+///
+/// ```
+/// # use std::future::Future;
+/// # use std::pin::pin;
+/// # use std::task::{Context, Poll, Waker};
+/// use blob_event::AsyncEvent;
+///
+/// let request = AsyncEvent::<u32, String>::new();
+/// request.subscribe(|id| async move { Ok(println!("audited request {}", id)) });
+/// let cache = request.subscribe(|_| async { Err("cache offline".to_string()) });
+///
+/// let mut invoke = pin!(request.invoke(7));
+/// let mut cx = Context::from_waker(Waker::noop());
+/// let Poll::Ready(failures) = invoke.as_mut().poll(&mut cx) else { unreachable!() };
+/// assert_eq!(failures, vec![(cache, "cache offline".to_string())]);
+/// ```
+pub struct AsyncEvent<Args, E> {
+    event: Event<Start<Args, E>, Borrowed>,
+    dispatch: AsyncDispatch,
+}
+
+// What an async invocation hands to every handler: the arguments, and the place to leave
+// the handler's future.
+struct Start<Args, E> {
+    args: Args,
+    started: Mutex<Vec<(Subscription, HandlerFuture<E>)>>,
+}
+
+struct AsyncHandler<F> {
+    id: Subscription,
+    handler: F,
+}
+
+impl<F, Fut, Args, E> EventHandler<Start<Args, E>> for AsyncHandler<F>
+where
+    F: FnMut(Args) -> Fut + Send,
+    Fut: Future<Output = Result<(), E>> + Send + 'static,
+    Args: Clone,
+{
+    fn call(&mut self, start: &Start<Args, E>) {
+        let future = (self.handler)(start.args.clone());
+        start.started.acquire().push((self.id, Box::pin(future)));
+    }
+}
+
+impl<Args: Clone, E> AsyncEvent<Args, E> {
+    /// Creates a new event with no subscribers, whose handlers run one after another.
+    pub fn new() -> Self {
+        Self::with_dispatch(AsyncDispatch::Sequential)
+    }
+
+    /// Creates a new event with no subscribers, whose handlers run as `dispatch` says.
+    pub fn with_dispatch(dispatch: AsyncDispatch) -> Self {
+        AsyncEvent {
+            event: Event::empty(),
+            dispatch,
+        }
+    }
+
+    /// Subscribes an async callback to this event.
+    ///
+    /// Returns a `Subscription` that must be used to unsubscribe later.
+    pub fn subscribe<F, Fut>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Args) -> Fut + Send + 'static,
+        Fut: Future<Output = Result<(), E>> + Send + 'static,
+    {
+        return self
+            .event
+            .subscribe_handler_with(0, |id| Box::new(AsyncHandler { id, handler }));
+    }
+
+    /// Unsubscribes a callback from this event.
+    ///
+    /// Returns `true` if the subscription was found and removed, `false` otherwise. A
+    /// handler whose future is already running in an invocation still runs to completion.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.event.unsubscribe(id);
+    }
+
+    /// Removes all subscribers from this event.
+    pub fn unsubscribe_all(&self) {
+        self.event.unsubscribe_all();
+    }
+
+    /// Returns the current number of subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        return self.event.subscriber_count();
+    }
+
+    /// Triggers the event, calling every subscribed handler with a clone of `args`, and
+    /// returns a future that runs the handlers' futures and resolves to the errors of the
+    /// ones that failed, in dispatch order.
+    ///
+    /// The handlers are called, and their futures created, as soon as `invoke` is called;
+    /// the futures only make progress while the returned future is polled. The set of
+    /// handlers is captured the same way as by [`Event::invoke`].
+    pub fn invoke(&self, args: Args) -> impl Future<Output = Vec<(Subscription, E)>> + use<Args, E> {
+        let start = Start {
+            args,
+            started: Mutex::new(Vec::new()),
+        };
+        self.event.invoke(&start);
+        let started = core::mem::take(&mut *start.started.acquire());
+        let dispatch = self.dispatch;
+        async move {
+            match dispatch {
+                AsyncDispatch::Sequential => {
+                    let mut failures = Vec::new();
+                    for (id, future) in started {
+                        if let Err(error) = future.await {
+                            failures.push((id, error));
+                        }
+                    }
+                    return failures;
+                }
+                AsyncDispatch::Concurrent => return JoinAll::new(started).await,
+            }
+        }
+    }
+}
+
+// Polls every handler future until all have finished, collecting the errors in the order
+// the handlers were called.
+struct JoinAll<E> {
+    running: Vec<Option<(Subscription, HandlerFuture<E>)>>,
+    results: Vec<Option<(Subscription, E)>>,
+}
+
+impl<E> JoinAll<E> {
+    fn new(started: Vec<(Subscription, HandlerFuture<E>)>) -> Self {
+        let results = started.iter().map(|_| None).collect();
+        JoinAll {
+            running: started.into_iter().map(Some).collect(),
+            results,
+        }
+    }
+}
+
+// The handler futures are boxed and the errors are never pinned.
+impl<E> Unpin for JoinAll<E> {}
+
+impl<E> Future for JoinAll<E> {
+    type Output = Vec<(Subscription, E)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let mut pending = false;
+        for (slot, result) in this.running.iter_mut().zip(this.results.iter_mut()) {
+            let Some((id, future)) = slot else {
+                continue;
+            };
+            match future.as_mut().poll(cx) {
+                Poll::Ready(outcome) => {
+                    if let Err(error) = outcome {
+                        *result = Some((*id, error));
+                    }
+                    *slot = None;
+                }
+                Poll::Pending => pending = true,
+            }
+        }
+        if pending {
+            return Poll::Pending;
+        }
+        return Poll::Ready(this.results.iter_mut().filter_map(Option::take).collect());
+    }
+}
+
+impl<Args, E> Clone for AsyncEvent<Args, E> {
+    fn clone(&self) -> Self {
+        AsyncEvent {
+            event: self.event.clone(),
+            dispatch: self.dispatch,
+        }
+    }
+}
+
+impl<Args: Clone, E> Default for AsyncEvent<Args, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
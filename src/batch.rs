@@ -0,0 +1,153 @@
+use std::sync::{Arc, Weak};
+use std::time::Duration;
+
+use crate::sync::{Lock, Mutex};
+use crate::timer::{self, Scheduler};
+use crate::{ByClone, Event, EventError, Subscription};
+
+/// Controls when a batched subscription hands its accumulated arguments to the handler.
+///
+/// A batch is delivered as soon as it holds `max_size` items, or, if a maximum latency is
+/// set, once that much time has passed since its first item arrived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchConfig {
+    max_size: usize,
+    max_latency: Option<Duration>,
+}
+
+impl BatchConfig {
+    /// Creates a configuration that delivers batches of `max_size` items.
+    ///
+    /// A `max_size` of zero is rejected when subscribing.
+    pub fn new(max_size: usize) -> Self {
+        BatchConfig {
+            max_size,
+            max_latency: None,
+        }
+    }
+
+    /// Creates a configuration without a size limit that delivers whatever has accumulated
+    /// once the oldest item has waited for `window`.
+    pub fn by_time(window: Duration) -> Self {
+        BatchConfig {
+            max_size: usize::MAX,
+            max_latency: Some(window),
+        }
+    }
+
+    /// Also delivers a partial batch once its oldest item has waited for `latency`.
+    pub fn max_latency(mut self, latency: Duration) -> Self {
+        self.max_latency = Some(latency);
+        self
+    }
+
+    fn preallocated(&self) -> usize {
+        self.max_size.min(MAX_PREALLOCATED)
+    }
+}
+
+// Upper bound on how much room is reserved up front for a batch, so large or unbounded batch
+// sizes don't allocate more than they are likely to use.
+const MAX_PREALLOCATED: usize = 1024;
+
+struct Batcher<Args, F> {
+    config: BatchConfig,
+    // Times the latency, if there is one. Picked when subscribing.
+    scheduler: Option<Arc<dyn Scheduler>>,
+    pending: Mutex<Pending<Args>>,
+    // Locked for the whole flush so batches reach the handler in the order they were filled,
+    // even when the timer thread and an invoking thread flush at the same time.
+    handler: Mutex<F>,
+}
+
+struct Pending<Args> {
+    items: Vec<Args>,
+    // Incremented every time a batch is taken, so a latency timer armed for an earlier batch
+    // doesn't cut the next one short.
+    generation: u64,
+}
+
+impl<Args, F> Batcher<Args, F>
+where
+    Args: Send + 'static,
+    F: FnMut(Vec<Args>) + Send + 'static,
+{
+    fn push(self: &Arc<Self>, args: Args) {
+        let (full, first_of_batch, generation) = {
+            let mut pending = self.pending.acquire();
+            pending.items.push(args);
+            let len = pending.items.len();
+            (len >= self.config.max_size, len == 1, pending.generation)
+        };
+
+        if full {
+            self.flush(None);
+        } else if first_of_batch
+            && let Some(latency) = self.config.max_latency
+            && let Some(scheduler) = &self.scheduler
+        {
+            let batcher: Weak<Self> = Arc::downgrade(self);
+            let flush = move || {
+                if let Some(batcher) = batcher.upgrade() {
+                    batcher.flush(Some(generation));
+                }
+            };
+            scheduler.schedule(scheduler.now() + latency, Box::new(flush));
+        }
+    }
+
+    // Delivers the pending items, if any. A timer flush passes the generation it was armed
+    // for and does nothing if that batch has already been delivered.
+    fn flush(&self, generation: Option<u64>) {
+        let mut handler = self.handler.acquire();
+        let batch = {
+            let mut pending = self.pending.acquire();
+            if generation.is_some_and(|generation| generation != pending.generation) {
+                return;
+            }
+            pending.generation += 1;
+            std::mem::replace(&mut pending.items, Vec::with_capacity(self.config.preallocated()))
+        };
+        if !batch.is_empty() {
+            handler(batch);
+        }
+    }
+}
+
+impl<Args> Event<Args>
+where
+    Args: Clone + Send + 'static,
+{
+    /// Subscribes a callback that receives the event's arguments in batches.
+    ///
+    /// Invocations are accumulated and handed to `handler` as a `Vec` according to `config`.
+    /// Size-triggered batches are delivered on the invoking thread; latency-triggered ones on
+    /// the crate's shared timer thread. Items still waiting when the subscription is removed
+    /// are discarded.
+    ///
+    /// The handler must not invoke this event in a way that completes a batch, since the
+    /// batch in progress is still being delivered.
+    ///
+    /// Returns [`EventError::InvalidBatchSize`] if the configured batch size is zero, and
+    /// otherwise fails as [`try_subscribe`](Self::try_subscribe) does, such as on a closed
+    /// event.
+    pub fn subscribe_batched<F>(&self, config: BatchConfig, handler: F) -> Result<Subscription, EventError>
+    where
+        F: FnMut(Vec<Args>) + Send + 'static,
+    {
+        if config.max_size == 0 {
+            return Err(EventError::InvalidBatchSize);
+        }
+
+        let batcher = Arc::new(Batcher {
+            config,
+            scheduler: config.max_latency.map(|_| timer::current()),
+            pending: Mutex::new(Pending {
+                items: Vec::with_capacity(config.preallocated()),
+                generation: 0,
+            }),
+            handler: Mutex::new(handler),
+        });
+        return self.try_subscribe_handler(0, Box::new(ByClone(move |args| batcher.push(args))));
+    }
+}
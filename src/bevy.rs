@@ -0,0 +1,138 @@
+//! Integration with the Bevy game engine, available with the `bevy` feature.
+//!
+//! [`BlobEventPlugin`] runs the crate's frame-based delivery from Bevy's schedule, and
+//! [`BlobEventAppExt`] adds the methods that hook events into an [`App`]:
+//!
+//! - [`pump_event`](BlobEventAppExt::pump_event) and
+//!   [`drain_queue`](BlobEventAppExt::drain_queue) dispatch a
+//!   [pumped](crate::DeliveryMode::Pumped) event or an [`EventQueue`] once per frame, on the
+//!   main thread, in [`PreUpdate`].
+//! - [`forward_messages`](BlobEventAppExt::forward_messages) invokes an event with every
+//!   Bevy message of a type, and [`emit_messages`](BlobEventAppExt::emit_messages) writes
+//!   every invocation of an event as a Bevy message, so the two event systems stay in sync
+//!   without hand-written glue.
+//!
+//! ```no_run
+//! use bevy_app::App;
+//! use bevy_ecs::message::Message;
+//! use blob_event::Event;
+//! use blob_event::bevy::{BlobEventAppExt, BlobEventPlugin};
+//!
+//! #[derive(Message, Clone)]
+//! struct Scored(u32);
+//!
+//! // Invoked by game logic outside the ECS, such as a networking thread
+//! let scores = Event::<Scored>::new();
+//!
+//! App::new()
+//!     .add_plugins(BlobEventPlugin)
+//!     .emit_messages(&scores)
+//!     .run();
+//! ```
+
+use std::sync::{Arc, Mutex};
+
+use bevy_app::{App, Plugin, PostUpdate, PreUpdate};
+use bevy_ecs::message::{Message, MessageReader, MessageWriter};
+use bevy_ecs::system::NonSendMut;
+
+use crate::{Event, EventQueue};
+
+/// Dispatches the events and queues registered with
+/// [`pump_event`](BlobEventAppExt::pump_event) and
+/// [`drain_queue`](BlobEventAppExt::drain_queue) once per frame, in [`PreUpdate`].
+///
+/// The dispatching runs on the main thread, which becomes the designated thread of the
+/// pumped events. Registering an event or a queue adds the plugin if it is missing.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BlobEventPlugin;
+
+// The events and queues dispatched each frame. Pumped events have to be pumped from the
+// same thread every time, so this is a non-send resource, which Bevy only touches from the
+// main thread.
+#[derive(Default)]
+struct Pumps(Vec<Box<dyn FnMut()>>);
+
+impl Plugin for BlobEventPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_non_send::<Pumps>().add_systems(PreUpdate, run_pumps);
+    }
+}
+
+fn run_pumps(mut pumps: NonSendMut<Pumps>) {
+    for pump in &mut pumps.0 {
+        pump();
+    }
+}
+
+/// Methods hooking blob-event events into a Bevy [`App`].
+pub trait BlobEventAppExt {
+    /// [Pumps](Event::pump) `event` once per frame, so the invocations queued on it since
+    /// the previous frame are dispatched on the main thread.
+    ///
+    /// `event` should be created with [`DeliveryMode::Pumped`](crate::DeliveryMode::Pumped);
+    /// an event in another delivery mode has nothing to pump.
+    fn pump_event<Args: Clone + 'static>(&mut self, event: Event<Args>) -> &mut Self;
+
+    /// [Drains](EventQueue::drain) `queue` once per frame, on the main thread.
+    fn drain_queue<Args: Clone + 'static>(&mut self, queue: EventQueue<Args>) -> &mut Self;
+
+    /// Invokes `event` with every message of type `M` written in Bevy, in [`PostUpdate`],
+    /// registering the message type if needed.
+    fn forward_messages<M: Message + Clone>(&mut self, event: Event<M>) -> &mut Self;
+
+    /// Writes every invocation of `event` as a message of type `M` in Bevy, registering the
+    /// message type if needed.
+    ///
+    /// Invocations are collected as they happen, from any thread, and written in
+    /// [`PreUpdate`], so systems see them in the frame that follows. The event is tapped
+    /// through a [mirror](Event::mirror), so this doesn't count as a subscriber.
+    fn emit_messages<M: Message + Clone>(&mut self, event: &Event<M>) -> &mut Self;
+}
+
+impl BlobEventAppExt for App {
+    fn pump_event<Args: Clone + 'static>(&mut self, event: Event<Args>) -> &mut Self {
+        return add_pump(self, move || {
+            event.pump();
+        });
+    }
+
+    fn drain_queue<Args: Clone + 'static>(&mut self, mut queue: EventQueue<Args>) -> &mut Self {
+        return add_pump(self, move || {
+            queue.drain();
+        });
+    }
+
+    fn forward_messages<M: Message + Clone>(&mut self, event: Event<M>) -> &mut Self {
+        let forward = move |mut messages: MessageReader<M>| {
+            for message in messages.read() {
+                event.invoke(message.clone());
+            }
+        };
+        return self.add_message::<M>().add_systems(PostUpdate, forward);
+    }
+
+    fn emit_messages<M: Message + Clone>(&mut self, event: &Event<M>) -> &mut Self {
+        let mirror = event.mirror();
+        let collected = Arc::new(Mutex::new(Vec::new()));
+        let collecting = Arc::clone(&collected);
+        mirror.subscribe(move |message: M| collecting.lock().unwrap().push(message));
+        let emit = move |mut messages: MessageWriter<M>| {
+            // The mirror stays attached for as long as the system holds it.
+            let _ = &mirror;
+            let collected = std::mem::take(&mut *collected.lock().unwrap());
+            for message in collected {
+                messages.write(message);
+            }
+        };
+        return self.add_message::<M>().add_systems(PreUpdate, emit);
+    }
+}
+
+fn add_pump(app: &mut App, pump: impl FnMut() + 'static) -> &mut App {
+    if !app.is_plugin_added::<BlobEventPlugin>() {
+        app.add_plugins(BlobEventPlugin);
+    }
+    app.world_mut().non_send_mut::<Pumps>().0.push(Box::new(pump));
+    return app;
+}
@@ -0,0 +1,126 @@
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::vec::Vec;
+
+use crate::{Event, Property, Subscription};
+
+thread_local! {
+    // The bindings propagating a change on this thread, innermost last.
+    static SYNCING: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+}
+
+static NEXT_BINDING: AtomicUsize = AtomicUsize::new(0);
+
+/// The link between two properties made by
+/// [`bind_bidirectional`](Property::bind_bidirectional). Dropping it leaves the two bound for
+/// as long as they both exist; call [`unbind`](Self::unbind) to separate them earlier.
+pub struct PropertyBinding<T, U> {
+    source: (Event<(T, T)>, Subscription),
+    target: (Event<(U, U)>, Subscription),
+}
+
+impl<T, U> PropertyBinding<T, U> {
+    /// Separates the two properties, so that neither follows the other anymore.
+    pub fn unbind(self) {
+        self.source.0.unsubscribe(self.source.1);
+        self.target.0.unsubscribe(self.target.1);
+    }
+}
+
+impl<T> Property<T>
+where
+    T: Clone + Send + 'static,
+{
+    /// Keeps this property and `other` in sync in both directions: every change of this
+    /// property sets `other` to `to` of the new value, and every change of `other` sets this
+    /// property to `from` of its new value. `other` is set from this property's value right
+    /// away. Requires `std`.
+    ///
+    /// A change made by the binding itself isn't propagated back, so the two don't ping-pong
+    /// even when `to` and `from` don't round-trip exactly, as with rounding. Other
+    /// subscribers of either property see every change as usual.
+    ///
+    /// The binding doesn't keep either property alive: once one of them is dropped, changes
+    /// of the other no longer go anywhere.
+    ///
+    /// ```
+    /// use blob_event::Property;
+    ///
+    /// let celsius = Property::new(20.0);
+    /// let fahrenheit = Property::new(0.0);
+    /// celsius.bind_bidirectional(&fahrenheit, |c| c * 9.0 / 5.0 + 32.0, |f| (f - 32.0) * 5.0 / 9.0);
+    /// assert_eq!(fahrenheit.get(), 68.0);
+    ///
+    /// fahrenheit.set(212.0);
+    /// assert_eq!(celsius.get(), 100.0);
+    /// ```
+    pub fn bind_bidirectional<U, To, From>(
+        &self,
+        other: &Property<U>,
+        mut to: To,
+        mut from: From,
+    ) -> PropertyBinding<T, U>
+    where
+        U: Clone + Send + 'static,
+        To: FnMut(T) -> U + Send + 'static,
+        From: FnMut(U) -> T + Send + 'static,
+    {
+        let id = NEXT_BINDING.fetch_add(1, Ordering::Relaxed);
+        other.set(to(self.get()));
+
+        // Each property's change event holds the handler setting the other one, so strong
+        // handles would keep both alive for good.
+        let target = Arc::downgrade(&other.inner);
+        let forward = self.changed().subscribe(move |(_, new)| {
+            let Some(inner) = target.upgrade() else {
+                return;
+            };
+            if let Some(_syncing) = Syncing::enter(id) {
+                Property { inner }.set(to(new));
+            }
+        });
+        let source = Arc::downgrade(&self.inner);
+        let backward = other.changed().subscribe(move |(_, new)| {
+            let Some(inner) = source.upgrade() else {
+                return;
+            };
+            if let Some(_syncing) = Syncing::enter(id) {
+                Property { inner }.set(from(new));
+            }
+        });
+        return PropertyBinding {
+            source: (self.changed().clone(), forward),
+            target: (other.changed().clone(), backward),
+        };
+    }
+}
+
+// Marks a binding as propagating a change on this thread until dropped.
+struct Syncing {
+    id: usize,
+}
+
+impl Syncing {
+    // Returns `None` if the binding is already propagating a change on this thread, which
+    // means the change being handled is its own.
+    fn enter(id: usize) -> Option<Syncing> {
+        return SYNCING.with_borrow_mut(|syncing| {
+            if syncing.contains(&id) {
+                return None;
+            }
+            syncing.push(id);
+            return Some(Syncing { id });
+        });
+    }
+}
+
+impl Drop for Syncing {
+    fn drop(&mut self) {
+        SYNCING.with_borrow_mut(|syncing| {
+            if let Some(position) = syncing.iter().rposition(|id| *id == self.id) {
+                syncing.remove(position);
+            }
+        });
+    }
+}
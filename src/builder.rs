@@ -0,0 +1,221 @@
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+
+#[cfg(feature = "std")]
+use crate::dispatcher::{self, StartDispatcher};
+use crate::{Borrowed, Cloned, DispatchOrder, Event, SubscriberLimit};
+#[cfg(feature = "std")]
+use crate::{DeliveryMode, RecursionPolicy, TimeoutAction};
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+/// Configures and creates an [`Event`].
+///
+/// The delivery mode is part of the event's type, so choosing it here also decides the
+/// signature handlers must have:
+///
+/// ```
+/// use blob_event::{Borrowed, Event, SharedEvent};
+///
+/// // Handlers take `i32` by value
+/// let small: Event<i32> = Event::builder().build();
+///
+/// // Handlers take `&Vec<u8>`, nothing is cloned
+/// let large: Event<Vec<u8>, Borrowed> = Event::builder().borrowed().build();
+///
+/// // Handlers take `Arc<Vec<u8>>`
+/// let shared: SharedEvent<Vec<u8>> = Event::builder().shared().build();
+/// ```
+pub struct EventBuilder<Args: ?Sized, D = Cloned> {
+    config: EventConfig,
+    // Set by `delivery_mode`, along with how to start the event's dispatcher thread.
+    #[cfg(feature = "std")]
+    queued: Option<(DeliveryMode, StartDispatcher<Args>)>,
+    args: PhantomData<fn(&Args)>,
+    delivery: PhantomData<D>,
+}
+
+// Settings chosen through the builder, kept with the event's subscription list.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct EventConfig {
+    pub(crate) order: DispatchOrder,
+    pub(crate) max_subscribers: Option<(usize, SubscriberLimit)>,
+    pub(crate) capacity: usize,
+    #[cfg(feature = "std")]
+    pub(crate) handler_timeout: Option<(Duration, TimeoutAction)>,
+    #[cfg(feature = "std")]
+    pub(crate) slow_handler_threshold: Option<Duration>,
+    #[cfg(feature = "std")]
+    pub(crate) collect_stats: bool,
+    #[cfg(feature = "std")]
+    pub(crate) max_depth: Option<(usize, RecursionPolicy)>,
+}
+
+impl<Args: ?Sized> EventBuilder<Args> {
+    /// Creates a builder for an event with the default [`Cloned`] delivery mode.
+    pub fn new() -> Self {
+        EventBuilder {
+            config: EventConfig::default(),
+            #[cfg(feature = "std")]
+            queued: None,
+            args: PhantomData,
+            delivery: PhantomData,
+        }
+    }
+}
+
+impl<Args: ?Sized, D> EventBuilder<Args, D> {
+    /// Handlers receive their own clone of the arguments.
+    pub fn cloned(self) -> EventBuilder<Args, Cloned> {
+        self.with_delivery()
+    }
+
+    /// Handlers receive a reference to the arguments.
+    pub fn borrowed(self) -> EventBuilder<Args, Borrowed> {
+        self.with_delivery()
+    }
+
+    /// Handlers receive an `Arc` of the arguments, producing a [`SharedEvent`](crate::SharedEvent).
+    pub fn shared(self) -> EventBuilder<Arc<Args>, Cloned>
+    where
+        Args: Sized,
+    {
+        EventBuilder {
+            config: self.config,
+            #[cfg(feature = "std")]
+            queued: None,
+            args: PhantomData,
+            delivery: PhantomData,
+        }
+    }
+
+    fn with_delivery<E>(self) -> EventBuilder<Args, E> {
+        EventBuilder {
+            config: self.config,
+            #[cfg(feature = "std")]
+            queued: None,
+            args: PhantomData,
+            delivery: PhantomData,
+        }
+    }
+
+    /// Sets the order in which the event calls its handlers. Defaults to
+    /// [`DispatchOrder::Insertion`].
+    pub fn dispatch_order(mut self, order: DispatchOrder) -> Self {
+        self.config.order = order;
+        self
+    }
+
+    /// Pre-allocates the event's handler storage for `capacity` subscribers, so that
+    /// subscribing up to that many doesn't reallocate it.
+    ///
+    /// Storage still grows as needed beyond `capacity`; see [`Event::shrink_to_fit`] for
+    /// giving memory back.
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.config.capacity = capacity;
+        self
+    }
+
+    /// Caps the number of handlers the event can hold at `limit`, with `policy` deciding
+    /// what happens to subscriptions beyond it.
+    ///
+    /// Subscriptions that leak grow an event's handler list without bound; a cap makes them
+    /// fail early instead, and [`EventHooks::on_subscriber_limit`](crate::EventHooks::on_subscriber_limit)
+    /// is called every time it is reached.
+    pub fn max_subscribers(mut self, limit: usize, policy: SubscriberLimit) -> Self {
+        self.config.max_subscribers = Some((limit, policy));
+        self
+    }
+
+    /// Watches the handlers of the event's queued and detached dispatches, and applies
+    /// `action` to any handler still running after `limit`.
+    ///
+    /// This covers dispatches that run on the crate's threads or through
+    /// [`pump`](Event::pump): those of events with a queued [`DeliveryMode`], and those
+    /// started by [`invoke_detached`](Event::invoke_detached). Handlers can't be interrupted,
+    /// but offending ones are reported through [`Event::handler_timeouts`], so a stuck
+    /// subscriber no longer stalls the event without a trace.
+    #[cfg(feature = "std")]
+    pub fn handler_timeout(mut self, limit: Duration, action: TimeoutAction) -> Self {
+        self.config.handler_timeout = Some((limit, action));
+        self
+    }
+
+    /// Reports every handler call that takes longer than `threshold` through
+    /// [`Event::slow_handlers`], on every dispatch path.
+    ///
+    /// The report is made once the handler returns, with how long it took, which makes it
+    /// the first place to look when an event-driven application develops latency spikes.
+    #[cfg(feature = "std")]
+    pub fn slow_handler_threshold(mut self, threshold: Duration) -> Self {
+        self.config.slow_handler_threshold = Some(threshold);
+        self
+    }
+
+    /// Makes the event count its invocations and the calls and running time of every
+    /// handler, for reading with [`Event::stats`].
+    ///
+    /// Each handler call then reads the clock twice and updates two atomic counters, so this
+    /// is off by default.
+    #[cfg(feature = "std")]
+    pub fn collect_stats(mut self) -> Self {
+        self.config.collect_stats = true;
+        self
+    }
+
+    /// Limits how deeply dispatches of the event can nest on one thread to `depth`, with
+    /// `policy` deciding what happens to a dispatch that would go deeper.
+    ///
+    /// A handler that invokes its own event, directly or through a cycle of other events,
+    /// starts a dispatch that reaches the handler again while it is still running. Handlers
+    /// are never called inside themselves, so without a limit that dispatch waits forever for
+    /// the running handler. A depth of 1 forbids nested dispatches and hands them to `policy`
+    /// instead, which turns such a cycle into a panic or a dropped invoke that can be traced.
+    /// With deeper limits, nested dispatches run, but skip the handlers that are still running
+    /// further up the same thread and call only the others.
+    #[cfg(feature = "std")]
+    pub fn max_recursion_depth(mut self, depth: usize, policy: RecursionPolicy) -> Self {
+        self.config.max_depth = Some((depth, policy));
+        self
+    }
+
+    /// Creates the configured event.
+    pub fn build(self) -> Event<Args, D> {
+        #[cfg(feature = "std")]
+        if let Some((mode, start)) = self.queued {
+            return Event::with_dispatcher(self.config, |event| start(event, mode));
+        }
+        Event::with_config(self.config)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<Args> EventBuilder<Args, Cloned>
+where
+    Args: Clone + Send + 'static,
+{
+    /// Sets when the event's handlers run relative to `invoke`. Defaults to
+    /// [`DeliveryMode::Immediate`].
+    ///
+    /// With [`DeliveryMode::Queued`], the event gets a thread of its own that runs the
+    /// handlers, so invoking it never waits for them. Only available for events that hand
+    /// out owned arguments. Calling [`cloned`](Self::cloned), [`borrowed`](Self::borrowed) or
+    /// [`shared`](Self::shared) afterwards resets it to immediate delivery.
+    pub fn delivery_mode(mut self, mode: DeliveryMode) -> Self {
+        self.queued = Some((mode, dispatcher::start::<Args>));
+        self
+    }
+}
+
+impl<Args: ?Sized> Default for EventBuilder<Args> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Args: ?Sized> Event<Args> {
+    /// Returns a builder for configuring a new event.
+    pub fn builder() -> EventBuilder<Args> {
+        EventBuilder::new()
+    }
+}
@@ -0,0 +1,178 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+
+use crate::{Borrowed, Event, Subscription};
+
+/// A node in a tree of events, where a message published on a node is delivered to the
+/// node's handlers and then bubbles up to each of its ancestors in turn, until a handler
+/// [consumes](Propagation::consume) it.
+///
+/// This is the propagation model of UI widget trees and scene graphs: a click published on
+/// a button reaches the button first, then its panel, then the window, and any of them can
+/// stop it from going further. As in the DOM, handlers subscribed with
+/// [`subscribe_capture`](Self::subscribe_capture) see the message on its way down instead,
+/// before the target does, which lets an ancestor intercept it first. A message goes through
+/// three [`Phase`]s:
+///
+/// 1. Capture: the capture handlers of each ancestor, from the root down to the parent
+/// 2. Target: the capture handlers and then the other handlers of the node it was published
+///    on
+/// 3. Bubble: the other handlers of each ancestor, from the parent up to the root
+///
+/// Nodes keep their ancestors alive, but not their children. Cloning a node returns another
+/// handle to the same node.
+pub struct BusNode<Args> {
+    inner: Arc<NodeInner<Args>>,
+}
+
+struct NodeInner<Args> {
+    // The handlers for the target and bubble phases.
+    event: Event<Propagation<Args>, Borrowed>,
+    capture: Event<Propagation<Args>, Borrowed>,
+    parent: Option<BusNode<Args>>,
+}
+
+/// Where a message travelling through a tree of [`BusNode`]s is, as seen by a handler.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Phase {
+    /// On its way down from the root, at an ancestor of the target.
+    Capture,
+    /// At the node it was published on.
+    Target,
+    /// On its way up to the root, at an ancestor of the target.
+    Bubble,
+}
+
+/// A message travelling through a tree of [`BusNode`]s, as handed to their handlers.
+pub struct Propagation<Args> {
+    args: Args,
+    consumed: AtomicBool,
+    // A `Phase` as its index.
+    phase: AtomicU8,
+}
+
+impl<Args> Propagation<Args> {
+    /// Returns the published message.
+    pub fn args(&self) -> &Args {
+        return &self.args;
+    }
+
+    /// Stops the message from going any further, in either direction. The other handlers of
+    /// the current node still receive it.
+    pub fn consume(&self) {
+        self.consumed.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns `true` if a handler has consumed the message.
+    pub fn is_consumed(&self) -> bool {
+        return self.consumed.load(Ordering::Relaxed);
+    }
+
+    /// Returns the phase the message is in.
+    pub fn phase(&self) -> Phase {
+        return match self.phase.load(Ordering::Relaxed) {
+            0 => Phase::Capture,
+            1 => Phase::Target,
+            _ => Phase::Bubble,
+        };
+    }
+
+    fn enter(&self, phase: Phase) {
+        self.phase.store(phase as u8, Ordering::Relaxed);
+    }
+}
+
+impl<Args> BusNode<Args> {
+    /// Creates the root of a new tree.
+    pub fn root() -> Self {
+        return Self::with_parent(None);
+    }
+
+    /// Creates a child of this node, to which messages published on the child bubble up.
+    pub fn child(&self) -> Self {
+        return Self::with_parent(Some(self.clone()));
+    }
+
+    fn with_parent(parent: Option<BusNode<Args>>) -> Self {
+        return BusNode {
+            inner: Arc::new(NodeInner {
+                event: Event::builder().borrowed().build(),
+                capture: Event::builder().borrowed().build(),
+                parent,
+            }),
+        };
+    }
+
+    /// Returns the parent of this node, or `None` for the root.
+    pub fn parent(&self) -> Option<&BusNode<Args>> {
+        return self.inner.parent.as_ref();
+    }
+
+    /// Subscribes a handler to the messages published on this node or any of its
+    /// descendants that haven't been consumed before reaching it, in the target and bubble
+    /// phases.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(&Propagation<Args>) + Send + 'static,
+    {
+        return self.inner.event.subscribe(handler);
+    }
+
+    /// Subscribes a handler to the messages published on this node or any of its
+    /// descendants, in the capture and target phases. On their way down, these messages
+    /// reach it before any handler of the descendants, so it can consume them first.
+    pub fn subscribe_capture<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(&Propagation<Args>) + Send + 'static,
+    {
+        return self.inner.capture.subscribe(handler);
+    }
+
+    /// Unsubscribes a handler from this node, returning `true` if it was subscribed here.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.inner.event.unsubscribe(id) || self.inner.capture.unsubscribe(id);
+    }
+
+    /// Delivers `args` through the capture, target, and bubble phases, stopping after the
+    /// node on which a handler consumed it. Returns `true` if it was consumed.
+    pub fn publish(&self, args: Args) -> bool {
+        let propagation = Propagation {
+            args,
+            consumed: AtomicBool::new(false),
+            phase: AtomicU8::new(Phase::Capture as u8),
+        };
+        let ancestors: Vec<&BusNode<Args>> = core::iter::successors(self.parent(), |node| node.parent()).collect();
+
+        for node in ancestors.iter().rev() {
+            node.inner.capture.invoke(&propagation);
+            if propagation.is_consumed() {
+                return true;
+            }
+        }
+
+        propagation.enter(Phase::Target);
+        self.inner.capture.invoke(&propagation);
+        self.inner.event.invoke(&propagation);
+        if propagation.is_consumed() {
+            return true;
+        }
+
+        propagation.enter(Phase::Bubble);
+        for node in ancestors {
+            node.inner.event.invoke(&propagation);
+            if propagation.is_consumed() {
+                return true;
+            }
+        }
+        return false;
+    }
+}
+
+impl<Args> Clone for BusNode<Args> {
+    fn clone(&self) -> Self {
+        return BusNode {
+            inner: Arc::clone(&self.inner),
+        };
+    }
+}
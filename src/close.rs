@@ -0,0 +1,114 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::{Borrowed, ByClone, Cloned, Event, EventHandler, EventHandlers, HandlerCell, Subscription};
+
+// Adds a close notification to a handler.
+struct OnClose<H, C> {
+    handler: H,
+    on_close: Option<C>,
+}
+
+impl<H, C, Args> EventHandler<Args> for OnClose<H, C>
+where
+    H: EventHandler<Args>,
+    C: FnOnce() + Send,
+    Args: ?Sized,
+{
+    fn call(&mut self, args: &Args) {
+        self.handler.call(args);
+    }
+
+    fn close(&mut self) {
+        if let Some(on_close) = self.on_close.take() {
+            on_close();
+        }
+    }
+}
+
+impl<Args: ?Sized> EventHandlers<Args> {
+    // Marks the event closed and removes every handler, returning their cells in dispatch
+    // order. Returns nothing if the event was already closed.
+    fn close(&mut self) -> Vec<HandlerCell<Args>> {
+        if self.closed {
+            return Vec::new();
+        }
+        self.closed = true;
+        let cells = self.snapshot().cells.into_vec();
+        self.clear();
+        return cells;
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    /// Closes the event, signalling that it will never fire again.
+    ///
+    /// Every handler is unsubscribed, and then the close notifications of those subscribed
+    /// with `subscribe_with_close` run, in dispatch order. The notification of a handler that
+    /// is running at the time, such as the one calling `close`, runs once that call returns.
+    /// From then on, invoking the event does nothing, and `try_invoke` returns
+    /// [`DispatchError::Closed`]. Subscribing returns a subscription that matches no handler,
+    /// and the fallible `try_subscribe` returns [`EventError::Closed`]. Invocations still queued by a
+    /// [`DeliveryMode`](crate::DeliveryMode) are dropped. Closing twice does nothing.
+    ///
+    /// [`DispatchError::Closed`]: crate::DispatchError::Closed
+    /// [`EventError::Closed`]: crate::EventError::Closed
+    pub fn close(&self) {
+        yield_point!(BeforeUnsubscribe);
+        let cells = self.inner.update(|handlers| handlers.close());
+        let removed: Vec<_> = cells.iter().map(|cell| cell.id).collect();
+        self.inner.notify_unsubscribed(&removed);
+        for cell in cells.iter() {
+            cell.close();
+        }
+    }
+
+    /// Returns `true` if the event has been [closed](Self::close).
+    pub fn is_closed(&self) -> bool {
+        return self.inner.snapshot.load().closed;
+    }
+}
+
+impl<Args: Clone> Event<Args, Cloned> {
+    /// Subscribes a callback like [`subscribe`](Self::subscribe), along with `on_close`,
+    /// which is called once if the event is [closed](Self::close) while the callback is
+    /// still subscribed.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// let lines = Event::<String>::new();
+    /// lines.subscribe_with_close(|line| println!("{}", line), || println!("end of input"));
+    /// lines.invoke("hello".to_string());
+    /// lines.close();
+    /// assert_eq!(lines.subscriber_count(), 0);
+    /// ```
+    pub fn subscribe_with_close<F, C>(&self, handler: F, on_close: C) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static,
+        C: FnOnce() + Send + 'static,
+    {
+        let handler = OnClose {
+            handler: ByClone(handler),
+            on_close: Some(on_close),
+        };
+        return self.subscribe_handler(0, Box::new(handler));
+    }
+}
+
+impl<Args: ?Sized> Event<Args, Borrowed> {
+    /// Subscribes a callback like [`subscribe`](Self::subscribe), along with `on_close`,
+    /// which is called once if the event is [closed](Self::close) while the callback is
+    /// still subscribed.
+    pub fn subscribe_with_close<F, C>(&self, handler: F, on_close: C) -> Subscription
+    where
+        F: FnMut(&Args) + Send + 'static,
+        C: FnOnce() + Send + 'static,
+    {
+        let handler = OnClose {
+            handler,
+            on_close: Some(on_close),
+        };
+        return self.subscribe_handler(0, Box::new(handler));
+    }
+}
@@ -0,0 +1,176 @@
+use std::boxed::Box;
+use std::sync::Arc;
+use std::vec::Vec;
+
+use crate::sync::{Lock, Mutex};
+use crate::{Event, Property};
+
+/// A read-only value derived from other properties, recomputed whenever one of them
+/// changes.
+///
+/// [`Computed::new`] takes a tuple of up to six dependencies, each a [`Property`] or a
+/// reference to a `Property` or another `Computed`, and a function of their values. The
+/// function runs once up front and again after every change of a dependency, and the
+/// [`changed`](Self::changed) event dispatches `(old, new)` whenever the result differs
+/// from the previous one. Dropping the `Computed` stops the recomputation. Requires `std`.
+///
+/// ```
+/// use blob_event::{Computed, Property};
+///
+/// let price = Property::new(20.0);
+/// let quantity = Property::new(3);
+/// let total = Computed::new((&price, &quantity), |price, quantity| price * quantity as f64);
+/// total.changed().subscribe(|(_, total)| println!("total: {:.2}", total));
+///
+/// quantity.set(4); // Prints "total: 80.00"
+/// assert_eq!(total.get(), 80.0);
+/// ```
+///
+/// # Panics
+///
+/// A handler that reacts to the computed value changing by changing one of its
+/// dependencies would recompute it forever. As with any [`Property`], the change that
+/// closes such a cycle is detected and panics.
+pub struct Computed<T> {
+    value: Property<T>,
+    // Unsubscribes the recomputation from each dependency's change event.
+    unwatch: Vec<Box<dyn FnOnce() + Send>>,
+}
+
+impl<T> Computed<T>
+where
+    T: Clone + PartialEq + Send + 'static,
+{
+    /// Derives a value from `dependencies` through `compute`, which receives their current
+    /// values as separate arguments, in the order of the tuple.
+    pub fn new<D, F>(dependencies: D, compute: F) -> Self
+    where
+        D: Dependencies<F, T>,
+    {
+        let compute = dependencies.bind(compute);
+        let value = Property::new(compute());
+        let recompute = Mutex::new(());
+        let target = value.clone();
+        let on_change = move || {
+            // Computing and storing under one lock keeps the stored value in step with the
+            // latest dependencies when they change on several threads at once.
+            let change = {
+                let _recomputing = recompute.acquire();
+                let new = compute();
+                target.replace(new.clone()).map(|old| (old, new))
+            };
+            if let Some(change) = change {
+                target.inner.changed.invoke(change);
+            }
+        };
+        let unwatch = dependencies.watch(Arc::new(on_change));
+        return Computed { value, unwatch };
+    }
+
+    /// Returns a copy of the current value.
+    pub fn get(&self) -> T {
+        return self.value.get();
+    }
+
+    /// Returns the event dispatched with `(old, new)` every time the value changes.
+    pub fn changed(&self) -> &Event<(T, T)> {
+        return self.value.changed();
+    }
+}
+
+impl<T> Drop for Computed<T> {
+    fn drop(&mut self) {
+        for unwatch in self.unwatch.drain(..) {
+            unwatch();
+        }
+    }
+}
+
+impl<T: Clone + core::fmt::Debug> core::fmt::Debug for Computed<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return f.debug_tuple("Computed").field(&self.value.get()).finish();
+    }
+}
+
+/// Something a [`Computed`] can depend on: a [`Property`], a reference to one, or a
+/// reference to another `Computed`.
+pub trait Dependency {
+    /// The type of the dependency's value.
+    type Value: Clone + Send + 'static;
+
+    /// Returns the property holding the dependency's value.
+    fn property(&self) -> &Property<Self::Value>;
+}
+
+impl<T: Clone + Send + 'static> Dependency for Property<T> {
+    type Value = T;
+
+    fn property(&self) -> &Property<T> {
+        return self;
+    }
+}
+
+impl<T: Clone + Send + 'static> Dependency for &Property<T> {
+    type Value = T;
+
+    fn property(&self) -> &Property<T> {
+        return self;
+    }
+}
+
+impl<T: Clone + Send + 'static> Dependency for &Computed<T> {
+    type Value = T;
+
+    fn property(&self) -> &Property<T> {
+        return &self.value;
+    }
+}
+
+/// The dependencies of a [`Computed`] whose function is `F`: a tuple of one to six
+/// [`Dependency`] values, for a function taking their values in the same order.
+pub trait Dependencies<F, T> {
+    /// Returns a function computing the value from the dependencies' current values.
+    fn bind(&self, compute: F) -> Box<dyn Fn() -> T + Send + Sync>;
+
+    /// Subscribes `on_change` to every dependency's change event, returning for each one a
+    /// function that unsubscribes it.
+    fn watch(&self, on_change: Arc<dyn Fn() + Send + Sync>) -> Vec<Box<dyn FnOnce() + Send>>;
+}
+
+fn watch<T>(property: &Property<T>, on_change: &Arc<dyn Fn() + Send + Sync>) -> Box<dyn FnOnce() + Send>
+where
+    T: Clone + Send + 'static,
+{
+    let on_change = Arc::clone(on_change);
+    let event = property.changed().clone();
+    let subscription = event.subscribe(move |_| on_change());
+    return Box::new(move || {
+        event.unsubscribe(subscription);
+    });
+}
+
+macro_rules! impl_dependencies {
+    ($($name:ident $index:tt),+) => {
+        impl<F, T, $($name),+> Dependencies<F, T> for ($($name,)+)
+        where
+            $($name: Dependency,)+
+            F: Fn($($name::Value),+) -> T + Send + Sync + 'static,
+        {
+            fn bind(&self, compute: F) -> Box<dyn Fn() -> T + Send + Sync> {
+                let sources = ($(self.$index.property().clone(),)+);
+                return Box::new(move || compute($(sources.$index.get()),+));
+            }
+
+            fn watch(&self, on_change: Arc<dyn Fn() + Send + Sync>) -> Vec<Box<dyn FnOnce() + Send>> {
+                return vec![$(watch(self.$index.property(), &on_change)),+];
+            }
+        }
+    };
+}
+
+impl_dependencies!(A 0);
+impl_dependencies!(A 0, B 1);
+impl_dependencies!(A 0, B 1, C 2);
+impl_dependencies!(A 0, B 1, C 2, D 3);
+impl_dependencies!(A 0, B 1, C 2, D 3, E 4);
+impl_dependencies!(A 0, B 1, C 2, D 3, E 4, G 5);
@@ -0,0 +1,157 @@
+use alloc::sync::Arc;
+
+use crate::sync::{Lock, Mutex};
+use crate::{Cloned, Event, EventHooks, Subscription, WeakEvent};
+
+/// A view of an event that is only subscribed to it while it has subscribers of its own, as
+/// returned by [`Event::publish`].
+///
+/// The first subscription attaches a forwarding handler to the source event, and removing the
+/// last one detaches it again, so an idle `Connectable` costs the source nothing. Invocations
+/// of the source while nothing is subscribed are not seen by anyone.
+pub struct Connectable<Args> {
+    event: Event<Args>,
+    link: Arc<Link<Args>>,
+}
+
+// Keeps the forwarding subscription on the source in step with the number of subscribers.
+struct Link<Args> {
+    source: Event<Args>,
+    target: WeakEvent<Args, Cloned>,
+    forwarder: Mutex<Option<Subscription>>,
+}
+
+impl<Args> Link<Args>
+where
+    Args: Clone + Send + 'static,
+{
+    // Attaches to or detaches from the source as the current subscriber count calls for.
+    // Subscribers are counted under the lock, so racing subscriptions settle on the right
+    // state whatever order their notifications arrive in.
+    fn sync(&self) {
+        let Some(target) = self.target.upgrade() else {
+            return;
+        };
+        let mut forwarder = self.forwarder.acquire();
+        let subscribed = target.subscriber_count() > 0;
+        match *forwarder {
+            None if subscribed => *forwarder = Some(self.attach(&target)),
+            Some(id) if !subscribed => {
+                self.source.unsubscribe(id);
+                *forwarder = None;
+            }
+            _ => {}
+        }
+    }
+
+    fn attach(&self, target: &Event<Args>) -> Subscription {
+        let target = target.downgrade();
+        let source = self.source.downgrade();
+        return self.source.subscribe_with_id(move |id| {
+            move |args| match target.upgrade() {
+                Some(target) => target.invoke(args),
+                // Every handle to the connectable was dropped without unsubscribing.
+                None => {
+                    if let Some(source) = source.upgrade() {
+                        source.unsubscribe(id);
+                    }
+                }
+            }
+        });
+    }
+}
+
+impl<Args> EventHooks<Args> for Link<Args>
+where
+    Args: Clone + Send + 'static,
+{
+    fn on_subscribe(&self, _: Subscription) {
+        self.sync();
+    }
+
+    fn on_unsubscribe(&self, _: Subscription) {
+        self.sync();
+    }
+}
+
+impl<Args> Event<Args>
+where
+    Args: Clone + Send + 'static,
+{
+    /// Returns a [`Connectable`] that forwards this event's invocations, but only holds a
+    /// subscription on this event while something is subscribed to it.
+    ///
+    /// This suits derived events and bridges that shouldn't keep their source busy when no
+    /// one is listening. The `Connectable` keeps this event alive.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// let source = Event::<u32>::new();
+    /// let shared = source.publish();
+    /// assert_eq!(source.subscriber_count(), 0);
+    ///
+    /// let sub = shared.subscribe(|n| println!("got {}", n));
+    /// assert_eq!(source.subscriber_count(), 1);
+    ///
+    /// shared.unsubscribe(sub);
+    /// assert_eq!(source.subscriber_count(), 0);
+    /// ```
+    pub fn publish(&self) -> Connectable<Args> {
+        let event = Event::new();
+        let link = Arc::new(Link {
+            source: self.clone(),
+            target: event.downgrade(),
+            forwarder: Mutex::new(None),
+        });
+        event.set_hooks(Arc::clone(&link));
+        Connectable { event, link }
+    }
+}
+
+impl<Args> Connectable<Args>
+where
+    Args: Clone + Send + 'static,
+{
+    /// Subscribes a callback, attaching to the source event if this is the first
+    /// subscription.
+    ///
+    /// Returns a `Subscription` that must be used to unsubscribe later.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.event.subscribe(handler);
+    }
+
+    /// Unsubscribes a callback, detaching from the source event if it was the last one.
+    ///
+    /// Returns `true` if the subscription was found and removed, `false` otherwise.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.event.unsubscribe(id);
+    }
+
+    /// Removes all subscribers and detaches from the source event.
+    pub fn unsubscribe_all(&self) {
+        self.event.unsubscribe_all();
+    }
+
+    /// Returns the current number of subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        return self.event.subscriber_count();
+    }
+
+    /// Returns `true` while a forwarding subscription is held on the source event.
+    pub fn is_connected(&self) -> bool {
+        return self.link.forwarder.acquire().is_some();
+    }
+}
+
+impl<Args> Clone for Connectable<Args> {
+    fn clone(&self) -> Self {
+        Connectable {
+            event: self.event.clone(),
+            link: Arc::clone(&self.link),
+        }
+    }
+}
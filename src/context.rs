@@ -0,0 +1,96 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::marker::PhantomData;
+
+use crate::{Borrowed, Cloned, Event, Subscription, WeakEvent};
+
+/// Handed to handlers subscribed with `subscribe_with_context` on every call, giving them
+/// access to their own subscription.
+pub struct InvokeContext<Args: ?Sized> {
+    id: Subscription,
+    // Unsubscribing works the same for every delivery mode.
+    event: WeakEvent<Args, Cloned>,
+}
+
+impl<Args: ?Sized> InvokeContext<Args> {
+    /// Returns the subscription of the running handler.
+    pub fn subscription(&self) -> Subscription {
+        return self.id;
+    }
+
+    /// Unsubscribes the running handler from the event, returning `true` if it was still
+    /// subscribed.
+    ///
+    /// The current call runs to completion, and the handlers after it in the same dispatch
+    /// are still called. Dispatches that haven't reached the handler yet skip it, including
+    /// ones that started before it unsubscribed. The handler is dropped once it has returned
+    /// and no dispatch holds it anymore.
+    pub fn unsubscribe_self(&self) -> bool {
+        return self.event.upgrade().is_some_and(|event| event.unsubscribe(self.id));
+    }
+}
+
+impl<Args: ?Sized> core::fmt::Debug for InvokeContext<Args> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return f
+            .debug_struct("InvokeContext")
+            .field("subscription", &self.id)
+            .finish_non_exhaustive();
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    fn context(&self, id: Subscription) -> InvokeContext<Args> {
+        return InvokeContext {
+            id,
+            event: WeakEvent {
+                inner: Arc::downgrade(&self.inner),
+                delivery: PhantomData,
+            },
+        };
+    }
+}
+
+impl<Args: Clone + 'static> Event<Args, Cloned> {
+    /// Subscribes a callback like [`subscribe`](Self::subscribe) that also receives an
+    /// [`InvokeContext`], through which it can unsubscribe itself.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// let connected = Event::<u32>::new();
+    /// connected.subscribe_with_context(|peer, ctx| {
+    ///     println!("first peer: {}", peer);
+    ///     ctx.unsubscribe_self();
+    /// });
+    /// connected.invoke(7); // Prints: first peer: 7
+    /// connected.invoke(8); // Nothing, the handler removed itself
+    /// ```
+    pub fn subscribe_with_context<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Args, &InvokeContext<Args>) + Send + 'static,
+    {
+        let mut handler = handler;
+        return self.subscribe_handler_with(0, |id| {
+            let context = self.context(id);
+            Box::new(move |args: &Args| handler(args.clone(), &context))
+        });
+    }
+}
+
+impl<Args: ?Sized + 'static> Event<Args, Borrowed> {
+    /// Subscribes a callback like [`subscribe`](Self::subscribe) that also receives an
+    /// [`InvokeContext`], through which it can unsubscribe itself.
+    ///
+    /// See [`Event::subscribe_with_context`].
+    pub fn subscribe_with_context<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(&Args, &InvokeContext<Args>) + Send + 'static,
+    {
+        let mut handler = handler;
+        return self.subscribe_handler_with(0, |id| {
+            let context = self.context(id);
+            Box::new(move |args: &Args| handler(args, &context))
+        });
+    }
+}
@@ -0,0 +1,83 @@
+use alloc::boxed::Box;
+
+use crate::{Borrowed, Cloned, Event, EventHandler, Subscription};
+
+/// What a handler subscribed with [`subscribe_ctl`](Event::subscribe_ctl) tells the
+/// dispatch to do once it returns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum HandlerControl {
+    /// Carry on with the next handler.
+    #[default]
+    Continue,
+    /// Skip the handlers after this one for the current invocation. The event's mirrors
+    /// still receive it, and the [`DispatchReport`](crate::DispatchReport) says it was
+    /// [stopped](crate::DispatchReport::stopped).
+    ///
+    /// Handlers run concurrently by `invoke_parallel` have no "after", so it ignores this.
+    StopPropagation,
+    /// Unsubscribe this handler, then carry on with the next one. The handler isn't called
+    /// again, as with [`InvokeContext::unsubscribe_self`](crate::InvokeContext::unsubscribe_self).
+    Unsubscribe,
+}
+
+// Adapts a closure returning a `HandlerControl`, which the dispatch acts on.
+struct Controlled<F>(F);
+
+impl<F, Args> EventHandler<Args> for Controlled<F>
+where
+    F: FnMut(&Args) -> HandlerControl + Send,
+    Args: ?Sized,
+{
+    fn call(&mut self, args: &Args) {
+        (self.0)(args);
+    }
+
+    fn call_ctl(&mut self, args: &Args) -> HandlerControl {
+        return (self.0)(args);
+    }
+}
+
+impl<Args: Clone + 'static> Event<Args, Cloned> {
+    /// Subscribes a callback whose return value steers the dispatch: it can let the
+    /// remaining handlers run, stop them from running for this invocation, or unsubscribe
+    /// itself.
+    ///
+    /// ```
+    /// use blob_event::{Event, HandlerControl};
+    ///
+    /// let key_pressed = Event::<char>::new();
+    /// key_pressed.subscribe_ctl(|key| {
+    ///     if key == '\u{1b}' {
+    ///         println!("menu closed");
+    ///         return HandlerControl::StopPropagation; // The game doesn't see Escape
+    ///     }
+    ///     HandlerControl::Continue
+    /// });
+    /// key_pressed.subscribe_ctl(|key| {
+    ///     println!("first key: {}", key);
+    ///     HandlerControl::Unsubscribe // A one-shot handler
+    /// });
+    ///
+    /// key_pressed.invoke('w'); // Prints: first key: w
+    /// key_pressed.invoke('a'); // Nothing, the one-shot handler is gone
+    /// ```
+    pub fn subscribe_ctl<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Args) -> HandlerControl + Send + 'static,
+    {
+        let mut handler = handler;
+        return self.subscribe_handler(0, Box::new(Controlled(move |args: &Args| handler(args.clone()))));
+    }
+}
+
+impl<Args: ?Sized + 'static> Event<Args, Borrowed> {
+    /// Subscribes a callback whose return value steers the dispatch.
+    ///
+    /// See [`Event::subscribe_ctl`].
+    pub fn subscribe_ctl<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(&Args) -> HandlerControl + Send + 'static,
+    {
+        return self.subscribe_handler(0, Box::new(Controlled(handler)));
+    }
+}
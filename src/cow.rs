@@ -0,0 +1,50 @@
+use alloc::borrow::Cow;
+use alloc::boxed::Box;
+
+use crate::{Cloned, Event, EventHandler, Subscription};
+
+// Adapts a closure taking a `Cow` of the arguments, which borrows them until the closure
+// asks for ownership.
+struct ByCow<F>(F);
+
+impl<F, Args> EventHandler<Args> for ByCow<F>
+where
+    F: for<'a> FnMut(Cow<'a, Args>) + Send,
+    Args: Clone,
+{
+    fn call(&mut self, args: &Args) {
+        (self.0)(Cow::Borrowed(args));
+    }
+}
+
+impl<Args: Clone + 'static> Event<Args, Cloned> {
+    /// Subscribes a callback that receives the arguments as a [`Cow`], borrowing them.
+    ///
+    /// Unlike [`subscribe`](Self::subscribe), nothing is cloned for the callback unless it
+    /// asks for ownership, with [`into_owned`](Cow::into_owned) or
+    /// [`to_mut`](Cow::to_mut). Handlers that mostly read the payload and only sometimes
+    /// keep it pay for the clone just then, on an event whose other handlers still get their
+    /// own copy.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    /// use std::borrow::Cow;
+    ///
+    /// let line_read = Event::<String>::new();
+    /// let mut errors = Vec::new();
+    /// line_read.subscribe_cow(move |line: Cow<String>| {
+    ///     if line.starts_with("ERROR") {
+    ///         errors.push(line.into_owned()); // Only error lines are cloned
+    ///     }
+    /// });
+    ///
+    /// line_read.invoke("INFO started".to_string());
+    /// line_read.invoke("ERROR disk full".to_string());
+    /// ```
+    pub fn subscribe_cow<F>(&self, handler: F) -> Subscription
+    where
+        F: for<'a> FnMut(Cow<'a, Args>) + Send + 'static,
+    {
+        return self.subscribe_handler(0, Box::new(ByCow(handler)));
+    }
+}
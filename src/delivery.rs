@@ -0,0 +1,22 @@
+use alloc::sync::Arc;
+
+use crate::Event;
+
+/// Delivery mode where every handler receives its own clone of the arguments.
+///
+/// This is the default mode of [`Event`] and suits small or cheaply cloned payloads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Cloned;
+
+/// Delivery mode where handlers receive a reference to the arguments.
+///
+/// Nothing is cloned during dispatch, and the arguments may be unsized, so an
+/// `Event<str, Borrowed>` takes `&str` handlers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Borrowed;
+
+/// An event whose handlers receive an `Arc` of the arguments.
+///
+/// Invoking it only bumps a reference count per handler, which keeps large payloads cheap
+/// to share while still handing each handler an owned value.
+pub type SharedEvent<Args> = Event<Arc<Args>, Cloned>;
@@ -0,0 +1,93 @@
+use std::collections::VecDeque;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Condvar, Mutex};
+
+use crate::{Event, pool};
+
+type Job = Box<dyn FnOnce() + Send>;
+
+// The detached invocations of one event that haven't finished yet. They are run one at a
+// time, in the order they were issued, by a single drain task on the worker pool.
+pub(crate) struct DetachedQueue {
+    state: Mutex<DetachedState>,
+    idle: Condvar,
+}
+
+struct DetachedState {
+    jobs: VecDeque<Job>,
+    // Whether a drain task is currently scheduled or running.
+    draining: bool,
+}
+
+impl DetachedQueue {
+    pub(crate) fn new() -> Self {
+        DetachedQueue {
+            state: Mutex::new(DetachedState {
+                jobs: VecDeque::new(),
+                draining: false,
+            }),
+            idle: Condvar::new(),
+        }
+    }
+}
+
+impl<Args, D> Event<Args, D>
+where
+    Args: Send + 'static,
+    D: Send + 'static,
+{
+    /// Hands the dispatch of `args` to the crate's worker threads and returns immediately.
+    ///
+    /// Detached invocations of the same event are dispatched one at a time, in the order they
+    /// were issued; invocations of different events may run concurrently. Use
+    /// [`flush`](Self::flush) to wait for them to finish.
+    ///
+    /// A panicking handler ends the dispatch it was part of and is reported by the panic
+    /// hook, but doesn't affect later invocations.
+    pub fn invoke_detached(&self, args: Args) {
+        let event = self.clone();
+        let job: Job = Box::new(move || event.dispatch_watched(&args, true));
+
+        let queue = &self.inner.detached;
+        let mut state = queue.state.lock().unwrap();
+        state.jobs.push_back(job);
+        if !state.draining {
+            state.draining = true;
+            let event = self.clone();
+            pool::spawn(move || event.drain_detached());
+        }
+    }
+
+    /// Blocks until every invocation issued through [`invoke_detached`](Self::invoke_detached)
+    /// so far has finished.
+    ///
+    /// Must not be called from one of this event's handlers while it is being dispatched by a
+    /// detached invocation, since that dispatch can't finish until the handler returns.
+    pub fn flush(&self) {
+        let queue = &self.inner.detached;
+        let mut state = queue.state.lock().unwrap();
+        while state.draining {
+            state = queue.idle.wait(state).unwrap();
+        }
+    }
+
+    fn drain_detached(&self) {
+        let queue = &self.inner.detached;
+        loop {
+            let job = {
+                let mut state = queue.state.lock().unwrap();
+                match state.jobs.pop_front() {
+                    Some(job) => job,
+                    None => {
+                        state.draining = false;
+                        queue.idle.notify_all();
+                        return;
+                    }
+                }
+            };
+            // Handler panics are caught by the dispatch; this only guards the worker against
+            // panics elsewhere, such as in a slow-handler report.
+            let _ = panic::catch_unwind(AssertUnwindSafe(job));
+        }
+    }
+}
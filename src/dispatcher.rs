@@ -0,0 +1,338 @@
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, SyncSender};
+use std::sync::{Arc, Condvar, Mutex, OnceLock, Weak};
+use std::thread::{self, ThreadId};
+
+use crate::{Cloned, Event, EventInner};
+
+/// When an event's handlers run relative to `invoke`.
+///
+/// Chosen per event with [`EventBuilder::delivery_mode`](crate::EventBuilder::delivery_mode).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum DeliveryMode {
+    /// Handlers run on the invoking thread before `invoke` returns.
+    #[default]
+    Immediate,
+    /// `invoke` pushes the arguments into a queue holding up to `capacity` invocations, and
+    /// a thread dedicated to the event dispatches them in order. When the queue is full,
+    /// `backpressure` decides what `invoke` does. A capacity of zero hands every invocation
+    /// straight to the dispatcher thread.
+    Queued {
+        /// The number of invocations that may wait in the queue.
+        capacity: usize,
+        /// What `invoke` does when the queue is full.
+        backpressure: Backpressure,
+    },
+    /// Like `Queued`, but only the newest arguments are kept: invocations made while the
+    /// dispatcher thread is busy replace each other, and the handlers then run once with the
+    /// latest ones. Suits events where only the current value matters, such as window resizes
+    /// or progress updates. `invoke` never waits.
+    Coalesced,
+    /// `invoke` queues the arguments without running any handler, and
+    /// [`Event::pump`](crate::Event::pump) runs the queued invocations on the thread that calls
+    /// it. The first thread to pump the event becomes its designated thread, such as a GUI
+    /// framework's main thread, and handlers invoked through `invoke` only ever run there.
+    /// [`try_invoke`](crate::Event::try_invoke), [`invoke_counted`](crate::Event::invoke_counted)
+    /// and [`invoke_mut`](crate::Event::invoke_mut) still run them on the calling thread, as
+    /// on any queued event.
+    Pumped,
+}
+
+/// What invoking a [`DeliveryMode::Queued`] event does when its queue is full.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum Backpressure {
+    /// `invoke` waits until the dispatcher thread makes room, slowing producers down to the
+    /// pace of the handlers.
+    ///
+    /// The handlers can't wait for room themselves, since they run on the dispatcher thread:
+    /// the invocations they make are set aside regardless of the capacity, and dispatched
+    /// right after the running one, ahead of the queue.
+    #[default]
+    Block,
+    /// `invoke` discards the arguments and returns, so producers are never held up.
+    Drop,
+}
+
+// Hands the arguments of a queued invocation over to the event's dispatcher thread.
+pub(crate) trait Enqueue<Args: ?Sized>: Send + Sync {
+    fn enqueue(&self, args: Args)
+    where
+        Args: Sized;
+
+    // The delivery mode the dispatcher implements.
+    fn mode(&self) -> DeliveryMode;
+
+    // How many invocations are waiting to be dispatched, if the queue can tell.
+    fn pending(&self) -> Option<usize> {
+        return None;
+    }
+
+    // Runs the invocations waiting for `Event::pump` through `dispatch`, returning how many
+    // there were. Only pumped events have any.
+    fn pump(&self, _dispatch: &mut dyn FnMut(&Args)) -> usize {
+        return 0;
+    }
+}
+
+// Dispatches `args` from a dispatcher thread. Returns `false` once the event has been dropped.
+fn dispatch_on_thread<Args>(event: &Weak<EventInner<Args>>, args: &Args) -> bool
+where
+    Args: Clone + Send + 'static,
+{
+    let Some(inner) = event.upgrade() else {
+        return false;
+    };
+    let event = Event::<Args, Cloned> {
+        inner,
+        delivery: PhantomData,
+    };
+    // Handler panics are caught by the dispatch, so the thread and the rest of the queue keep
+    // going; this also guards against panics elsewhere, such as in a slow-handler report.
+    let _ = panic::catch_unwind(AssertUnwindSafe(|| event.dispatch_watched(args, true)));
+    return true;
+}
+
+// Starts the dispatcher of an event that is being created, given a handle to that event. Kept
+// as a function pointer by the builder, which is the only place that knows `Args` can be sent
+// to another thread.
+pub(crate) type StartDispatcher<Args> = fn(Weak<EventInner<Args>>, DeliveryMode) -> Option<Box<dyn Enqueue<Args>>>;
+
+// The sending end of an event's queue. Dropping it, which happens when the event is dropped,
+// ends the dispatcher thread; invocations still in the queue are discarded.
+struct Dispatcher<Args> {
+    sender: SyncSender<Args>,
+    capacity: usize,
+    backpressure: Backpressure,
+    // The dispatcher thread, and the invocations its handlers made, which it dispatches
+    // before taking the next one from the queue.
+    thread: ThreadId,
+    set_aside: Arc<Mutex<VecDeque<Args>>>,
+}
+
+impl<Args> Dispatcher<Args>
+where
+    Args: Clone + Send + 'static,
+{
+    fn start(event: Weak<EventInner<Args>>, capacity: usize, backpressure: Backpressure) -> Self {
+        let (sender, receiver) = mpsc::sync_channel::<Args>(capacity);
+        let set_aside = Arc::new(Mutex::new(VecDeque::new()));
+        let dispatching = Arc::clone(&set_aside);
+        let handle = thread::Builder::new()
+            .name("blob-event-dispatcher".to_string())
+            .spawn(move || {
+                for mut args in receiver {
+                    loop {
+                        if !dispatch_on_thread(&event, &args) {
+                            return;
+                        }
+                        let next = dispatching.lock().unwrap().pop_front();
+                        let Some(next) = next else {
+                            break;
+                        };
+                        args = next;
+                    }
+                }
+            })
+            .expect("failed to spawn a blob-event dispatcher thread");
+        Dispatcher {
+            sender,
+            capacity,
+            backpressure,
+            thread: handle.thread().id(),
+            set_aside,
+        }
+    }
+}
+
+impl<Args: Send> Enqueue<Args> for Dispatcher<Args> {
+    fn enqueue(&self, args: Args) {
+        // Sending only fails once the dispatcher thread has stopped, which means the event
+        // is being dropped and there is no one left to deliver to.
+        match self.backpressure {
+            // Only the dispatcher thread makes room in the queue, so it would wait for itself.
+            Backpressure::Block if thread::current().id() == self.thread => {
+                self.set_aside.lock().unwrap().push_back(args);
+            }
+            Backpressure::Block => {
+                let _ = self.sender.send(args);
+            }
+            Backpressure::Drop => {
+                let _ = self.sender.try_send(args);
+            }
+        }
+    }
+
+    fn mode(&self) -> DeliveryMode {
+        return DeliveryMode::Queued {
+            capacity: self.capacity,
+            backpressure: self.backpressure,
+        };
+    }
+}
+
+// The dispatcher of a `DeliveryMode::Coalesced` event: a single slot holding the newest
+// arguments that haven't been dispatched yet.
+struct Coalescer<Args> {
+    latest: Arc<Latest<Args>>,
+}
+
+struct Latest<Args> {
+    state: Mutex<LatestState<Args>>,
+    ready: Condvar,
+}
+
+struct LatestState<Args> {
+    pending: Option<Args>,
+    // Set when the event is dropped, to stop the dispatcher thread.
+    closed: bool,
+}
+
+impl<Args> Coalescer<Args>
+where
+    Args: Clone + Send + 'static,
+{
+    fn start(event: Weak<EventInner<Args>>) -> Self {
+        let latest = Arc::new(Latest {
+            state: Mutex::new(LatestState {
+                pending: None,
+                closed: false,
+            }),
+            ready: Condvar::new(),
+        });
+        let shared = Arc::clone(&latest);
+        thread::Builder::new()
+            .name("blob-event-dispatcher".to_string())
+            .spawn(move || {
+                while let Some(args) = shared.take() {
+                    if !dispatch_on_thread(&event, &args) {
+                        return;
+                    }
+                }
+            })
+            .expect("failed to spawn a blob-event dispatcher thread");
+        Coalescer { latest }
+    }
+}
+
+impl<Args> Latest<Args> {
+    // Waits for arguments to dispatch, or returns `None` once the event is gone.
+    fn take(&self) -> Option<Args> {
+        let mut state = self.state.lock().unwrap();
+        loop {
+            if state.closed {
+                return None;
+            }
+            if let Some(args) = state.pending.take() {
+                return Some(args);
+            }
+            state = self.ready.wait(state).unwrap();
+        }
+    }
+}
+
+impl<Args: Send> Enqueue<Args> for Coalescer<Args> {
+    fn enqueue(&self, args: Args) {
+        // The arguments this replaces are dropped after the lock is released, in case their
+        // destructor does anything with this event.
+        let _replaced = self.latest.state.lock().unwrap().pending.replace(args);
+        self.latest.ready.notify_one();
+    }
+
+    fn mode(&self) -> DeliveryMode {
+        return DeliveryMode::Coalesced;
+    }
+
+    fn pending(&self) -> Option<usize> {
+        return Some(self.latest.state.lock().unwrap().pending.iter().count());
+    }
+}
+
+impl<Args> Drop for Coalescer<Args> {
+    fn drop(&mut self) {
+        self.latest.state.lock().unwrap().closed = true;
+        self.latest.ready.notify_one();
+    }
+}
+
+// The queue of a `DeliveryMode::Pumped` event, emptied by `Event::pump`.
+struct Pump<Args> {
+    pending: Mutex<VecDeque<Args>>,
+    // The thread that pumped the event first, the only one allowed to pump it afterwards.
+    owner: OnceLock<ThreadId>,
+}
+
+impl<Args: Send> Enqueue<Args> for Pump<Args> {
+    fn enqueue(&self, args: Args) {
+        self.pending.lock().unwrap().push_back(args);
+    }
+
+    fn mode(&self) -> DeliveryMode {
+        return DeliveryMode::Pumped;
+    }
+
+    fn pending(&self) -> Option<usize> {
+        return Some(self.pending.lock().unwrap().len());
+    }
+
+    fn pump(&self, dispatch: &mut dyn FnMut(&Args)) -> usize {
+        let current = thread::current().id();
+        let owner = *self.owner.get_or_init(|| current);
+        assert_eq!(
+            owner, current,
+            "event pumped from a thread other than its designated thread"
+        );
+
+        // Invocations queued by the handlers during this pump wait for the next one, so a
+        // handler that invokes its own event can't keep the pump going forever.
+        let count = self.pending.lock().unwrap().len();
+        for _ in 0..count {
+            let Some(args) = self.pending.lock().unwrap().pop_front() else {
+                break;
+            };
+            dispatch(&args);
+        }
+        return count;
+    }
+}
+
+pub(crate) fn start<Args>(event: Weak<EventInner<Args>>, mode: DeliveryMode) -> Option<Box<dyn Enqueue<Args>>>
+where
+    Args: Clone + Send + 'static,
+{
+    match mode {
+        DeliveryMode::Immediate => return None,
+        DeliveryMode::Queued { capacity, backpressure } => {
+            return Some(Box::new(Dispatcher::start(event, capacity, backpressure)));
+        }
+        DeliveryMode::Coalesced => return Some(Box::new(Coalescer::start(event))),
+        DeliveryMode::Pumped => {
+            return Some(Box::new(Pump {
+                pending: Mutex::new(VecDeque::new()),
+                owner: OnceLock::new(),
+            }));
+        }
+    }
+}
+
+impl<Args: Clone + 'static> Event<Args, Cloned> {
+    /// Runs the invocations queued on an event created with [`DeliveryMode::Pumped`], on the
+    /// calling thread, and returns how many there were.
+    ///
+    /// Invocations made while pumping, including by the handlers themselves, are left for the
+    /// next call. Events in other delivery modes have nothing to pump and return 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event has already been pumped from another thread. A panicking handler
+    /// unwinds out of `pump`, and the invocations after it stay queued.
+    pub fn pump(&self) -> usize {
+        let Some(queue) = &self.inner.queue else {
+            return 0;
+        };
+        return queue.pump(&mut |args| self.dispatch_watched(args, false));
+    }
+}
@@ -0,0 +1,86 @@
+use alloc::string::{String, ToString};
+use core::any::Any;
+use core::error::Error;
+use core::fmt;
+
+use crate::Subscription;
+
+/// The reason an operation on an event's subscriptions failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EventError {
+    /// A panic while the subscription list was being changed, such as a handler's destructor
+    /// panicking on unsubscribe, left the list poisoned. The operation was not performed.
+    ///
+    /// The non-fallible methods recover from this and clear the poisoning, after which the
+    /// fallible ones succeed again. Only returned with the default `std::sync::Mutex` lock
+    /// backend; the others don't poison.
+    Poisoned,
+    /// The event has been [closed](crate::Event::close), so nothing can subscribe to it.
+    Closed,
+    /// The event already has as many subscribers as its
+    /// [limit](crate::EventBuilder::max_subscribers) allows, or as a
+    /// [`StaticEvent`](crate::StaticEvent) has room for.
+    TooManySubscribers,
+    /// A batched subscription was configured with a batch size of zero.
+    InvalidBatchSize,
+}
+
+impl fmt::Display for EventError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EventError::Poisoned => write!(f, "event subscription list is poisoned"),
+            EventError::Closed => write!(f, "event is closed"),
+            EventError::TooManySubscribers => write!(f, "event has reached its subscriber limit"),
+            EventError::InvalidBatchSize => write!(f, "batch size must be at least 1"),
+        }
+    }
+}
+
+impl Error for EventError {}
+
+/// The reason a dispatch did not complete.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DispatchError {
+    /// A handler panicked. Handlers after it in the dispatch were not called.
+    HandlerPanicked {
+        /// The subscription whose handler panicked.
+        subscription: Subscription,
+        /// The panic message, if it was a string.
+        message: Option<String>,
+    },
+    /// The event has been [closed](crate::Event::close), so nothing was dispatched.
+    Closed,
+    /// The dispatch would have nested deeper than the event's
+    /// [recursion limit](crate::EventBuilder::max_recursion_depth), so it was dropped.
+    RecursionLimit,
+}
+
+impl DispatchError {
+    pub(crate) fn handler_panicked(subscription: Subscription, payload: &(dyn Any + Send)) -> Self {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned());
+        DispatchError::HandlerPanicked { subscription, message }
+    }
+}
+
+impl fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DispatchError::HandlerPanicked {
+                subscription,
+                message: Some(message),
+            } => write!(f, "handler for {:?} panicked: {}", subscription, message),
+            DispatchError::HandlerPanicked { subscription, .. } => {
+                write!(f, "handler for {:?} panicked", subscription)
+            }
+            DispatchError::Closed => write!(f, "event is closed"),
+            DispatchError::RecursionLimit => write!(f, "event exceeded its recursion limit"),
+        }
+    }
+}
+
+impl Error for DispatchError {}
@@ -0,0 +1,104 @@
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+
+use crate::Event;
+use crate::sync::{Lock, Mutex};
+
+/// A double-buffered queue in front of an [`Event`], for game loops and other code that
+/// processes events once per frame.
+///
+/// Invocations are [`push`](Self::push)ed from any thread into a front buffer, and
+/// [`drain`](Self::drain) swaps that buffer for an empty one and dispatches everything it
+/// held to the event's subscribers. The lock is only held for the push and the swap, never
+/// while handlers run, so producers aren't held up by a slow frame and the time a frame spends
+/// in handlers depends only on what was pushed before it started.
+///
+/// Clones share the front buffer and the event, so a clone can be handed to every producer
+/// thread while the game loop keeps one for draining.
+///
+/// ```
+/// use blob_event::EventQueue;
+/// use std::sync::{Arc, Mutex};
+///
+/// let mut collisions = EventQueue::<(u32, u32)>::new();
+/// let hits = Arc::new(Mutex::new(Vec::new()));
+///
+/// let hits_clone = Arc::clone(&hits);
+/// collisions.event().subscribe(move |pair| hits_clone.lock().unwrap().push(pair));
+///
+/// let physics = collisions.clone();
+/// std::thread::spawn(move || physics.push((1, 2))).join().unwrap();
+/// assert!(hits.lock().unwrap().is_empty());
+///
+/// // Once per frame
+/// assert_eq!(collisions.drain(), 1);
+/// assert_eq!(*hits.lock().unwrap(), vec![(1, 2)]);
+/// ```
+pub struct EventQueue<Args> {
+    event: Event<Args>,
+    front: Arc<Mutex<Vec<Args>>>,
+    // The buffer being dispatched by `drain`, kept between frames so its allocation is reused.
+    back: Vec<Args>,
+}
+
+impl<Args: Clone> EventQueue<Args> {
+    /// Creates an empty queue in front of a new event.
+    pub fn new() -> Self {
+        Self::with_event(Event::new())
+    }
+
+    /// Creates an empty queue in front of `event`, whose existing subscribers receive what is
+    /// drained.
+    pub fn with_event(event: Event<Args>) -> Self {
+        EventQueue {
+            event,
+            front: Arc::new(Mutex::new(Vec::new())),
+            back: Vec::new(),
+        }
+    }
+
+    /// Returns the event that drained invocations are dispatched to, for subscribing to it.
+    pub fn event(&self) -> &Event<Args> {
+        return &self.event;
+    }
+
+    /// Queues an invocation for the next [`drain`](Self::drain).
+    pub fn push(&self, args: Args) {
+        self.front.acquire().push(args);
+    }
+
+    /// Returns the number of invocations waiting for the next [`drain`](Self::drain).
+    pub fn pending(&self) -> usize {
+        return self.front.acquire().len();
+    }
+
+    /// Dispatches every invocation pushed since the last drain, in the order they were
+    /// pushed, and returns how many there were.
+    ///
+    /// Invocations pushed while draining, including by the handlers, wait for the next drain.
+    /// If a handler panics, the rest of this drain's invocations are discarded.
+    pub fn drain(&mut self) -> usize {
+        core::mem::swap(&mut *self.front.acquire(), &mut self.back);
+        let count = self.back.len();
+        for args in self.back.drain(..) {
+            self.event.invoke(args);
+        }
+        return count;
+    }
+}
+
+impl<Args> Clone for EventQueue<Args> {
+    fn clone(&self) -> Self {
+        EventQueue {
+            event: self.event.clone(),
+            front: Arc::clone(&self.front),
+            back: Vec::new(),
+        }
+    }
+}
+
+impl<Args: Clone> Default for EventQueue<Args> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,112 @@
+use std::boxed::Box;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::timer::{self, Scheduler};
+use crate::{Borrowed, ByClone, Cloned, Event, EventHandler, HandlerControl, Subscription};
+
+// Adapts a handler that isn't called anymore once `deadline` has passed on `scheduler`'s
+// clock, even if the timer removing it hasn't fired yet.
+struct Expiring<H> {
+    handler: H,
+    deadline: Instant,
+    scheduler: Arc<dyn Scheduler>,
+}
+
+impl<H> Expiring<H> {
+    fn expired(&self) -> bool {
+        return self.scheduler.now() >= self.deadline;
+    }
+}
+
+impl<H, Args> EventHandler<Args> for Expiring<H>
+where
+    H: EventHandler<Args>,
+    Args: ?Sized,
+{
+    fn call(&mut self, args: &Args) {
+        if !self.expired() {
+            self.handler.call(args);
+        }
+    }
+
+    fn call_ctl(&mut self, args: &Args) -> HandlerControl {
+        if self.expired() {
+            return HandlerControl::Unsubscribe;
+        }
+        return self.handler.call_ctl(args);
+    }
+}
+
+impl<Args, D> Event<Args, D>
+where
+    Args: ?Sized + 'static,
+    D: Send + Sync + 'static,
+{
+    // Subscribes `handler` until `duration` has passed, after which the scheduler of the
+    // calling thread unsubscribes it.
+    fn subscribe_expiring<H>(&self, duration: Duration, handler: H) -> Subscription
+    where
+        H: EventHandler<Args> + 'static,
+    {
+        let scheduler = timer::current();
+        let deadline = scheduler.now() + duration;
+        let id = self.subscribe_handler(
+            0,
+            Box::new(Expiring {
+                handler,
+                deadline,
+                scheduler: Arc::clone(&scheduler),
+            }),
+        );
+        let event = self.downgrade();
+        scheduler.schedule(
+            deadline,
+            Box::new(move || {
+                if let Some(event) = event.upgrade() {
+                    event.unsubscribe(id);
+                }
+            }),
+        );
+        return id;
+    }
+}
+
+impl<Args: Clone + 'static> Event<Args, Cloned> {
+    /// Subscribes a callback for a limited time: once `duration` has passed, the callback
+    /// is unsubscribed on its own. Requires `std`.
+    ///
+    /// The callback is never called after the deadline, even by a dispatch that is already
+    /// running. The time is kept by the [`Scheduler`] of the calling
+    /// thread, so it follows a virtual clock set with [`with_scheduler`](crate::with_scheduler).
+    /// The subscription can still be removed earlier with [`unsubscribe`](Self::unsubscribe).
+    ///
+    /// ```
+    /// use blob_event::Event;
+    /// use std::time::Duration;
+    ///
+    /// let clicked = Event::<(i32, i32)>::new();
+    /// // React to clicks on the toast while it is shown
+    /// clicked.subscribe_for(Duration::from_secs(5), |(x, y)| println!("toast clicked at {}, {}", x, y));
+    /// clicked.invoke((10, 20));
+    /// ```
+    pub fn subscribe_for<F>(&self, duration: Duration, handler: F) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.subscribe_expiring(duration, ByClone(handler));
+    }
+}
+
+impl<Args: ?Sized + 'static> Event<Args, Borrowed> {
+    /// Subscribes a callback that is unsubscribed on its own once `duration` has passed.
+    /// Requires `std`.
+    ///
+    /// See [`Event::subscribe_for`].
+    pub fn subscribe_for<F>(&self, duration: Duration, handler: F) -> Subscription
+    where
+        F: FnMut(&Args) + Send + 'static,
+    {
+        return self.subscribe_expiring(duration, handler);
+    }
+}
@@ -0,0 +1,116 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::sync::{Lock, Mutex};
+use crate::{Borrowed, Event, EventHandler, Subscription};
+
+/// An event whose handlers can fail.
+///
+/// Handlers return `Result<(), E>`. A failing handler doesn't stop the dispatch: every
+/// handler runs, and [`invoke`](Self::invoke) returns the errors together with the
+/// subscriptions that produced them, so the caller decides what to do about them.
+///
+/// Handlers receive their own clone of the arguments, as with the default
+/// [`Cloned`](crate::Cloned) mode of [`Event`], and subscriptions behave the same way.
+///
+/// ```
+/// use blob_event::FallibleEvent;
+///
+/// let save = FallibleEvent::<String, String>::new();
+/// save.subscribe(|path| Ok(println!("saved {}", path)));
+/// let disk = save.subscribe(|_| Err("disk full".to_string()));
+///
+/// let failures = save.invoke("notes.txt".to_string());
+/// assert_eq!(failures, vec![(disk, "disk full".to_string())]);
+/// ```
+pub struct FallibleEvent<Args, E> {
+    event: Event<Attempt<Args, E>, Borrowed>,
+}
+
+// What a fallible invocation hands to every handler: the arguments, and the place to leave
+// an error.
+struct Attempt<Args, E> {
+    args: Args,
+    failures: Mutex<Vec<(Subscription, E)>>,
+}
+
+struct Fallible<F> {
+    id: Subscription,
+    handler: F,
+}
+
+impl<F, Args, E> EventHandler<Attempt<Args, E>> for Fallible<F>
+where
+    F: FnMut(Args) -> Result<(), E> + Send,
+    Args: Clone,
+{
+    fn call(&mut self, attempt: &Attempt<Args, E>) {
+        if let Err(error) = (self.handler)(attempt.args.clone()) {
+            attempt.failures.acquire().push((self.id, error));
+        }
+    }
+}
+
+impl<Args: Clone, E> FallibleEvent<Args, E> {
+    /// Creates a new event with no subscribers.
+    pub fn new() -> Self {
+        FallibleEvent { event: Event::empty() }
+    }
+
+    /// Subscribes a fallible callback to this event.
+    ///
+    /// Returns a `Subscription` that must be used to unsubscribe later.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Args) -> Result<(), E> + Send + 'static,
+    {
+        return self
+            .event
+            .subscribe_handler_with(0, |id| Box::new(Fallible { id, handler }));
+    }
+
+    /// Unsubscribes a callback from this event.
+    ///
+    /// Returns `true` if the subscription was found and removed, `false` otherwise.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.event.unsubscribe(id);
+    }
+
+    /// Removes all subscribers from this event.
+    pub fn unsubscribe_all(&self) {
+        self.event.unsubscribe_all();
+    }
+
+    /// Returns the current number of subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        return self.event.subscriber_count();
+    }
+
+    /// Triggers the event, calling every subscribed handler with a clone of `args`, and
+    /// returns the errors of the handlers that failed, in the order they ran.
+    ///
+    /// Subscription changes made during dispatch are handled the same way as by
+    /// [`Event::invoke`].
+    pub fn invoke(&self, args: Args) -> Vec<(Subscription, E)> {
+        let attempt = Attempt {
+            args,
+            failures: Mutex::new(Vec::new()),
+        };
+        self.event.invoke(&attempt);
+        return core::mem::take(&mut *attempt.failures.acquire());
+    }
+}
+
+impl<Args, E> Clone for FallibleEvent<Args, E> {
+    fn clone(&self) -> Self {
+        FallibleEvent {
+            event: self.event.clone(),
+        }
+    }
+}
+
+impl<Args: Clone, E> Default for FallibleEvent<Args, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,228 @@
+use std::cell::Cell;
+use std::rc::Rc;
+use std::vec::Vec;
+
+use ::godot::builtin::{Callable, Signal, Variant};
+use ::godot::global::Error;
+use ::godot::meta::{FromGodot, ToGodot};
+
+use crate::{LocalEvent, Subscription};
+
+/// Arguments that can cross a Godot signal, as a tuple of up to five values that convert to
+/// and from [`Variant`].
+///
+/// Implemented for `()` and for tuples whose elements implement [`ToGodot`] and
+/// [`FromGodot`], so a signal `hit(damage: int, source: Node)` matches a
+/// `LocalEvent<(i64, Gd<Node>)>`.
+pub trait SignalArgs: Sized {
+    /// Converts the arguments into the signal's arguments.
+    fn to_variants(&self) -> Vec<Variant>;
+
+    /// Converts a signal's arguments back, or returns `None` if there are too few of them or
+    /// one has the wrong type.
+    fn from_variants(args: &[&Variant]) -> Option<Self>;
+}
+
+macro_rules! impl_signal_args {
+    ($($name:ident $index:tt),*) => {
+        impl<$($name),*> SignalArgs for ($($name,)*)
+        where
+            $($name: ToGodot + FromGodot,)*
+        {
+            fn to_variants(&self) -> Vec<Variant> {
+                return vec![$(self.$index.to_variant()),*];
+            }
+
+            #[allow(unused_variables)]
+            fn from_variants(args: &[&Variant]) -> Option<Self> {
+                return Some(($($name::try_from_variant(args.get($index)?).ok()?,)*));
+            }
+        }
+    };
+}
+
+impl_signal_args!();
+impl_signal_args!(A 0);
+impl_signal_args!(A 0, B 1);
+impl_signal_args!(A 0, B 1, C 2);
+impl_signal_args!(A 0, B 1, C 2, D 3);
+impl_signal_args!(A 0, B 1, C 2, D 3, E 4);
+
+/// The link between a [`LocalEvent`] and a Godot signal made by
+/// [`bind_signal`](LocalEvent::bind_signal). Dropping it leaves the two bound; call
+/// [`unbind`](Self::unbind) to separate them.
+pub struct SignalBinding<Args> {
+    event: LocalEvent<Args>,
+    subscription: Subscription,
+    signal: Signal,
+    callable: Callable,
+}
+
+impl<Args: SignalArgs + Clone + 'static> SignalBinding<Args> {
+    /// Separates the event and the signal, so that neither triggers the other anymore.
+    pub fn unbind(self) {
+        self.event.unsubscribe(self.subscription);
+        if self.signal.is_connected(&self.callable) {
+            self.signal.disconnect(&self.callable);
+        }
+    }
+}
+
+impl<Args> LocalEvent<Args>
+where
+    Args: SignalArgs + Clone + 'static,
+{
+    /// Binds this event to a Godot signal, such as one returned by
+    /// [`Signal::from_object_signal`]: invoking the event emits the signal, and emitting the
+    /// signal, from Rust or GDScript, invokes the event.
+    ///
+    /// Arguments are converted through [`Variant`]; an emission whose arguments don't convert
+    /// to `Args` is ignored. An emission coming from Godot isn't echoed back to the signal,
+    /// and neither are the invocations the handlers make while dispatching it; likewise, the
+    /// emission an invocation makes doesn't invoke the event a second time.
+    ///
+    /// Fails with Godot's error if the signal can't be connected to, for example because the
+    /// object has no signal of that name.
+    pub fn bind_signal(&self, signal: Signal) -> Result<SignalBinding<Args>, Error> {
+        let guard = EchoGuard::default();
+
+        let event = self.clone();
+        let from_godot = guard.clone();
+        let callable = Callable::from_fn("blob_event::LocalEvent::bind_signal", move |args: &[&Variant]| {
+            if let Some(args) = Args::from_variants(args) {
+                from_godot.receive(|| event.invoke(args));
+            }
+            return Variant::nil();
+        });
+        let error = signal.connect(&callable);
+        if error != Error::OK {
+            return Err(error);
+        }
+
+        let emitting = signal.clone();
+        let subscription = self.subscribe(move |args: Args| {
+            guard.emit(|| emitting.emit(&args.to_variants()));
+        });
+        return Ok(SignalBinding {
+            event: self.clone(),
+            subscription,
+            signal,
+            callable,
+        });
+    }
+}
+
+// Keeps a binding from echoing: Godot calls connected callables synchronously, so emitting the
+// signal from a handler would invoke the event again from inside its own dispatch, and
+// invoking the event from the callable would emit the signal it came from.
+#[derive(Clone, Default)]
+struct EchoGuard {
+    receiving: Rc<Cell<bool>>,
+    emitting: Rc<Cell<bool>>,
+}
+
+impl EchoGuard {
+    // Runs `invoke` for an emission coming from Godot, unless the binding is the one emitting.
+    fn receive(&self, invoke: impl FnOnce()) {
+        if !self.emitting.get() {
+            let _raised = Raised::raise(&self.receiving);
+            invoke();
+        }
+    }
+
+    // Runs `emit` for an invocation of the event, unless it happens while dispatching an
+    // emission coming from Godot.
+    fn emit(&self, emit: impl FnOnce()) {
+        if !self.receiving.get() {
+            let _raised = Raised::raise(&self.emitting);
+            emit();
+        }
+    }
+}
+
+// Holds a flag raised until dropped, so a panicking handler can't leave it stuck.
+struct Raised<'a>(&'a Cell<bool>);
+
+impl<'a> Raised<'a> {
+    fn raise(flag: &'a Cell<bool>) -> Raised<'a> {
+        flag.set(true);
+        return Raised(flag);
+    }
+}
+
+impl Drop for Raised<'_> {
+    fn drop(&mut self) {
+        self.0.set(false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::panic::{self, AssertUnwindSafe};
+
+    use super::*;
+
+    // Stands in for a bound signal: emitting it calls the binding's callable synchronously,
+    // as Godot does.
+    fn bind(event: &LocalEvent<(i64,)>, emitted: &Rc<RefCell<Vec<i64>>>) -> EchoGuard {
+        let guard = EchoGuard::default();
+        let signal: Rc<dyn Fn(i64)> = {
+            let from_godot = guard.clone();
+            let event = event.clone();
+            let emitted = Rc::clone(emitted);
+            Rc::new(move |value| {
+                emitted.borrow_mut().push(value);
+                from_godot.receive(|| event.invoke((value,)));
+            })
+        };
+        let to_godot = guard.clone();
+        event.subscribe(move |(value,)| to_godot.emit(|| signal(value)));
+        return guard;
+    }
+
+    #[test]
+    fn test_invoking_emits_once_without_reentering() {
+        let event = LocalEvent::<(i64,)>::new();
+        let emitted = Rc::new(RefCell::new(Vec::new()));
+        let received = Rc::new(RefCell::new(Vec::new()));
+        bind(&event, &emitted);
+        let receiving = Rc::clone(&received);
+        event.subscribe(move |(value,)| receiving.borrow_mut().push(value));
+
+        event.invoke((3,));
+
+        assert_eq!(*emitted.borrow(), vec![3]);
+        assert_eq!(*received.borrow(), vec![3]);
+    }
+
+    #[test]
+    fn test_emissions_from_godot_are_not_echoed() {
+        let event = LocalEvent::<(i64,)>::new();
+        let emitted = Rc::new(RefCell::new(Vec::new()));
+        let received = Rc::new(RefCell::new(Vec::new()));
+        let guard = bind(&event, &emitted);
+        let receiving = Rc::clone(&received);
+        event.subscribe(move |(value,)| receiving.borrow_mut().push(value));
+
+        guard.receive(|| event.invoke((5,)));
+
+        assert!(emitted.borrow().is_empty());
+        assert_eq!(*received.borrow(), vec![5]);
+    }
+
+    #[test]
+    fn test_panicking_handler_resets_the_guard() {
+        let guard = EchoGuard::default();
+
+        let result = panic::catch_unwind(AssertUnwindSafe(|| guard.emit(|| panic!("handler failed"))));
+        assert!(result.is_err());
+        let result = panic::catch_unwind(AssertUnwindSafe(|| guard.receive(|| panic!("handler failed"))));
+        assert!(result.is_err());
+
+        let mut calls = 0;
+        guard.emit(|| calls += 1);
+        guard.receive(|| calls += 1);
+        assert_eq!(calls, 2);
+    }
+}
@@ -0,0 +1,163 @@
+use alloc::sync::Arc;
+
+use crate::{DispatchError, DispatchReport, Event, EventError, EventInner, Subscribed, Subscription};
+
+/// Callbacks installed on an event with [`Event::set_hooks`], which are told about its
+/// subscriptions and dispatches.
+///
+/// Every method does nothing by default, so implementations only override the ones they
+/// need. Hooks are the place to build debuggers, metrics, or leak detectors on top of an
+/// event without touching the code that subscribes to it and invokes it.
+///
+/// The subscription callbacks are called after the subscription list has been changed and
+/// its lock released, so they may use the event freely.
+///
+/// ```
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use blob_event::{Event, EventHooks, Subscription};
+///
+/// #[derive(Default)]
+/// struct LeakCheck(AtomicUsize);
+///
+/// impl<Args: ?Sized> EventHooks<Args> for LeakCheck {
+///     fn on_subscribe(&self, _: Subscription) {
+///         self.0.fetch_add(1, Ordering::Relaxed);
+///     }
+///     fn on_unsubscribe(&self, _: Subscription) {
+///         self.0.fetch_sub(1, Ordering::Relaxed);
+///     }
+/// }
+///
+/// let resized = Event::<(u32, u32)>::new();
+/// let check = std::sync::Arc::new(LeakCheck::default());
+/// resized.set_hooks(check.clone());
+///
+/// let layout = resized.subscribe(|_| {});
+/// resized.unsubscribe(layout);
+/// assert_eq!(check.0.load(Ordering::Relaxed), 0);
+/// ```
+pub trait EventHooks<Args: ?Sized>: Send + Sync {
+    /// Called when a handler subscribes, with its subscription.
+    fn on_subscribe(&self, subscription: Subscription) {
+        let _ = subscription;
+    }
+
+    /// Called when a handler is unsubscribed, by any means, with its subscription.
+    fn on_unsubscribe(&self, subscription: Subscription) {
+        let _ = subscription;
+    }
+
+    /// Called when a subscription finds the event at the limit set with
+    /// [`EventBuilder::max_subscribers`](crate::EventBuilder::max_subscribers), before the
+    /// subscription is refused or the oldest handler is evicted to make room.
+    ///
+    /// A limit being reached usually means subscriptions are leaking, which makes this the
+    /// place to raise a warning.
+    fn on_subscriber_limit(&self) {}
+
+    /// Called with the arguments of a dispatch dropped for nesting deeper than the event's
+    /// [recursion limit](crate::EventBuilder::max_recursion_depth), when its policy is
+    /// [`RecursionPolicy::Hook`](crate::RecursionPolicy::Hook).
+    fn on_recursion_overflow(&self, args: &Args) {
+        let _ = args;
+    }
+
+    /// Called at the start of every dispatch, before any interceptor or handler sees `args`.
+    fn on_invoke_start(&self, args: &Args) {
+        let _ = args;
+    }
+
+    /// Called once every handler of a dispatch has run, with the dispatch's report, or with
+    /// the error that ended it early when panics are caught, as by
+    /// [`try_invoke`](Event::try_invoke). Not called when a handler's panic unwinds out of the
+    /// dispatch.
+    fn on_invoke_end(&self, outcome: Result<&DispatchReport, &DispatchError>) {
+        let _ = outcome;
+    }
+}
+
+impl<Args: ?Sized, H: EventHooks<Args> + ?Sized> EventHooks<Args> for Arc<H> {
+    fn on_subscribe(&self, subscription: Subscription) {
+        (**self).on_subscribe(subscription);
+    }
+
+    fn on_unsubscribe(&self, subscription: Subscription) {
+        (**self).on_unsubscribe(subscription);
+    }
+
+    fn on_subscriber_limit(&self) {
+        (**self).on_subscriber_limit();
+    }
+
+    fn on_recursion_overflow(&self, args: &Args) {
+        (**self).on_recursion_overflow(args);
+    }
+
+    fn on_invoke_start(&self, args: &Args) {
+        (**self).on_invoke_start(args);
+    }
+
+    fn on_invoke_end(&self, outcome: Result<&DispatchReport, &DispatchError>) {
+        (**self).on_invoke_end(outcome);
+    }
+}
+
+pub(crate) type Hooks<Args> = Arc<dyn EventHooks<Args>>;
+
+impl<Args: ?Sized> EventInner<Args> {
+    // Calls `notify` with the event's hooks, if it has any.
+    pub(crate) fn notify(&self, notify: impl FnOnce(&dyn EventHooks<Args>)) {
+        let hooks = self.snapshot.load().hooks.clone();
+        if let Some(hooks) = hooks {
+            notify(hooks.as_ref());
+        }
+    }
+
+    // Tells the hooks about a new subscription, and the one evicted for it, if any. Returns
+    // the new subscription.
+    pub(crate) fn notify_subscribed(&self, subscribed: Subscribed) -> Subscription {
+        self.notify(|hooks| {
+            if let Some(evicted) = subscribed.evicted {
+                hooks.on_subscriber_limit();
+                hooks.on_unsubscribe(evicted);
+            }
+            hooks.on_subscribe(subscribed.id);
+        });
+        return subscribed.id;
+    }
+
+    // Tells the hooks if a subscription was refused because of the subscriber limit.
+    pub(crate) fn notify_refused(&self, error: &EventError) {
+        if *error == EventError::TooManySubscribers {
+            self.notify(|hooks| hooks.on_subscriber_limit());
+        }
+    }
+
+    // Tells the hooks about every subscription in `removed`.
+    pub(crate) fn notify_unsubscribed(&self, removed: &[Subscription]) {
+        self.notify(|hooks| {
+            for id in removed.iter() {
+                hooks.on_unsubscribe(*id);
+            }
+        });
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    /// Installs `hooks` on this event, replacing the ones installed before, if any.
+    ///
+    /// See [`EventHooks`]. Dispatches already underway keep calling the hooks they started
+    /// with.
+    pub fn set_hooks<H>(&self, hooks: H)
+    where
+        H: EventHooks<Args> + 'static,
+    {
+        let hooks: Hooks<Args> = Arc::new(hooks);
+        self.inner.update(|handlers| handlers.hooks = Some(hooks));
+    }
+
+    /// Removes the hooks installed with [`set_hooks`](Self::set_hooks), if any.
+    pub fn clear_hooks(&self) {
+        self.inner.update(|handlers| handlers.hooks = None);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::Subscription;
+
+/// A description of an active subscription.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SubscriptionInfo {
+    /// The subscription being described.
+    pub subscription: Subscription,
+    /// The name given by [`subscribe_named`](crate::Event::subscribe_named), if any.
+    pub name: Option<&'static str>,
+    /// Whether the subscription's handler is executing right now, on any thread.
+    pub running: bool,
+    /// How many times the subscription's handler has been called.
+    pub calls: u64,
+    /// When the handler subscribed.
+    #[cfg(feature = "std")]
+    pub subscribed_at: std::time::Instant,
+}
+
+/// A summary of a completed dispatch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct DispatchReport {
+    /// How many handlers were called.
+    pub handlers_called: usize,
+    /// How many handlers were part of the dispatch's snapshot but were unsubscribed before
+    /// their turn came, and so were not called.
+    pub handlers_skipped: usize,
+    /// Whether an [interceptor](crate::Event::add_interceptor) vetoed the dispatch, in which
+    /// case no handler was called.
+    pub vetoed: bool,
+    /// Whether a handler returned [`HandlerControl::StopPropagation`](crate::HandlerControl::StopPropagation),
+    /// in which case the handlers after it were not called.
+    pub stopped: bool,
+}
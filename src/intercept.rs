@@ -0,0 +1,128 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+
+use crate::{DispatchError, Event, Snapshot, Subscription, catch_unwind};
+
+/// What an interceptor added with [`Event::add_interceptor`] decides to do with a dispatch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InterceptDecision<Args: ?Sized> {
+    /// Lets the dispatch go on with the arguments unchanged.
+    Continue,
+    /// Cancels the dispatch. No further interceptor, handler, or mirror sees the arguments.
+    Veto,
+    /// Lets the dispatch go on with these arguments instead, for the interceptors after this
+    /// one as well as the handlers.
+    Replace(Box<Args>),
+}
+
+pub(crate) type Interceptor<Args> = Arc<dyn Fn(&Args) -> InterceptDecision<Args> + Send + Sync>;
+
+// The outcome of running an event's interceptors.
+pub(crate) enum Intercepted<Args: ?Sized> {
+    Unchanged,
+    Replaced(Box<Args>),
+    Vetoed,
+}
+
+impl<Args: ?Sized> Intercepted<Args> {
+    // Returns the arguments to dispatch, or `None` if the dispatch was vetoed. The other
+    // dispatches hand a replacement to their payload instead.
+    #[cfg(feature = "rayon")]
+    pub(crate) fn args<'a>(&'a self, original: &'a Args) -> Option<&'a Args> {
+        match self {
+            Intercepted::Unchanged => return Some(original),
+            Intercepted::Replaced(args) => return Some(args),
+            Intercepted::Vetoed => return None,
+        }
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    /// Adds an interceptor that runs before the handlers on every dispatch of this event and
+    /// decides whether the dispatch goes on, and with which arguments.
+    ///
+    /// Interceptors run in the order they were added, each seeing the arguments as left by
+    /// the ones before it. They suit concerns shared by every handler, such as validation,
+    /// access checks, or logging, which would otherwise be repeated in each of them.
+    /// Interceptors don't count as subscribers, and like handlers, the ones added during a
+    /// dispatch first run on the next one.
+    ///
+    /// Returns a `Subscription` that removes the interceptor when passed to
+    /// [`remove_interceptor`](Self::remove_interceptor).
+    ///
+    /// ```
+    /// use blob_event::{Event, InterceptDecision};
+    ///
+    /// let withdraw = Event::<u32>::new();
+    /// withdraw.add_interceptor(|amount| match *amount {
+    ///     0 => InterceptDecision::Veto,
+    ///     amount if amount > 500 => InterceptDecision::Replace(Box::new(500)),
+    ///     _ => InterceptDecision::Continue,
+    /// });
+    /// withdraw.subscribe(|amount| assert!(amount > 0 && amount <= 500));
+    /// withdraw.invoke(0);
+    /// withdraw.invoke(900);
+    /// ```
+    pub fn add_interceptor<F>(&self, interceptor: F) -> Subscription
+    where
+        F: Fn(&Args) -> InterceptDecision<Args> + Send + Sync + 'static,
+    {
+        return self.inner.update(|handlers| {
+            let id = handlers.allocate();
+            handlers.interceptors.push((id, Arc::new(interceptor)));
+            id
+        });
+    }
+
+    /// Removes an interceptor added with [`add_interceptor`](Self::add_interceptor).
+    ///
+    /// Returns `true` if the interceptor was found and removed, `false` otherwise.
+    pub fn remove_interceptor(&self, id: Subscription) -> bool {
+        return self.inner.update(|handlers| {
+            let Some(position) = handlers.interceptors.iter().position(|(other, _)| *other == id) else {
+                return false;
+            };
+            handlers.interceptors.remove(position);
+            handlers.release(id);
+            true
+        });
+    }
+
+    // Runs the interceptors of `snapshot` on `args`. With `catch_panics`, a panicking
+    // interceptor is reported like a panicking handler.
+    pub(crate) fn intercept(
+        &self,
+        snapshot: &Snapshot<Args>,
+        args: &Args,
+        catch_panics: bool,
+    ) -> Result<Intercepted<Args>, DispatchError> {
+        let mut result = Intercepted::Unchanged;
+        for (id, interceptor) in snapshot.interceptors.iter() {
+            let current = match &result {
+                Intercepted::Replaced(replaced) => replaced,
+                _ => args,
+            };
+            let mut decision = InterceptDecision::Continue;
+            let mut call = || decision = interceptor(current);
+            if catch_panics {
+                if let Err(payload) = catch_unwind(call) {
+                    return Err(DispatchError::handler_panicked(*id, payload.as_ref()));
+                }
+            } else {
+                call();
+            }
+            match decision {
+                InterceptDecision::Continue => {}
+                InterceptDecision::Veto => {
+                    #[cfg(feature = "tracing")]
+                    tracing::debug!(event = snapshot.event, interceptor = ?id, "vetoed");
+                    #[cfg(feature = "log")]
+                    log::debug!("invocation of event {} vetoed by {:?}", snapshot.event, id);
+                    return Ok(Intercepted::Vetoed);
+                }
+                InterceptDecision::Replace(args) => result = Intercepted::Replaced(args),
+            }
+        }
+        return Ok(result);
+    }
+}
@@ -0,0 +1,246 @@
+//! Events shared between processes over a Unix domain socket, available with the `ipc`
+//! feature on Unix.
+//!
+//! One process [hosts](host) an event on a socket path and any number of others
+//! [connect] to it. Each side gets an [`IpcEvent`] whose
+//! [`invoke`](IpcEvent::invoke) dispatches to its own handlers and forwards the arguments to
+//! the other side, where they are dispatched too:
+//!
+//! ```no_run
+//! use blob_event::ipc;
+//!
+//! // In the supervisor
+//! let jobs = ipc::host::<String>("/tmp/jobs.sock").unwrap();
+//! jobs.event().subscribe(|job| println!("worker reported {}", job));
+//!
+//! // In a worker
+//! let jobs = ipc::connect::<String>("/tmp/jobs.sock").unwrap();
+//! jobs.invoke("done: resize images".to_string()).unwrap();
+//! ```
+//!
+//! The host forwards its invocations to every connected process, while a connected process
+//! forwards its invocations to the host only. Arguments cross the socket as one line of JSON
+//! each, so they need `serde`'s `Serialize` and `DeserializeOwned`, and both sides must agree
+//! on their type; lines that can't be deserialized are ignored. Forwarded invocations are
+//! dispatched on a thread the `IpcEvent` owns per connection, in the order they were sent.
+//!
+//! A process that stops reading only holds up the invocations forwarded to it, and only
+//! for a few seconds: a write that can't complete by then drops its connection.
+
+use std::fs;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::Shutdown;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, PoisonError, Weak};
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::Event;
+
+/// An event shared with other processes, as returned by [`host`] and [`connect`].
+///
+/// Cloning it returns another handle to the same event and connections. The socket is closed
+/// once every handle is dropped; for the host, that also removes the socket file.
+pub struct IpcEvent<Args> {
+    event: Event<Args>,
+    link: Arc<Link>,
+}
+
+// How long forwarding an invocation to another process may block before its connection is
+// dropped.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(5);
+
+// The connections an `IpcEvent` forwards its invocations to.
+struct Link {
+    peers: Mutex<Vec<Arc<Peer>>>,
+    // Set for the host, whose socket file is removed on drop.
+    socket: Option<PathBuf>,
+}
+
+impl Drop for Link {
+    fn drop(&mut self) {
+        for peer in self.peers.get_mut().unwrap_or_else(PoisonError::into_inner).drain(..) {
+            peer.close();
+        }
+        if let Some(socket) = &self.socket {
+            // Wakes the thread accepting connections so it notices the host is gone.
+            let _ = UnixStream::connect(socket);
+            let _ = fs::remove_file(socket);
+        }
+    }
+}
+
+// A connection to another process. Its own lock keeps concurrent invocations from
+// interleaving their lines, without holding up the writes to other processes.
+struct Peer {
+    stream: Mutex<UnixStream>,
+}
+
+impl Peer {
+    fn new(stream: UnixStream) -> io::Result<Arc<Peer>> {
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        return Ok(Arc::new(Peer {
+            stream: Mutex::new(stream),
+        }));
+    }
+
+    // Writes `line` to the process, returning `false` if the connection failed or timed out.
+    fn send(&self, line: &[u8]) -> bool {
+        let mut stream = self.stream.lock().unwrap_or_else(PoisonError::into_inner);
+        if stream.write_all(line).is_ok() {
+            return true;
+        }
+        // A timed out write may have sent part of the line, so the connection can't be used
+        // anymore.
+        let _ = stream.shutdown(Shutdown::Both);
+        return false;
+    }
+
+    fn close(&self) {
+        let stream = self.stream.lock().unwrap_or_else(PoisonError::into_inner);
+        let _ = stream.shutdown(Shutdown::Both);
+    }
+}
+
+/// Hosts an event on a Unix domain socket at `path`, for other processes to [`connect`] to.
+///
+/// A socket file left behind by a host that is no longer running is replaced. Fails if
+/// another host is listening at `path`, or if the socket can't be created.
+pub fn host<Args>(path: impl AsRef<Path>) -> io::Result<IpcEvent<Args>>
+where
+    Args: Serialize + DeserializeOwned + Clone + Send + 'static,
+{
+    let path = path.as_ref().to_path_buf();
+    let listener = match UnixListener::bind(&path) {
+        Err(error) if error.kind() == io::ErrorKind::AddrInUse && UnixStream::connect(&path).is_err() => {
+            fs::remove_file(&path)?;
+            UnixListener::bind(&path)?
+        }
+        result => result?,
+    };
+    let event = Event::new();
+    let link = Arc::new(Link {
+        peers: Mutex::new(Vec::new()),
+        socket: Some(path),
+    });
+    let accepting = Arc::downgrade(&link);
+    let handlers = event.clone();
+    thread::Builder::new()
+        .name("blob-event-ipc".to_string())
+        .spawn(move || accept(listener, accepting, handlers))?;
+    return Ok(IpcEvent { event, link });
+}
+
+/// Connects to an event [hosted](host) at `path` by another process.
+pub fn connect<Args>(path: impl AsRef<Path>) -> io::Result<IpcEvent<Args>>
+where
+    Args: Serialize + DeserializeOwned + Clone + Send + 'static,
+{
+    let stream = UnixStream::connect(path)?;
+    let event = Event::new();
+    receive(stream.try_clone()?, event.clone())?;
+    let link = Arc::new(Link {
+        peers: Mutex::new(vec![Peer::new(stream)?]),
+        socket: None,
+    });
+    return Ok(IpcEvent { event, link });
+}
+
+fn accept<Args>(listener: UnixListener, link: Weak<Link>, event: Event<Args>)
+where
+    Args: DeserializeOwned + Clone + Send + 'static,
+{
+    for stream in listener.incoming() {
+        let Some(link) = link.upgrade() else {
+            return;
+        };
+        let Ok(stream) = stream else {
+            continue;
+        };
+        let Ok(reader) = stream.try_clone() else {
+            continue;
+        };
+        if let Ok(peer) = Peer::new(stream)
+            && receive(reader, event.clone()).is_ok()
+        {
+            link.peers.lock().unwrap_or_else(PoisonError::into_inner).push(peer);
+        }
+    }
+}
+
+// Starts a thread dispatching the invocations read from `stream` until it is closed.
+fn receive<Args>(stream: UnixStream, event: Event<Args>) -> io::Result<()>
+where
+    Args: DeserializeOwned + Clone + Send + 'static,
+{
+    thread::Builder::new()
+        .name("blob-event-ipc".to_string())
+        .spawn(move || {
+            for line in BufReader::new(stream).lines() {
+                let Ok(line) = line else {
+                    return;
+                };
+                if let Ok(args) = serde_json::from_str(&line) {
+                    let _ = event.try_invoke(args);
+                }
+            }
+        })?;
+    return Ok(());
+}
+
+impl<Args> IpcEvent<Args>
+where
+    Args: Serialize + DeserializeOwned + Clone + Send + 'static,
+{
+    /// Dispatches `args` to this process's handlers, then forwards them to the other side.
+    ///
+    /// Returns how many processes the arguments were sent to. Connections that fail are
+    /// dropped; for a process that [connected](connect), that means the host went away.
+    /// Fails only if the arguments can't be serialized, in which case nothing is dispatched.
+    pub fn invoke(&self, args: Args) -> io::Result<usize> {
+        let mut line = serde_json::to_vec(&args)?;
+        line.push(b'\n');
+        self.event.invoke(args);
+        // Written without the list locked, so a slow process doesn't hold up new connections
+        // or the other invocations' bookkeeping.
+        let peers = self.link.peers.lock().unwrap_or_else(PoisonError::into_inner).clone();
+        let failed: Vec<Arc<Peer>> = peers.iter().filter(|peer| !peer.send(&line)).cloned().collect();
+        if !failed.is_empty() {
+            self.link
+                .peers
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .retain(|peer| !failed.iter().any(|failed| Arc::ptr_eq(failed, peer)));
+        }
+        return Ok(peers.len() - failed.len());
+    }
+
+    /// Returns the local event, which receives both this process's invocations and the ones
+    /// forwarded from the other side.
+    ///
+    /// Invoking it directly only dispatches locally.
+    pub fn event(&self) -> Event<Args> {
+        return self.event.clone();
+    }
+
+    /// Returns how many processes are connected: for the host, the processes that connected
+    /// to it, and for the others, 1 while the host is reachable.
+    ///
+    /// A process that went away is only noticed by the next [`invoke`](Self::invoke).
+    pub fn peer_count(&self) -> usize {
+        return self.link.peers.lock().unwrap_or_else(PoisonError::into_inner).len();
+    }
+}
+
+impl<Args> Clone for IpcEvent<Args> {
+    fn clone(&self) -> Self {
+        return IpcEvent {
+            event: self.event.clone(),
+            link: Arc::clone(&self.link),
+        };
+    }
+}
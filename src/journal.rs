@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+use std::fs::{File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::Event;
+use crate::sync::{Lock, Mutex};
+
+/// An event whose invocations are written to an on-disk journal before they are
+/// dispatched, so the ones a crash interrupted can be dispatched again on the next start.
+///
+/// Created with [`Event::journaled`]. Each [`invoke`](Self::invoke) appends the arguments,
+/// serialized as one line of JSON, to the journal file and syncs it to disk before the
+/// handlers run. On startup, [`replay`](Self::replay) dispatches every invocation in the
+/// journal again, in the order they were written; once their effects are safely stored
+/// elsewhere, [`clear`](Self::clear) empties it.
+///
+/// Every entry is tagged with a [schema version](Self::schema_version), so that a journal
+/// written before the arguments' type changed can still be read with a [`Replayer`].
+///
+/// Only invocations made through the `Journaled` are written; invoking the underlying
+/// [`event`](Self::event) directly bypasses the journal. Cloning a `Journaled` returns
+/// another handle to the same event and journal.
+///
+/// ```no_run
+/// use blob_event::Event;
+///
+/// let orders = Event::<(u64, String)>::new().journaled("orders.jsonl").unwrap();
+/// orders.event().subscribe(|(id, item)| println!("shipping {} for order {}", item, id));
+///
+/// // Dispatch whatever was journaled before the last crash
+/// orders.replay().unwrap();
+/// orders.clear().unwrap();
+///
+/// orders.invoke((42, "keyboard".to_string())).unwrap();
+/// ```
+pub struct Journaled<Args> {
+    event: Event<Args>,
+    journal: Arc<Journal>,
+    version: u32,
+}
+
+struct Journal {
+    path: PathBuf,
+    file: Mutex<File>,
+}
+
+impl<Args> Event<Args>
+where
+    Args: Serialize + DeserializeOwned + Clone + Send + 'static,
+{
+    /// Wraps this event in a [`Journaled`] that writes its invocations to the journal file at
+    /// `path`, creating the file if it doesn't exist.
+    ///
+    /// Entries are tagged with schema version 0 unless
+    /// [`schema_version`](Journaled::schema_version) says otherwise.
+    ///
+    /// An existing journal is kept so it can be [replayed](Journaled::replay). If its last
+    /// entry was only partly written, as when the process died during the write, that entry
+    /// is discarded, since its invocation was never dispatched.
+    pub fn journaled(&self, path: impl AsRef<Path>) -> io::Result<Journaled<Args>> {
+        let path = path.as_ref().to_path_buf();
+        let mut file = OpenOptions::new().read(true).append(true).create(true).open(&path)?;
+        discard_torn_entry(&mut file)?;
+        return Ok(Journaled {
+            event: self.clone(),
+            journal: Arc::new(Journal {
+                path,
+                file: Mutex::new(file),
+            }),
+            version: 0,
+        });
+    }
+}
+
+impl<Args> Journaled<Args>
+where
+    Args: Serialize + DeserializeOwned + Clone + Send + 'static,
+{
+    /// Sets the schema version that entries written through this handle are tagged with.
+    ///
+    /// Bump it whenever the serialized form of `Args` changes, and give the [`Replayer`]
+    /// that reads the journal a migration from the previous version.
+    pub fn schema_version(mut self, version: u32) -> Self {
+        self.version = version;
+        return self;
+    }
+
+    /// Writes `args` to the journal, then dispatches them to the event's handlers.
+    ///
+    /// If the journal can't be written, the error is returned and nothing is dispatched.
+    pub fn invoke(&self, args: Args) -> io::Result<()> {
+        let record = Record {
+            version: self.version,
+            args: &args,
+        };
+        let mut line = serde_json::to_vec(&record)?;
+        line.push(b'\n');
+        {
+            let mut file = self.journal.file.acquire();
+            file.write_all(&line)?;
+            file.sync_data()?;
+        }
+        self.event.invoke(args);
+        return Ok(());
+    }
+
+    /// Dispatches every invocation in the journal again, in the order they were written,
+    /// without writing them a second time. Returns how many were dispatched.
+    ///
+    /// The journal is read in full before anything is dispatched, so invocations the
+    /// handlers make through this `Journaled` are written but not replayed by this call.
+    /// Returns an error of kind [`InvalidData`](io::ErrorKind::InvalidData) if an entry
+    /// can't be deserialized or has a different schema version, in which case nothing is
+    /// dispatched. Use a [`Replayer`] to read entries of other versions.
+    pub fn replay(&self) -> io::Result<usize> {
+        let report = Replayer::new(self.version).replay(&self.journal.path, &self.event)?;
+        return Ok(report.dispatched);
+    }
+
+    /// Empties the journal, typically once the replayed invocations have been handled.
+    pub fn clear(&self) -> io::Result<()> {
+        let file = self.journal.file.acquire();
+        file.set_len(0)?;
+        file.sync_data()?;
+        return Ok(());
+    }
+
+    /// Returns the event the invocations are dispatched to.
+    pub fn event(&self) -> Event<Args> {
+        return self.event.clone();
+    }
+
+    /// Returns the path of the journal file.
+    pub fn path(&self) -> &Path {
+        return &self.journal.path;
+    }
+}
+
+impl<Args> Clone for Journaled<Args> {
+    fn clone(&self) -> Self {
+        return Journaled {
+            event: self.event.clone(),
+            journal: Arc::clone(&self.journal),
+            version: self.version,
+        };
+    }
+}
+
+#[derive(Serialize)]
+struct Record<'a, Args> {
+    version: u32,
+    args: &'a Args,
+}
+
+#[derive(Deserialize)]
+struct RawRecord {
+    version: u32,
+    args: serde_json::Value,
+}
+
+type Migration<Args> = Box<dyn Fn(serde_json::Value) -> serde_json::Result<Args> + Send + Sync>;
+
+/// Reads a journal written by [`Journaled`] and dispatches its entries to an event,
+/// converting entries written with older schema versions of the arguments along the way.
+///
+/// Entries tagged with the replayer's own version are deserialized as `Args`. For entries
+/// of any other version, the replayer needs to be told what to do: [`migrate`](Self::migrate)
+/// deserializes them as the type the arguments had then and maps them to `Args`, and
+/// [`skip`](Self::skip) drops them. An entry of a version it knows nothing about is an
+/// error, unless [`skip_unknown`](Self::skip_unknown) was called.
+///
+/// ```no_run
+/// use blob_event::{Event, Replayer};
+///
+/// // Version 0 only had the order's id; version 1 added the item
+/// let orders = Event::<(u64, String)>::new();
+/// let report = Replayer::new(1)
+///     .migrate(0, |id: u64| (id, "unknown".to_string()))
+///     .replay("orders.jsonl", &orders)
+///     .unwrap();
+/// println!("replayed {} orders", report.dispatched);
+/// ```
+pub struct Replayer<Args> {
+    version: u32,
+    migrations: HashMap<u32, Option<Migration<Args>>>,
+    skip_unknown: bool,
+}
+
+/// What a [`Replayer`] did with the entries of a journal.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReplayReport {
+    /// How many entries were dispatched, including migrated ones.
+    pub dispatched: usize,
+    /// How many entries were skipped because of their schema version.
+    pub skipped: usize,
+}
+
+impl<Args> Replayer<Args>
+where
+    Args: DeserializeOwned + Clone + Send + 'static,
+{
+    /// Creates a replayer for entries written with schema version `version` of `Args`, with
+    /// no migrations.
+    pub fn new(version: u32) -> Self {
+        return Replayer {
+            version,
+            migrations: HashMap::new(),
+            skip_unknown: false,
+        };
+    }
+
+    /// Reads entries of schema version `version` as `Old`, and dispatches what `migration`
+    /// turns them into.
+    ///
+    /// To go through several versions, compose the conversions in `migration`.
+    pub fn migrate<Old, F>(mut self, version: u32, migration: F) -> Self
+    where
+        Old: DeserializeOwned,
+        F: Fn(Old) -> Args + Send + Sync + 'static,
+    {
+        let migration = move |value| serde_json::from_value::<Old>(value).map(&migration);
+        self.migrations.insert(version, Some(Box::new(migration)));
+        return self;
+    }
+
+    /// Skips entries of schema version `version`.
+    pub fn skip(mut self, version: u32) -> Self {
+        self.migrations.insert(version, None);
+        return self;
+    }
+
+    /// Skips entries of every schema version that has no migration, instead of failing.
+    pub fn skip_unknown(mut self) -> Self {
+        self.skip_unknown = true;
+        return self;
+    }
+
+    /// Reads the journal at `path` and dispatches its entries to `event`, in the order they
+    /// were written.
+    ///
+    /// The journal is read and converted in full before anything is dispatched. Returns an
+    /// error of kind [`InvalidData`](io::ErrorKind::InvalidData) if an entry can't be
+    /// deserialized or has a version the replayer doesn't handle, in which case nothing is
+    /// dispatched.
+    pub fn replay(&self, path: impl AsRef<Path>, event: &Event<Args>) -> io::Result<ReplayReport> {
+        let reader = BufReader::new(File::open(path)?);
+        let mut entries = Vec::new();
+        let mut report = ReplayReport::default();
+        for line in reader.lines() {
+            let line = line?;
+            if line.is_empty() {
+                continue;
+            }
+            let record: RawRecord = serde_json::from_str(&line)?;
+            let args = if record.version == self.version {
+                serde_json::from_value(record.args)?
+            } else {
+                match self.migrations.get(&record.version) {
+                    Some(Some(migration)) => migration(record.args)?,
+                    Some(None) => {
+                        report.skipped += 1;
+                        continue;
+                    }
+                    None if self.skip_unknown => {
+                        report.skipped += 1;
+                        continue;
+                    }
+                    None => {
+                        let message = format!("journal entry has unknown schema version {}", record.version);
+                        return Err(io::Error::new(io::ErrorKind::InvalidData, message));
+                    }
+                }
+            };
+            entries.push(args);
+        }
+        report.dispatched = entries.len();
+        for args in entries {
+            event.invoke(args);
+        }
+        return Ok(report);
+    }
+}
+
+// Truncates the journal after its last complete entry, so new entries aren't appended to
+// the remains of one that was cut short.
+fn discard_torn_entry(file: &mut File) -> io::Result<()> {
+    let mut contents = Vec::new();
+    file.seek(SeekFrom::Start(0))?;
+    file.read_to_end(&mut contents)?;
+    if contents.last().is_some_and(|last| *last != b'\n') {
+        let complete = contents
+            .iter()
+            .rposition(|byte| *byte == b'\n')
+            .map_or(0, |end| end + 1);
+        file.set_len(complete as u64)?;
+        file.sync_data()?;
+    }
+    return Ok(());
+}
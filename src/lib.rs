@@ -1,118 +1,1453 @@
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::needless_return)]
 
-// Core trait that defines what an event can do
-trait EventHandler<Args>: Send {
-    fn call(&mut self, args: Args);
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::sync::{Arc, Weak};
+use alloc::vec::Vec;
+use core::any::Any;
+use core::borrow::Borrow;
+use core::marker::PhantomData;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use smallvec::SmallVec;
+
+use crate::slab::Slab;
+use crate::sync::{Lock, Mutex, Published};
+
+// Marks a spot where an event is about to take one of its locks. With the `testing` feature
+// enabled, threads driven by a `testing::Schedule` wait here for their turn.
+macro_rules! yield_point {
+    ($point:ident) => {
+        #[cfg(feature = "testing")]
+        $crate::testing::yield_point($crate::testing::YieldPoint::$point);
+    };
+}
+
+mod async_event;
+#[cfg(feature = "std")]
+mod batch;
+#[cfg(feature = "std")]
+mod binding;
+mod builder;
+mod bus;
+mod close;
+#[cfg(feature = "std")]
+mod computed;
+mod connectable;
+mod context;
+mod control;
+mod cow;
+mod delivery;
+#[cfg(feature = "std")]
+mod detached;
+#[cfg(feature = "std")]
+mod dispatcher;
+mod error;
+mod event_queue;
+#[cfg(feature = "std")]
+mod expire;
+mod fallible;
+#[cfg(feature = "godot")]
+mod gdext;
+mod hooks;
+mod info;
+mod intercept;
+#[cfg(feature = "journal")]
+mod journal;
+mod limit;
+mod local;
+#[cfg(feature = "std")]
+mod mailbox;
+mod named;
+mod next;
+mod observable;
+mod on_error;
+mod operators;
+mod order;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod pipeline;
+#[cfg(feature = "std")]
+mod pool;
+mod property;
+mod query;
+#[cfg(feature = "std")]
+mod recorder;
+#[cfg(feature = "std")]
+mod recursion;
+mod ref_event;
+mod replace;
+#[cfg(feature = "std")]
+mod request;
+#[cfg(feature = "std")]
+mod shared;
+mod single_consumer;
+mod slab;
+#[cfg(any(feature = "spin", not(feature = "std")))]
+mod spin;
+mod static_event;
+#[cfg(feature = "std")]
+mod stats;
+mod sync;
+mod take;
+#[cfg(feature = "std")]
+mod timer;
+mod times;
+mod transaction;
+#[cfg(feature = "std")]
+mod ui;
+mod veto;
+#[cfg(feature = "std")]
+mod wait;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
+#[cfg(feature = "std")]
+mod watchdog;
+
+#[cfg(feature = "bevy")]
+pub mod bevy;
+#[cfg(all(feature = "ipc", unix))]
+pub mod ipc;
+#[cfg(feature = "mqtt")]
+pub mod mqtt;
+#[cfg(all(feature = "signals", unix))]
+pub mod os_signals;
+#[cfg(feature = "prometheus")]
+pub mod prometheus;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "redis")]
+pub mod redis_bridge;
+#[cfg(feature = "remote")]
+pub mod remote;
+#[cfg(feature = "std")]
+pub mod schedule;
+#[cfg(feature = "std")]
+pub mod shutdown;
+#[cfg(feature = "testing")]
+pub mod testing;
+
+pub use async_event::{AsyncDispatch, AsyncEvent};
+#[cfg(feature = "std")]
+pub use batch::BatchConfig;
+#[cfg(feature = "std")]
+pub use binding::PropertyBinding;
+#[cfg(feature = "derive")]
+pub use blob_event_derive::{EventEmitter, EventSet};
+pub use builder::EventBuilder;
+use builder::EventConfig;
+pub use bus::{BusNode, Phase, Propagation};
+#[cfg(feature = "std")]
+pub use computed::{Computed, Dependencies, Dependency};
+pub use connectable::Connectable;
+pub use context::InvokeContext;
+pub use control::HandlerControl;
+pub use delivery::{Borrowed, Cloned, SharedEvent};
+#[cfg(feature = "std")]
+pub use dispatcher::{Backpressure, DeliveryMode};
+pub use error::{DispatchError, EventError};
+pub use event_queue::EventQueue;
+pub use fallible::FallibleEvent;
+#[cfg(feature = "godot")]
+pub use gdext::{SignalArgs, SignalBinding};
+pub use hooks::EventHooks;
+use hooks::Hooks;
+pub use info::{DispatchReport, SubscriptionInfo};
+pub use intercept::InterceptDecision;
+use intercept::{Intercepted, Interceptor};
+#[cfg(feature = "journal")]
+pub use journal::{Journaled, ReplayReport, Replayer};
+pub use limit::SubscriberLimit;
+pub use local::LocalEvent;
+#[cfg(feature = "std")]
+pub use mailbox::{Address, Mailbox};
+pub use next::Next;
+#[cfg(feature = "std")]
+pub use observable::{MapChange, ObservableMap};
+pub use observable::{ObservableVec, VecChange};
+pub use order::DispatchOrder;
+pub use property::Property;
+pub use query::QueryEvent;
+#[cfg(feature = "std")]
+pub use recorder::{EventRecorder, RecordedInvocation};
+#[cfg(feature = "std")]
+pub use recursion::RecursionPolicy;
+pub use ref_event::{BorrowedArgs, RefEvent};
+#[cfg(feature = "std")]
+pub use request::{RequestEvent, Responder};
+pub use single_consumer::SingleConsumerEvent;
+pub use static_event::{StaticEvent, StaticHandler};
+#[cfg(feature = "std")]
+pub use stats::{EventStats, HandlerStats};
+#[cfg(feature = "std")]
+pub use timer::{Scheduler, Task, with_scheduler};
+pub use transaction::Transaction;
+#[cfg(feature = "std")]
+pub use ui::{UiLoop, UiTask};
+pub use veto::{Verdict, VetoEvent};
+#[cfg(feature = "std")]
+pub use watchdog::{SlowHandler, TimeoutAction};
+
+// Core trait that defines what an event can do. Handlers always receive the payload by
+// reference; the event's delivery mode decides how that is presented to the user's closure.
+trait EventHandler<Args: ?Sized>: Send {
+    fn call(&mut self, args: &Args);
+
+    // Calls the handler, returning what the dispatch should do next. Only handlers
+    // subscribed with `subscribe_ctl` return anything but `Continue`.
+    fn call_ctl(&mut self, args: &Args) -> HandlerControl {
+        self.call(args);
+        return HandlerControl::Continue;
+    }
+
+    // Calls the handler from `invoke_mut`, which lends it the payload mutably. Only handlers
+    // subscribed with `subscribe_mut` change it; the others see it as they would otherwise.
+    fn call_mut(&mut self, args: &mut Args) -> HandlerControl {
+        return self.call_ctl(args);
+    }
+
+    // Called with every error emitted on the event. Returns `true` if the handler takes
+    // errors of that type.
+    fn error(&mut self, _error: &dyn core::any::Any) -> bool {
+        return false;
+    }
+
+    // Called once if the event is closed while the handler is subscribed.
+    fn close(&mut self) {}
+
+    // Returns the handler as `Any`, for events that call their handlers with something else
+    // than `&Args` and need to get back at the concrete type.
+    fn as_any(&mut self) -> Option<&mut dyn core::any::Any> {
+        return None;
+    }
+}
+
+// How a dispatch lends its payload to the handlers. Most dispatches lend it shared;
+// `invoke_mut` lends it mutably, and `RefEvent` lends something else than `&Args` altogether.
+trait Payload<Args: ?Sized> {
+    // The payload as the interceptors, hooks, and mirrors see it.
+    fn args(&self) -> &Args;
+
+    // Dispatches the arguments an interceptor replaced the payload with instead.
+    fn replace(&mut self, args: Box<Args>);
+
+    // Calls `handler` with the payload.
+    fn call(&mut self, handler: &mut dyn EventHandler<Args>) -> HandlerControl;
+}
+
+// The payload of every dispatch but `invoke_mut`, lent shared.
+struct SharedPayload<'a, Args: ?Sized> {
+    args: &'a Args,
+    replaced: Option<Box<Args>>,
+}
+
+impl<Args: ?Sized> Payload<Args> for SharedPayload<'_, Args> {
+    fn args(&self) -> &Args {
+        return self.replaced.as_deref().unwrap_or(self.args);
+    }
+
+    fn replace(&mut self, args: Box<Args>) {
+        self.replaced = Some(args);
+    }
+
+    fn call(&mut self, handler: &mut dyn EventHandler<Args>) -> HandlerControl {
+        return handler.call_ctl(self.args());
+    }
 }
 
 // Implement for closures
 impl<F, Args> EventHandler<Args> for F
 where
-    F: FnMut(Args) + Send,
+    F: FnMut(&Args) + Send,
+    Args: ?Sized,
 {
-    fn call(&mut self, args: Args) {
+    fn call(&mut self, args: &Args) {
         self(args);
     }
 }
 
-/// A unique identifier for a subscription.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Subscription(usize);
+// Adapts a closure taking owned arguments, cloning the payload for each call.
+struct ByClone<F>(F);
 
-impl Subscription {
-    pub fn new(id: usize) -> Subscription {
-        return Subscription(id);
+impl<F, Args> EventHandler<Args> for ByClone<F>
+where
+    F: FnMut(Args) + Send,
+    Args: Clone,
+{
+    fn call(&mut self, args: &Args) {
+        (self.0)(args.clone());
     }
 }
 
+// Each handler lives in its own cell so it can run without the subscription list being
+// locked, which lets handlers subscribe and unsubscribe (themselves included) mid-dispatch.
+type HandlerCell<Args> = Arc<HandlerSlot<Args>>;
+
+struct HandlerSlot<Args: ?Sized> {
+    id: Subscription,
+    // Given with `subscribe_named`, for debugging.
+    name: Option<&'static str>,
+    // Cleared when the subscription is removed, so invokes that already hold the cell in
+    // their snapshot skip it.
+    active: AtomicBool,
+    handler: Mutex<Box<dyn EventHandler<Args>>>,
+    // How many times the handler has been called, counted on every event.
+    calls: AtomicUsize,
+    // Set by `close` until the handler's close notification has run.
+    closing: AtomicBool,
+    #[cfg(feature = "std")]
+    subscribed_at: std::time::Instant,
+    #[cfg(feature = "std")]
+    counters: stats::CallCounters,
+    // The thread running the handler, as told by `recursion::thread_token`, or 0 while idle.
+    #[cfg(feature = "std")]
+    running_on: AtomicUsize,
+}
+
+impl<Args: ?Sized> HandlerSlot<Args> {
+    // Calls the handler with `args`, for `invoke_parallel`, which lends them to every handler
+    // at once.
+    #[cfg(feature = "rayon")]
+    fn call(&self, args: &Args) -> HandlerControl {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        return self.with_handler(|handler| handler.call_ctl(args));
+    }
+
+    // Calls the handler with `payload`, however the dispatch lends it.
+    fn call_with(&self, payload: &mut dyn Payload<Args>) -> HandlerControl {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        return self.with_handler(|handler| payload.call(handler));
+    }
+
+    // Runs `f` with the handler locked, then runs the close notification if the event was
+    // closed meanwhile.
+    fn with_handler<R>(&self, f: impl FnOnce(&mut dyn EventHandler<Args>) -> R) -> R {
+        // Declared first so it runs after the lock is released, even if the handler panics.
+        let _closing = PendingClose(self);
+        let mut handler = self.handler.acquire();
+        #[cfg(feature = "std")]
+        let _running = recursion::Running::enter(&self.running_on);
+        return f(&mut **handler);
+    }
+
+    // Runs the handler's close notification, right away if the handler is idle, or else once
+    // its running call returns. Waiting for that call instead would deadlock a handler that
+    // closes its own event.
+    fn close(&self) {
+        self.closing.store(true, Ordering::SeqCst);
+        self.finish_close();
+    }
+
+    // Runs a pending close notification unless the handler is locked, in which case whoever
+    // holds the lock runs it after releasing.
+    fn finish_close(&self) {
+        while self.closing.load(Ordering::SeqCst) {
+            let Some(mut handler) = self.handler.try_acquire() else {
+                return;
+            };
+            if self.closing.swap(false, Ordering::SeqCst) {
+                handler.close();
+            }
+        }
+    }
+}
+
+struct PendingClose<'a, Args: ?Sized>(&'a HandlerSlot<Args>);
+
+impl<Args: ?Sized> Drop for PendingClose<'_, Args> {
+    fn drop(&mut self) {
+        self.0.finish_close();
+    }
+}
+
+/// A unique identifier for a subscription.
+///
+/// Subscriptions can only be obtained by subscribing. Each one pairs a slot index with a
+/// generation that changes whenever the slot is reused, so a handle kept after unsubscribing
+/// never matches the subscription that later takes its slot. It also records which event it
+/// belongs to, so passing it to another event's `unsubscribe` does nothing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Subscription {
+    event: usize,
+    index: u32,
+    generation: u32,
+}
+
+// Hands out the identity recorded in every subscription of an event.
+fn next_event_id() -> usize {
+    static NEXT_EVENT_ID: AtomicUsize = AtomicUsize::new(0);
+    return NEXT_EVENT_ID.fetch_add(1, Ordering::Relaxed);
+}
+
 /// A thread-safe event system that allows multiple subscribers to register callbacks.
 ///
 /// Subscribers remain active until explicitly unsubscribed using the `SubscriptionId`.
-pub struct Event<Args> {
-    handlers: Arc<Mutex<EventHandlers<Args>>>,
+///
+/// The `D` parameter selects how handlers receive the arguments. The default, [`Cloned`],
+/// hands every handler its own clone; [`Borrowed`] hands out `&Args` instead. See
+/// [`EventBuilder`] for choosing the mode when creating an event.
+pub struct Event<Args: ?Sized, D = Cloned> {
+    inner: Arc<EventInner<Args>>,
+    delivery: PhantomData<D>,
+}
+
+struct EventInner<Args: ?Sized> {
+    // The subscription list. Only operations that change it take the lock.
+    handlers: Mutex<EventHandlers<Args>>,
+    // What `invoke` dispatches to, republished after every change to `handlers` so that
+    // invoking never takes a lock.
+    snapshot: Published<Snapshot<Args>>,
+    #[cfg(feature = "std")]
+    detached: detached::DetachedQueue,
+    // The dispatcher that `invoke` hands the arguments to, for events created with
+    // `DeliveryMode::Queued`.
+    #[cfg(feature = "std")]
+    queue: Option<Box<dyn dispatcher::Enqueue<Args>>>,
+    #[cfg(feature = "std")]
+    watchdog: watchdog::Watchdog,
+    #[cfg(feature = "std")]
+    counters: stats::EventCounters,
+}
+
+// How many handlers an event stores inline before its lists spill to the heap. Most events
+// have only a few subscribers, which then cost no allocations beyond the handlers themselves.
+const INLINE_HANDLERS: usize = 4;
+
+// An immutable copy of the subscription list, in dispatch order.
+struct Snapshot<Args: ?Sized> {
+    // Identifies the event in diagnostics.
+    event: usize,
+    cells: SmallVec<[HandlerCell<Args>; INLINE_HANDLERS]>,
+    interceptors: Vec<(Subscription, Interceptor<Args>)>,
+    hooks: Option<Hooks<Args>>,
+    mirrors: Vec<Weak<EventInner<Args>>>,
+    closed: bool,
+    #[cfg(feature = "std")]
+    max_depth: Option<(usize, RecursionPolicy)>,
+}
+
+impl<Args: ?Sized> Snapshot<Args> {
+    // Whether a dispatch skips `cell`: it was unsubscribed, or a recursion limit above 1 let
+    // the dispatch nest inside the handler's own call, which it would wait for forever.
+    fn skips(&self, cell: &HandlerSlot<Args>) -> bool {
+        if !cell.active.load(Ordering::Acquire) {
+            return true;
+        }
+        #[cfg(feature = "std")]
+        if self.max_depth.is_some() {
+            return cell.running_on.load(Ordering::Relaxed) == recursion::thread_token();
+        }
+        return false;
+    }
+}
+
+struct EventHandlers<Args: ?Sized> {
+    // Keyed by the slot index of the subscription, which the slab also checks the generation
+    // of.
+    handlers: Slab<Entry<Args>>,
+    // Subscriptions in the order they are dispatched to (or its reverse, for
+    // `DispatchOrder::Reverse`).
+    order: SmallVec<[Subscription; INLINE_HANDLERS]>,
+    config: EventConfig,
+    // Identifies this event in its subscriptions.
+    event: usize,
+    // Events created by `mirror`, which receive every dispatched payload. They are held
+    // weakly so a mirror detaches on its own once the last handle to it is dropped.
+    mirrors: Vec<Weak<EventInner<Args>>>,
+    // Added with `add_interceptor`, in the order they run. They take their subscriptions from
+    // the same slots as the handlers.
+    interceptors: Vec<(Subscription, Interceptor<Args>)>,
+    // Installed with `set_hooks`.
+    hooks: Option<Hooks<Args>>,
+    // Set by `close`, after which nothing subscribes or is dispatched.
+    closed: bool,
+    // Counts subscriptions, so the oldest handler can be told apart once slots are reused.
+    subscribed: u64,
+    // The events an operator derived this one from. Their forwarding handlers only hold this
+    // event weakly, so it keeps them alive instead, and chained operators keep working while
+    // only the last event of the chain is held.
+    sources: Vec<Box<dyn Any + Send>>,
+}
+
+struct Entry<Args: ?Sized> {
+    cell: HandlerCell<Args>,
+    priority: i32,
+    // The value of `EventHandlers::subscribed` when the handler subscribed.
+    seq: u64,
+}
+
+// A handler added by `EventHandlers::subscribe`.
+struct Subscribed {
+    id: Subscription,
+    // The handler removed to make room for it, on an event that evicts at its limit.
+    evicted: Option<Subscription>,
+}
+
+impl<Args: ?Sized> EventHandlers<Args> {
+    fn insert(&mut self, id: Subscription, priority: i32, cell: HandlerCell<Args>) {
+        match self.config.order {
+            DispatchOrder::Priority => {
+                // After every handler with the same or a higher priority, so ties keep their
+                // subscription order.
+                let position = self
+                    .order
+                    .partition_point(|other| self.entry(other).unwrap().priority >= priority);
+                self.order.insert(position, id);
+            }
+            _ => self.order.push(id),
+        }
+        let seq = self.subscribed;
+        self.subscribed += 1;
+        self.handlers.insert(id.index, Entry { cell, priority, seq });
+    }
+
+    fn remove(&mut self, id: Subscription) -> bool {
+        return self.remove_cell(id).is_some();
+    }
+
+    // Like `remove`, returning the removed handler's cell.
+    fn remove_cell(&mut self, id: Subscription) -> Option<HandlerCell<Args>> {
+        if id.event != self.event {
+            return None;
+        }
+        let entry = self.handlers.remove(id.index, id.generation)?;
+        entry.cell.active.store(false, Ordering::Release);
+        self.order.retain(|other| *other != id);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(subscription = ?id, "unsubscribed");
+        #[cfg(feature = "log")]
+        log::debug!("unsubscribed {:?}", id);
+        return Some(entry.cell);
+    }
+
+    // Removes every handler, returning their subscriptions.
+    fn clear(&mut self) -> Vec<Subscription> {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(event = self.event, count = self.handlers.len(), "unsubscribed all");
+        #[cfg(feature = "log")]
+        log::debug!(
+            "unsubscribed all {} handlers of event {}",
+            self.handlers.len(),
+            self.event
+        );
+        let mut removed = Vec::with_capacity(self.handlers.len());
+        for entry in self.handlers.drain() {
+            entry.cell.active.store(false, Ordering::Release);
+            removed.push(entry.cell.id);
+        }
+        self.order.clear();
+        return removed;
+    }
+
+    fn allocate(&mut self) -> Subscription {
+        let (index, generation) = self.handlers.allocate();
+        return Subscription {
+            event: self.event,
+            index,
+            generation,
+        };
+    }
+
+    // Releases a subscription from `allocate` that never got a handler.
+    fn release(&mut self, id: Subscription) {
+        self.handlers.release(id.index);
+    }
+
+    fn entry(&self, id: &Subscription) -> Option<&Entry<Args>> {
+        if id.event != self.event {
+            return None;
+        }
+        return self.handlers.get(id.index, id.generation);
+    }
+
+    // Adds the handler built by `make`, which is given the handler's own subscription.
+    // Fails without calling `make` if the event is closed or at its subscriber limit.
+    fn subscribe<M>(&mut self, priority: i32, name: Option<&'static str>, make: M) -> Result<Subscribed, EventError>
+    where
+        M: FnOnce(Subscription) -> Box<dyn EventHandler<Args>>,
+    {
+        if self.closed {
+            return Err(EventError::Closed);
+        }
+        let evicted = self.make_room()?;
+        let id = self.allocate();
+        let cell = Arc::new(HandlerSlot {
+            id,
+            name,
+            active: AtomicBool::new(true),
+            handler: Mutex::new(make(id)),
+            calls: AtomicUsize::new(0),
+            closing: AtomicBool::new(false),
+            #[cfg(feature = "std")]
+            subscribed_at: std::time::Instant::now(),
+            #[cfg(feature = "std")]
+            counters: stats::CallCounters::default(),
+            #[cfg(feature = "std")]
+            running_on: AtomicUsize::new(0),
+        });
+        self.insert(id, priority, cell);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(subscription = ?id, name, priority, "subscribed");
+        #[cfg(feature = "log")]
+        match name {
+            Some(name) => log::debug!("subscribed {:?} ({}) with priority {}", id, name, priority),
+            None => log::debug!("subscribed {:?} with priority {}", id, priority),
+        }
+        return Ok(Subscribed { id, evicted });
+    }
+
+    // Returns a subscription that matches no handler, for a subscription that was refused.
+    fn rejected(&mut self) -> Subscription {
+        let id = self.allocate();
+        self.release(id);
+        return id;
+    }
+
+    fn cell(&self, id: &Subscription) -> Option<HandlerCell<Args>> {
+        self.entry(id).map(|entry| Arc::clone(&entry.cell))
+    }
+
+    fn snapshot(&self) -> Snapshot<Args> {
+        let cells = self.order.iter().map(|id| Arc::clone(&self.entry(id).unwrap().cell));
+        let cells = if self.config.order == DispatchOrder::Reverse {
+            cells.rev().collect()
+        } else {
+            cells.collect()
+        };
+        Snapshot {
+            event: self.event,
+            cells,
+            interceptors: self.interceptors.clone(),
+            hooks: self.hooks.clone(),
+            mirrors: self.mirrors.clone(),
+            closed: self.closed,
+            #[cfg(feature = "std")]
+            max_depth: self.config.max_depth,
+        }
+    }
 }
 
-struct EventHandlers<Args> {
-    handlers: HashMap<Subscription, Box<dyn EventHandler<Args>>>,
-    next_id: usize,
+impl<Args: ?Sized> EventInner<Args> {
+    // Changes the subscription list and publishes the result to future invokes. The new
+    // snapshot is stored before the lock is released, so concurrent updates can't publish
+    // out of order.
+    //
+    // Removed handlers stay referenced by the previous snapshot, which is only dropped once the
+    // lock is released, so their destructors can use the event again.
+    fn update<R>(&self, change: impl FnOnce(&mut EventHandlers<Args>) -> R) -> R {
+        let mut handlers = self.handlers.acquire();
+        let (result, previous) = self.change_and_publish(&mut handlers, change);
+        drop(handlers);
+        drop(previous);
+        return result;
+    }
+
+    // Like `update`, but fails instead of recovering if the subscription list is poisoned.
+    fn try_update<R>(&self, change: impl FnOnce(&mut EventHandlers<Args>) -> R) -> Result<R, EventError> {
+        let mut handlers = self.handlers.acquire_unpoisoned().ok_or(EventError::Poisoned)?;
+        let (result, previous) = self.change_and_publish(&mut handlers, change);
+        drop(handlers);
+        drop(previous);
+        return Ok(result);
+    }
+
+    fn change_and_publish<R>(
+        &self,
+        handlers: &mut EventHandlers<Args>,
+        change: impl FnOnce(&mut EventHandlers<Args>) -> R,
+    ) -> (R, Arc<Snapshot<Args>>) {
+        let result = change(handlers);
+        let previous = self.snapshot.swap(handlers.snapshot());
+        #[cfg(feature = "std")]
+        self.counters.record_subscribers(handlers.handlers.len());
+        return (result, previous);
+    }
 }
 
-impl<Args> Event<Args> {
+impl<Args: ?Sized> Event<Args> {
     /// Creates a new event with no subscribers.
+    ///
+    /// The event uses the default [`Cloned`] delivery mode; use [`Event::builder`] to pick
+    /// another one.
     pub fn new() -> Self {
+        Self::empty()
+    }
+
+    /// Creates a new event with room for `capacity` subscribers before its handler storage
+    /// has to grow.
+    ///
+    /// Shorthand for building the event with [`EventBuilder::capacity`].
+    pub fn with_capacity(capacity: usize) -> Self {
+        return Event::builder().capacity(capacity).build();
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    // Creates an event with no subscribers in any delivery mode.
+    fn empty() -> Self {
+        Self::with_config(EventConfig::default())
+    }
+
+    fn with_config(config: EventConfig) -> Self {
+        #[cfg(feature = "std")]
+        return Self::with_dispatcher(config, |_| None);
+        #[cfg(not(feature = "std"))]
+        return Self::from_handlers(Self::new_handlers(config));
+    }
+
+    // Creates an event whose `invoke` goes through the dispatcher `start` returns, if any.
+    // `start` is given a handle to the event so the dispatcher can deliver to it.
+    #[cfg(feature = "std")]
+    fn with_dispatcher<S>(config: EventConfig, start: S) -> Self
+    where
+        S: FnOnce(Weak<EventInner<Args>>) -> Option<Box<dyn dispatcher::Enqueue<Args>>>,
+    {
+        let handlers = Self::new_handlers(config);
         Event {
-            handlers: Arc::new(Mutex::new(EventHandlers {
-                handlers: HashMap::new(),
-                next_id: 0,
-            })),
+            inner: Arc::new_cyclic(|event| EventInner {
+                snapshot: Published::new(handlers.snapshot()),
+                handlers: Mutex::new(handlers),
+                detached: detached::DetachedQueue::new(),
+                queue: start(Weak::clone(event)),
+                watchdog: watchdog::Watchdog::new(&config),
+                counters: stats::EventCounters::new(config.collect_stats),
+            }),
+            delivery: PhantomData,
         }
     }
 
-    /// Subscribes a callback to this event.
-    ///
-    /// Returns a `SubscriptionId` that must be used to unsubscribe later.
-    /// The subscription will remain active until explicitly unsubscribed.
-    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    #[cfg(not(feature = "std"))]
+    fn from_handlers(handlers: EventHandlers<Args>) -> Self {
+        Event {
+            inner: Arc::new(EventInner {
+                snapshot: Published::new(handlers.snapshot()),
+                handlers: Mutex::new(handlers),
+            }),
+            delivery: PhantomData,
+        }
+    }
+
+    fn new_handlers(config: EventConfig) -> EventHandlers<Args> {
+        EventHandlers {
+            handlers: Slab::with_capacity(config.capacity),
+            order: SmallVec::with_capacity(config.capacity),
+            config,
+            event: next_event_id(),
+            mirrors: Vec::new(),
+            interceptors: Vec::new(),
+            hooks: None,
+            closed: false,
+            subscribed: 0,
+            sources: Vec::new(),
+        }
+    }
+
+    fn subscribe_handler(&self, priority: i32, handler: Box<dyn EventHandler<Args>>) -> Subscription {
+        return self.subscribe_handler_with(priority, |_| handler);
+    }
+
+    // Subscribes the handler built by `make`, which is given the handler's own subscription
+    // so it can unsubscribe itself without racing against its first invocation.
+    fn subscribe_handler_with<M>(&self, priority: i32, make: M) -> Subscription
     where
-        F: FnMut(Args) + Send + 'static,
+        M: FnOnce(Subscription) -> Box<dyn EventHandler<Args>>,
     {
-        let mut handlers = self.handlers.lock().unwrap();
-        let id = Subscription(handlers.next_id);
-        handlers.next_id += 1;
-        handlers.handlers.insert(id, Box::new(handler));
-        id
+        return self.subscribe_named_handler(priority, None, make);
+    }
+
+    // Like `subscribe_handler_with`, also giving the handler a name.
+    fn subscribe_named_handler<M>(&self, priority: i32, name: Option<&'static str>, make: M) -> Subscription
+    where
+        M: FnOnce(Subscription) -> Box<dyn EventHandler<Args>>,
+    {
+        yield_point!(BeforeSubscribe);
+        let subscribed = self.inner.update(|handlers| {
+            handlers
+                .subscribe(priority, name, make)
+                .map_err(|error| (error, handlers.rejected()))
+        });
+        match subscribed {
+            Ok(subscribed) => return self.inner.notify_subscribed(subscribed),
+            Err((error, rejected)) => {
+                self.inner.notify_refused(&error);
+                return rejected;
+            }
+        }
+    }
+
+    fn try_subscribe_handler(
+        &self,
+        priority: i32,
+        handler: Box<dyn EventHandler<Args>>,
+    ) -> Result<Subscription, EventError> {
+        yield_point!(BeforeSubscribe);
+        let subscribed = self
+            .inner
+            .try_update(|handlers| handlers.subscribe(priority, None, |_| handler))?
+            .inspect_err(|error| self.inner.notify_refused(error))?;
+        return Ok(self.inner.notify_subscribed(subscribed));
+    }
+
+    // Returns a handle that doesn't keep the event alive, for handlers that need to refer
+    // back to the event they are subscribed to.
+    fn downgrade(&self) -> WeakEvent<Args, D> {
+        WeakEvent {
+            inner: Arc::downgrade(&self.inner),
+            delivery: PhantomData,
+        }
     }
 
     /// Unsubscribes a callback from this event.
     ///
     /// Returns `true` if the subscription was found and removed, `false` otherwise.
     pub fn unsubscribe(&self, id: Subscription) -> bool {
-        let mut handlers = self.handlers.lock().unwrap();
-        return handlers.handlers.remove(&id).is_some();
+        yield_point!(BeforeUnsubscribe);
+        let removed = self.inner.update(|handlers| handlers.remove(id));
+        if removed {
+            self.inner.notify(|hooks| hooks.on_unsubscribe(id));
+        }
+        return removed;
     }
 
     /// Removes all subscribers from this event.
     pub fn unsubscribe_all(&self) {
-        let mut handlers = self.handlers.lock().unwrap();
-        handlers.handlers.clear();
+        yield_point!(BeforeUnsubscribe);
+        let removed = self.inner.update(|handlers| handlers.clear());
+        self.inner.notify_unsubscribed(&removed);
     }
 
-    /// Triggers the event, calling all subscribed handlers with the provided arguments.
-    pub fn invoke(&self, args: Args)
+    /// Releases the memory the handler storage holds beyond what the current subscribers
+    /// need.
+    ///
+    /// The storage keeps its peak size after subscribers leave, so that subscribing again
+    /// doesn't allocate. Call this after unsubscribing many handlers at once to give the
+    /// memory back. Every subscription ever made still keeps a few bytes, so that a stale
+    /// [`Subscription`] can never match a newer handler.
+    pub fn shrink_to_fit(&self) {
+        self.inner.update(|handlers| {
+            handlers.order.shrink_to_fit();
+            handlers.handlers.shrink_to_fit();
+            handlers.interceptors.shrink_to_fit();
+            handlers.mirrors.shrink_to_fit();
+        });
+    }
+
+    /// Unsubscribes a callback like [`unsubscribe`](Self::unsubscribe), but returns
+    /// [`EventError::Poisoned`] instead of carrying on if an earlier panic left the
+    /// subscription list poisoned.
+    pub fn try_unsubscribe(&self, id: Subscription) -> Result<bool, EventError> {
+        yield_point!(BeforeUnsubscribe);
+        let removed = self.inner.try_update(|handlers| handlers.remove(id))?;
+        if removed {
+            self.inner.notify(|hooks| hooks.on_unsubscribe(id));
+        }
+        return Ok(removed);
+    }
+
+    /// Removes all subscribers like [`unsubscribe_all`](Self::unsubscribe_all), but returns
+    /// [`EventError::Poisoned`] instead of carrying on if an earlier panic left the
+    /// subscription list poisoned.
+    pub fn try_unsubscribe_all(&self) -> Result<(), EventError> {
+        yield_point!(BeforeUnsubscribe);
+        let removed = self.inner.try_update(|handlers| handlers.clear())?;
+        self.inner.notify_unsubscribed(&removed);
+        return Ok(());
+    }
+
+    // Calls every subscribed handler with a reference to `args`.
+    fn dispatch(&self, args: &Args) {
+        // Without catching panics the dispatch can't fail; a panic unwinds into the caller.
+        let _ = self.dispatch_with(args, false);
+    }
+
+    // Calls every subscribed handler with a reference to `args`, returning how many ran.
+    fn dispatch_counted(&self, args: &Args) -> usize {
+        match self.dispatch_with(args, false) {
+            Ok(report) => return report.handlers_called,
+            // Only returned when catching panics.
+            Err(_) => return 0,
+        }
+    }
+
+    // Calls every subscribed handler with a reference to `args`. With `catch_panics`, a
+    // panicking handler ends the dispatch with an error instead of unwinding into the caller.
+    fn dispatch_with(&self, args: &Args, catch_panics: bool) -> Result<DispatchReport, DispatchError> {
+        // The published snapshot is loaded without locking, and the handlers are run from it
+        // without touching the subscription list at all.
+        yield_point!(BeforeSnapshot);
+        let snapshot = self.inner.snapshot.load();
+        return self.dispatch_snapshot(&snapshot, args, catch_panics, |_| ());
+    }
+
+    // Calls every handler of `snapshot` with a reference to `args`, as `dispatch_with` does.
+    // `guard` is called before each handler, and what it returns is kept until the handler
+    // returns.
+    fn dispatch_snapshot<G>(
+        &self,
+        snapshot: &Snapshot<Args>,
+        args: &Args,
+        catch_panics: bool,
+        guard: impl FnMut(Subscription) -> G,
+    ) -> Result<DispatchReport, DispatchError> {
+        let mut payload = SharedPayload { args, replaced: None };
+        return self.dispatch_payload(snapshot, &mut payload, catch_panics, guard);
+    }
+
+    // Like `dispatch_snapshot`, for a payload lent to the handlers some other way.
+    fn dispatch_payload<G>(
+        &self,
+        snapshot: &Snapshot<Args>,
+        payload: &mut dyn Payload<Args>,
+        catch_panics: bool,
+        guard: impl FnMut(Subscription) -> G,
+    ) -> Result<DispatchReport, DispatchError> {
+        if snapshot.closed {
+            return Err(DispatchError::Closed);
+        }
+        #[cfg(feature = "std")]
+        let _nested = recursion::enter(snapshot, |hooks| hooks.on_recursion_overflow(payload.args()))?;
+        let Some(hooks) = &snapshot.hooks else {
+            return self.call_handlers(snapshot, payload, catch_panics, guard);
+        };
+        hooks.on_invoke_start(payload.args());
+        let result = self.call_handlers(snapshot, payload, catch_panics, guard);
+        hooks.on_invoke_end(result.as_ref());
+        return result;
+    }
+
+    // The body of `dispatch_payload`, without the hooks.
+    fn call_handlers<G>(
+        &self,
+        snapshot: &Snapshot<Args>,
+        payload: &mut dyn Payload<Args>,
+        catch_panics: bool,
+        mut guard: impl FnMut(Subscription) -> G,
+    ) -> Result<DispatchReport, DispatchError> {
+        #[cfg(feature = "std")]
+        self.inner.counters.record_invocations(1);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("invoke", event = snapshot.event, handlers = snapshot.cells.len()).entered();
+        #[cfg(feature = "log")]
+        if snapshot.cells.is_empty() {
+            log::debug!("invoked event {} with no subscribers", snapshot.event);
+        }
+        match self.intercept(snapshot, payload.args(), catch_panics)? {
+            Intercepted::Unchanged => {}
+            Intercepted::Replaced(args) => payload.replace(args),
+            Intercepted::Vetoed => {
+                return Ok(DispatchReport {
+                    vetoed: true,
+                    ..DispatchReport::default()
+                });
+            }
+        }
+        let mut result = Ok(DispatchReport::default());
+        for cell in snapshot.cells.iter() {
+            yield_point!(BeforeHandler);
+            if snapshot.skips(cell) {
+                if let Ok(report) = result.as_mut() {
+                    report.handlers_skipped += 1;
+                }
+                continue;
+            }
+
+            let _guard = guard(cell.id);
+            #[cfg(feature = "tracing")]
+            let _span = tracing::trace_span!("handler", subscription = ?cell.id, name = cell.name).entered();
+            #[cfg(feature = "std")]
+            let _timing = self.time(cell);
+            let mut call = || cell.call_with(&mut *payload);
+            let control = if catch_panics {
+                match catch_unwind(call) {
+                    Ok(control) => control,
+                    Err(payload) => {
+                        let error = DispatchError::handler_panicked(cell.id, payload.as_ref());
+                        #[cfg(feature = "log")]
+                        log::debug!("{}", error);
+                        result = Err(error);
+                        break;
+                    }
+                }
+            } else {
+                call()
+            };
+            if let Ok(report) = result.as_mut() {
+                report.handlers_called += 1;
+            }
+            match control {
+                HandlerControl::Continue => {}
+                HandlerControl::Unsubscribe => {
+                    self.unsubscribe(cell.id);
+                }
+                HandlerControl::StopPropagation => {
+                    if let Ok(report) = result.as_mut() {
+                        report.stopped = true;
+                    }
+                    break;
+                }
+            }
+        }
+
+        if result.is_ok() {
+            // The mirror's handlers aren't counted in this event's report.
+            let mirrored = self.dispatch_mirrors(&snapshot.mirrors, |mirror| {
+                mirror.dispatch_with(payload.args(), catch_panics).map(|_| ())
+            });
+            if let Err(error) = mirrored {
+                result = Err(error);
+            }
+        }
+        return result;
+    }
+
+    // Calls every subscribed handler with the arguments built by `make`, which is only called
+    // if there is someone to deliver them to. The handlers are the ones that were checked for.
+    fn dispatch_lazy<T>(&self, make: impl FnOnce() -> T)
+    where
+        T: Borrow<Args>,
+    {
+        yield_point!(BeforeSnapshot);
+        let snapshot = self.inner.snapshot.load();
+        if snapshot.closed {
+            return;
+        }
+        if snapshot.cells.is_empty() && snapshot.mirrors.is_empty() {
+            #[cfg(feature = "std")]
+            self.inner.counters.record_invocations(1);
+            #[cfg(feature = "log")]
+            log::debug!("invoked event {} with no subscribers", snapshot.event);
+            return;
+        }
+        let _ = self.dispatch_snapshot(&snapshot, make().borrow(), false, |_| ());
+    }
+
+    // Calls every subscribed handler with a reference to each item of `batch`. The snapshot
+    // is loaded once, and each handler is locked once for the whole batch.
+    fn dispatch_batch(&self, batch: &[Args])
     where
-        Args: Clone,
+        Args: Sized,
     {
-        let ids: Vec<Subscription> = {
-            let handlers = self.handlers.lock().unwrap();
-            handlers.handlers.keys().copied().collect()
+        yield_point!(BeforeSnapshot);
+        let snapshot = self.inner.snapshot.load();
+        if snapshot.closed {
+            return;
+        }
+        if !snapshot.interceptors.is_empty() || snapshot.hooks.is_some() {
+            // Each item has to be intercepted and reported on its own.
+            for args in batch.iter() {
+                let _ = self.dispatch_snapshot(&snapshot, args, false, |_| ());
+            }
+            return;
+        }
+        // Events with hooks took the path above, so there are none to hand an overflow to.
+        #[cfg(feature = "std")]
+        let Ok(_nested) = recursion::enter(&snapshot, |_| {}) else {
+            return;
         };
+        #[cfg(feature = "std")]
+        self.inner.counters.record_invocations(batch.len());
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("invoke_batch", handlers = snapshot.cells.len(), items = batch.len()).entered();
+        // The items a handler stopped from reaching the handlers after it.
+        let mut stopped = Vec::new();
+        for cell in snapshot.cells.iter() {
+            yield_point!(BeforeHandler);
+            if snapshot.skips(cell) {
+                continue;
+            }
+            #[cfg(feature = "tracing")]
+            let _span = tracing::trace_span!("handler", subscription = ?cell.id, name = cell.name).entered();
+            let mut calls = 0;
+            let mut unsubscribe = false;
+            cell.with_handler(|handler| {
+                for (index, args) in batch.iter().enumerate() {
+                    if stopped.get(index) == Some(&true) {
+                        continue;
+                    }
+                    calls += 1;
+                    // Timed per item, so the stats count calls the same way as `invoke`.
+                    #[cfg(feature = "std")]
+                    let _timing = self.time(cell);
+                    match handler.call_ctl(args) {
+                        HandlerControl::Continue => {}
+                        HandlerControl::Unsubscribe => {
+                            unsubscribe = true;
+                            break;
+                        }
+                        HandlerControl::StopPropagation => {
+                            stopped.resize(batch.len(), false);
+                            stopped[index] = true;
+                        }
+                    }
+                }
+            });
+            cell.calls.fetch_add(calls, Ordering::Relaxed);
+            if unsubscribe {
+                self.unsubscribe(cell.id);
+            }
+        }
+
+        let _ = self.dispatch_mirrors(&snapshot.mirrors, |mirror| {
+            mirror.dispatch_batch(batch);
+            Ok::<(), ()>(())
+        });
+    }
 
-        for id in ids {
-            let mut handlers = self.handlers.lock().unwrap();
-            if let Some(handler) = handlers.handlers.get_mut(&id) {
-                handler.call(args.clone());
+    // Runs `dispatch` on every mirror that is still alive, stopping at the first error, and
+    // forgets the mirrors that have been dropped.
+    fn dispatch_mirrors<E>(
+        &self,
+        mirrors: &[Weak<EventInner<Args>>],
+        mut dispatch: impl FnMut(&Event<Args, D>) -> Result<(), E>,
+    ) -> Result<(), E> {
+        let mut mirror_dropped = false;
+        let mut result = Ok(());
+        for mirror in mirrors.iter() {
+            match mirror.upgrade() {
+                Some(inner) => {
+                    let mirror = Event::<Args, D> {
+                        inner,
+                        delivery: PhantomData,
+                    };
+                    result = dispatch(&mirror);
+                    if result.is_err() {
+                        break;
+                    }
+                }
+                None => mirror_dropped = true,
             }
         }
+
+        if mirror_dropped {
+            self.inner
+                .update(|handlers| handlers.mirrors.retain(|mirror| mirror.strong_count() > 0));
+        }
+        return result;
+    }
+
+    /// Creates an event that receives every payload this event dispatches.
+    ///
+    /// The mirror is invoked right after this event's own handlers, with the same arguments.
+    /// It doesn't count as a subscriber and stays attached only as long as a handle to it is
+    /// kept, so monitoring code can tap an event without changing how it is wired up.
+    pub fn mirror(&self) -> Event<Args, D> {
+        let mirror = Event::<Args, D>::empty();
+        self.inner.update(|handlers| {
+            handlers.mirrors.retain(|mirror| mirror.strong_count() > 0);
+            handlers.mirrors.push(Arc::downgrade(&mirror.inner));
+        });
+        return mirror;
     }
 
     /// Returns the current number of active subscribers.
     pub fn subscriber_count(&self) -> usize {
-        let handlers = self.handlers.lock().unwrap();
-        return handlers.handlers.len();
+        return self.inner.snapshot.load().cells.len();
+    }
+
+    /// Returns `true` if `id` is subscribed to this event.
+    pub fn contains(&self, id: Subscription) -> bool {
+        return self.inner.handlers.acquire().cell(&id).is_some();
+    }
+
+    /// Returns the current subscriptions, in dispatch order.
+    ///
+    /// Together with [`subscription_info`](Self::subscription_info), this lets a long-running
+    /// program audit what is attached to an event.
+    pub fn subscriptions(&self) -> Vec<Subscription> {
+        return self.inner.snapshot.load().cells.iter().map(|cell| cell.id).collect();
+    }
+
+    /// Returns a description of `id`, or `None` if it isn't subscribed to this event.
+    pub fn subscription_info(&self, id: Subscription) -> Option<SubscriptionInfo> {
+        let cell = {
+            let handlers = self.inner.handlers.acquire();
+            handlers.cell(&id)?
+        };
+        Some(SubscriptionInfo {
+            subscription: id,
+            name: cell.name,
+            running: cell.handler.try_acquire().is_none(),
+            calls: cell.calls.load(Ordering::Relaxed) as u64,
+            #[cfg(feature = "std")]
+            subscribed_at: cell.subscribed_at,
+        })
+    }
+}
+
+impl<Args: Clone> Event<Args, Cloned> {
+    /// Subscribes a callback to this event.
+    ///
+    /// Returns a `SubscriptionId` that must be used to unsubscribe later.
+    /// The subscription will remain active until explicitly unsubscribed.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.subscribe_handler(0, Box::new(ByClone(handler)));
+    }
+
+    /// Subscribes a callback like [`subscribe`](Self::subscribe), but returns
+    /// [`EventError::Poisoned`] instead of carrying on if an earlier panic left the
+    /// subscription list poisoned.
+    pub fn try_subscribe<F>(&self, handler: F) -> Result<Subscription, EventError>
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.try_subscribe_handler(0, Box::new(ByClone(handler)));
+    }
+
+    /// Subscribes a callback with the given priority.
+    ///
+    /// On events using [`DispatchOrder::Priority`], handlers with a higher priority run first.
+    /// Other dispatch orders ignore the priority.
+    pub fn subscribe_with_priority<F>(&self, priority: i32, handler: F) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.subscribe_handler(priority, Box::new(ByClone(handler)));
+    }
+
+    // Subscribes the callback built by `make`, which receives its own subscription.
+    fn subscribe_with_id<M, F>(&self, make: M) -> Subscription
+    where
+        M: FnOnce(Subscription) -> F,
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.subscribe_handler_with(0, |id| Box::new(ByClone(make(id))));
+    }
+
+    /// Triggers the event, calling all subscribed handlers with the provided arguments.
+    ///
+    /// Each handler receives its own clone of `args`.
+    ///
+    /// Handlers may subscribe to and unsubscribe from this event while it is being invoked,
+    /// including unsubscribing themselves. The set of handlers is captured when `invoke` starts:
+    /// handlers added during the dispatch are first called on the next invoke, and handlers
+    /// removed during the dispatch are not called if they haven't run yet.
+    ///
+    /// On an event created with a [`DeliveryMode`] other than `Immediate`, the arguments are
+    /// queued instead, and the handlers run after `invoke` returns, as that mode describes.
+    pub fn invoke(&self, args: Args) {
+        #[cfg(feature = "std")]
+        if let Some(queue) = &self.inner.queue {
+            queue.enqueue(args);
+            return;
+        }
+        self.dispatch(&args);
+    }
+
+    /// Triggers the event like [`invoke`](Self::invoke) and returns how many handlers ran.
+    ///
+    /// A count of zero means no one handled the event, so the caller can fall back to its
+    /// default behavior. Handlers of [mirrors](Self::mirror) aren't counted. As with
+    /// [`try_invoke`](Self::try_invoke), the handlers always run on the calling thread, even
+    /// on a queued event.
+    pub fn invoke_counted(&self, args: Args) -> usize {
+        return self.dispatch_counted(&args);
+    }
+
+    /// Triggers the event with arguments built by `make`, which is only called if the event
+    /// has subscribers.
+    ///
+    /// Suits diagnostic events whose payload is costly to build and usually unobserved. The
+    /// check and the dispatch use the same captured set of handlers, so the payload is never
+    /// built for a dispatch that reaches no one, and handlers subscribed while it is being
+    /// built are first called on the next invoke. `make` is called at most once.
+    pub fn invoke_with<F>(&self, make: F)
+    where
+        F: FnOnce() -> Args,
+    {
+        #[cfg(feature = "std")]
+        if let Some(queue) = &self.inner.queue {
+            if self.subscriber_count() > 0 {
+                queue.enqueue(make());
+            }
+            return;
+        }
+        self.dispatch_lazy(make);
+    }
+
+    /// Triggers the event once for every item of `batch`, in order.
+    ///
+    /// This is much cheaper than calling [`invoke`](Self::invoke) in a loop: the set of
+    /// handlers is captured once for the whole batch, and each handler is called with every
+    /// item before the next handler runs, rather than every handler being called for one item
+    /// before the next item is delivered. Each handler still sees the items in order.
+    ///
+    /// On an event created with a [`DeliveryMode`] other than `Immediate`, every item is
+    /// queued as if it had been invoked on its own.
+    pub fn invoke_batch(&self, batch: &[Args]) {
+        #[cfg(feature = "std")]
+        if let Some(queue) = &self.inner.queue {
+            for args in batch.iter() {
+                queue.enqueue(args.clone());
+            }
+            return;
+        }
+        self.dispatch_batch(batch);
+    }
+
+    /// Triggers the event like [`invoke`](Self::invoke), but reports what happened instead of
+    /// letting a handler panic unwind into the caller.
+    ///
+    /// If a handler panics, the remaining handlers are not called and the panic is returned
+    /// as [`DispatchError::HandlerPanicked`].
+    ///
+    /// The handlers always run on the calling thread, even on a queued event, since the
+    /// report can't be produced otherwise.
+    pub fn try_invoke(&self, args: Args) -> Result<DispatchReport, DispatchError> {
+        return self.dispatch_with(&args, true);
     }
 }
 
-impl<Args> Clone for Event<Args> {
+impl<Args: ?Sized> Event<Args, Borrowed> {
+    /// Subscribes a callback to this event.
+    ///
+    /// The callback receives a reference to the arguments passed to `invoke`, so nothing is
+    /// cloned during dispatch.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(&Args) + Send + 'static,
+    {
+        return self.subscribe_handler(0, Box::new(handler));
+    }
+
+    /// Subscribes a callback like [`subscribe`](Self::subscribe), but returns
+    /// [`EventError::Poisoned`] instead of carrying on if an earlier panic left the
+    /// subscription list poisoned.
+    pub fn try_subscribe<F>(&self, handler: F) -> Result<Subscription, EventError>
+    where
+        F: FnMut(&Args) + Send + 'static,
+    {
+        return self.try_subscribe_handler(0, Box::new(handler));
+    }
+
+    /// Subscribes a callback with the given priority.
+    ///
+    /// On events using [`DispatchOrder::Priority`], handlers with a higher priority run first.
+    /// Other dispatch orders ignore the priority.
+    pub fn subscribe_with_priority<F>(&self, priority: i32, handler: F) -> Subscription
+    where
+        F: FnMut(&Args) + Send + 'static,
+    {
+        return self.subscribe_handler(priority, Box::new(handler));
+    }
+
+    /// Triggers the event, calling all subscribed handlers with a reference to `args`.
+    ///
+    /// Subscription changes made during dispatch are handled the same way as in the
+    /// [`Cloned`] mode: the set of handlers is captured when `invoke` starts.
+    pub fn invoke(&self, args: &Args) {
+        self.dispatch(args);
+    }
+
+    /// Triggers the event like [`invoke`](Self::invoke) and returns how many handlers ran.
+    /// See the [`Cloned`] mode's [`invoke_counted`](Event::invoke_counted).
+    pub fn invoke_counted(&self, args: &Args) -> usize {
+        return self.dispatch_counted(args);
+    }
+
+    /// Triggers the event with arguments built by `make`, which is only called if the event
+    /// has subscribers. See the [`Cloned`] mode's [`invoke_with`](Event::invoke_with).
+    ///
+    /// `make` may return anything that borrows as `Args`, such as a `String` for an
+    /// `Event<str, Borrowed>`.
+    pub fn invoke_with<F, T>(&self, make: F)
+    where
+        F: FnOnce() -> T,
+        T: Borrow<Args>,
+    {
+        self.dispatch_lazy(make);
+    }
+
+    /// Triggers the event once for every item of `batch`, capturing the set of handlers once
+    /// and calling each handler with every item before the next handler runs. See the
+    /// [`Cloned`] mode's [`invoke_batch`](Event::invoke_batch).
+    pub fn invoke_batch(&self, batch: &[Args])
+    where
+        Args: Sized,
+    {
+        self.dispatch_batch(batch);
+    }
+
+    /// Triggers the event like [`invoke`](Self::invoke), but reports what happened instead of
+    /// letting a handler panic unwind into the caller.
+    ///
+    /// If a handler panics, the remaining handlers are not called and the panic is returned
+    /// as [`DispatchError::HandlerPanicked`].
+    pub fn try_invoke(&self, args: &Args) -> Result<DispatchReport, DispatchError> {
+        return self.dispatch_with(args, true);
+    }
+}
+
+// Runs `f`, returning the payload of its panic if it panics. Without `std` panics can't be
+// caught, so they always propagate.
+#[cfg(feature = "std")]
+fn catch_unwind<R>(f: impl FnOnce() -> R) -> Result<R, Box<dyn Any + Send>> {
+    return std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+}
+
+#[cfg(not(feature = "std"))]
+fn catch_unwind<R>(f: impl FnOnce() -> R) -> Result<R, Box<dyn Any + Send>> {
+    return Ok(f());
+}
+
+// A non-owning reference to an event's subscription list.
+struct WeakEvent<Args: ?Sized, D> {
+    inner: Weak<EventInner<Args>>,
+    delivery: PhantomData<D>,
+}
+
+impl<Args: ?Sized, D> WeakEvent<Args, D> {
+    fn upgrade(&self) -> Option<Event<Args, D>> {
+        let inner = self.inner.upgrade()?;
+        Some(Event {
+            inner,
+            delivery: PhantomData,
+        })
+    }
+}
+
+impl<Args: ?Sized, D> Clone for Event<Args, D> {
     fn clone(&self) -> Self {
         Event {
-            handlers: Arc::clone(&self.handlers),
+            inner: Arc::clone(&self.inner),
+            delivery: PhantomData,
+        }
+    }
+}
+
+// Shows what can be known about the event without its handlers, which can't be printed.
+impl<Args: ?Sized, D> core::fmt::Debug for Event<Args, D> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let snapshot = self.inner.snapshot.load();
+        let mut debug = f.debug_struct("Event");
+        debug
+            .field("id", &snapshot.event)
+            .field("subscribers", &snapshot.cells.len())
+            .field("handlers", &self.debug_subscribers())
+            .field("closed", &snapshot.closed);
+        #[cfg(feature = "std")]
+        match &self.inner.queue {
+            Some(queue) => {
+                debug.field("delivery", &queue.mode());
+                if let Some(pending) = queue.pending() {
+                    debug.field("pending", &pending);
+                }
+            }
+            None => {
+                debug.field("delivery", &DeliveryMode::Immediate);
+            }
         }
+        return debug.finish_non_exhaustive();
     }
 }
 
-impl<Args> Default for Event<Args> {
+impl<Args: ?Sized, D> Default for Event<Args, D> {
     fn default() -> Self {
-        Self::new()
+        Self::empty()
     }
 }
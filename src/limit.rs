@@ -0,0 +1,64 @@
+use crate::{Event, EventError, EventHandlers, Subscription};
+
+/// What an event with a [subscriber limit](crate::EventBuilder::max_subscribers) does with
+/// a subscription that would take it over the limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum SubscriberLimit {
+    /// The subscription is refused. `try_subscribe` returns
+    /// [`EventError::TooManySubscribers`], and `subscribe` returns a subscription that
+    /// matches no handler.
+    #[default]
+    Reject,
+    /// The handler that has been subscribed the longest is unsubscribed to make room.
+    EvictOldest,
+}
+
+impl<Args: ?Sized> EventHandlers<Args> {
+    // Makes sure one more handler fits under the subscriber limit, evicting the oldest one if
+    // the limit says so. Returns the evicted subscription.
+    pub(crate) fn make_room(&mut self) -> Result<Option<Subscription>, EventError> {
+        let Some((limit, policy)) = self.config.max_subscribers else {
+            return Ok(None);
+        };
+        if self.handlers.len() < limit {
+            return Ok(None);
+        }
+        #[cfg(feature = "tracing")]
+        tracing::warn!(event = self.event, limit, ?policy, "subscriber limit reached");
+        #[cfg(feature = "log")]
+        log::warn!("event {} reached its limit of {} subscribers", self.event, limit);
+        let oldest = self
+            .handlers
+            .iter()
+            .min_by_key(|entry| entry.seq)
+            .map(|entry| entry.cell.id);
+        match (policy, oldest) {
+            (SubscriberLimit::EvictOldest, Some(oldest)) => {
+                self.remove(oldest);
+                return Ok(Some(oldest));
+            }
+            _ => return Err(EventError::TooManySubscribers),
+        }
+    }
+}
+
+impl<Args: ?Sized> Event<Args> {
+    /// Creates a new event that holds at most `limit` handlers and refuses subscriptions
+    /// beyond that.
+    ///
+    /// Shorthand for building the event with
+    /// [`max_subscribers`](crate::EventBuilder::max_subscribers) and
+    /// [`SubscriberLimit::Reject`].
+    ///
+    /// ```
+    /// use blob_event::{Event, EventError};
+    ///
+    /// let clicked = Event::<(i32, i32)>::with_max_subscribers(1);
+    /// clicked.subscribe(|(x, y)| println!("clicked at {}, {}", x, y));
+    /// assert_eq!(clicked.try_subscribe(|_| {}), Err(EventError::TooManySubscribers));
+    /// ```
+    pub fn with_max_subscribers(limit: usize) -> Self {
+        return Event::builder().max_subscribers(limit, SubscriberLimit::Reject).build();
+    }
+}
@@ -0,0 +1,143 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::vec::Vec;
+use core::cell::{Cell, RefCell};
+
+use crate::{Subscription, next_event_id};
+
+/// A single-threaded event whose handlers don't need to be `Send`.
+///
+/// `LocalEvent` has the same subscription semantics as [`Event`](crate::Event) in the
+/// default [`Cloned`](crate::Cloned) mode, but it can't be shared across threads, so handlers
+/// may capture `Rc`s, `RefCell`s, and other thread-bound values such as JavaScript objects on
+/// `wasm32-unknown-unknown`.
+///
+/// ```
+/// use blob_event::LocalEvent;
+/// use std::cell::RefCell;
+/// use std::rc::Rc;
+///
+/// let event = LocalEvent::<i32>::new();
+/// let seen = Rc::new(RefCell::new(Vec::new()));
+///
+/// let seen_clone = Rc::clone(&seen);
+/// event.subscribe(move |x| seen_clone.borrow_mut().push(x));
+///
+/// event.invoke(1);
+/// assert_eq!(*seen.borrow(), vec![1]);
+/// ```
+pub struct LocalEvent<Args> {
+    inner: Rc<RefCell<LocalHandlers<Args>>>,
+}
+
+struct LocalHandlers<Args> {
+    event: usize,
+    next_index: u32,
+    // Subscribed handlers in subscription order.
+    handlers: Vec<LocalCell<Args>>,
+}
+
+type LocalCell<Args> = Rc<LocalSlot<Args>>;
+
+struct LocalSlot<Args> {
+    id: Subscription,
+    // Cleared when the subscription is removed, so invokes that already hold the cell in
+    // their snapshot skip it.
+    active: Cell<bool>,
+    handler: RefCell<Box<dyn FnMut(Args)>>,
+}
+
+impl<Args: Clone + 'static> LocalEvent<Args> {
+    /// Creates a new event with no subscribers.
+    pub fn new() -> Self {
+        LocalEvent {
+            inner: Rc::new(RefCell::new(LocalHandlers {
+                event: next_event_id(),
+                next_index: 0,
+                handlers: Vec::new(),
+            })),
+        }
+    }
+
+    /// Subscribes a callback to this event.
+    ///
+    /// Returns a `Subscription` that must be used to unsubscribe later.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Args) + 'static,
+    {
+        let mut handlers = self.inner.borrow_mut();
+        let id = Subscription {
+            event: handlers.event,
+            index: handlers.next_index,
+            generation: 0,
+        };
+        handlers.next_index = handlers.next_index.checked_add(1).expect("too many subscriptions");
+        handlers.handlers.push(Rc::new(LocalSlot {
+            id,
+            active: Cell::new(true),
+            handler: RefCell::new(Box::new(handler)),
+        }));
+        return id;
+    }
+
+    /// Unsubscribes a callback from this event.
+    ///
+    /// Returns `true` if the subscription was found and removed, `false` otherwise.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        let mut handlers = self.inner.borrow_mut();
+        let Some(position) = handlers.handlers.iter().position(|cell| cell.id == id) else {
+            return false;
+        };
+        let cell = handlers.handlers.remove(position);
+        cell.active.set(false);
+        // Released after the borrow, since dropping the handler may touch this event.
+        drop(handlers);
+        drop(cell);
+        return true;
+    }
+
+    /// Removes all subscribers from this event.
+    pub fn unsubscribe_all(&self) {
+        let removed = core::mem::take(&mut self.inner.borrow_mut().handlers);
+        for cell in removed.iter() {
+            cell.active.set(false);
+        }
+    }
+
+    /// Returns the current number of subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        return self.inner.borrow().handlers.len();
+    }
+
+    /// Triggers the event, calling all subscribed handlers with a clone of `args`.
+    ///
+    /// Handlers may subscribe to and unsubscribe from this event while it is being invoked.
+    /// As with [`Event`](crate::Event), the set of handlers is captured when `invoke` starts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a handler invokes this event again while it is still running.
+    pub fn invoke(&self, args: Args) {
+        let snapshot: Vec<LocalCell<Args>> = self.inner.borrow().handlers.clone();
+        for cell in snapshot.iter() {
+            if cell.active.get() {
+                (cell.handler.borrow_mut())(args.clone());
+            }
+        }
+    }
+}
+
+impl<Args> Clone for LocalEvent<Args> {
+    fn clone(&self) -> Self {
+        LocalEvent {
+            inner: Rc::clone(&self.inner),
+        }
+    }
+}
+
+impl<Args: Clone + 'static> Default for LocalEvent<Args> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,115 @@
+use std::sync::mpsc::{self, Sender};
+use std::thread::{self, JoinHandle};
+
+use crate::{Event, Subscription};
+
+/// An actor-style inbox: messages [sent](Address::send) to it queue up, and a thread owned
+/// by the mailbox delivers them one at a time, in the order they were sent, to its
+/// subscribers.
+///
+/// Every handler of a mailbox runs on that one thread and never concurrently with another,
+/// so handlers can share state with the other handlers of the mailbox without locking it
+/// against them. Handler panics are caught, so one bad message doesn't stop the mailbox.
+/// The thread delivers what is left in the queue and exits once the mailbox and all of its
+/// addresses are dropped.
+///
+/// ```
+/// use blob_event::Mailbox;
+///
+/// let logger = Mailbox::<String>::new();
+/// logger.subscribe(|line| println!("[log] {}", line));
+///
+/// let address = logger.address();
+/// std::thread::spawn(move || address.send("from a worker".to_string()));
+/// logger.join(); // Waits for the worker's message to be logged
+/// ```
+pub struct Mailbox<Msg> {
+    event: Event<Msg>,
+    sender: Sender<Msg>,
+    thread: JoinHandle<()>,
+}
+
+/// A handle for sending messages to a [`Mailbox`]. Cloning it returns another handle to the
+/// same mailbox.
+pub struct Address<Msg> {
+    sender: Sender<Msg>,
+}
+
+impl<Msg> Mailbox<Msg>
+where
+    Msg: Clone + Send + 'static,
+{
+    /// Creates a mailbox with no subscribers, and starts its thread.
+    pub fn new() -> Self {
+        let event = Event::new();
+        let (sender, receiver) = mpsc::channel::<Msg>();
+        let handlers = event.clone();
+        let thread = thread::Builder::new()
+            .name("blob-event-mailbox".to_string())
+            .spawn(move || {
+                for msg in receiver {
+                    let _ = handlers.try_invoke(msg);
+                }
+            })
+            .expect("failed to spawn a blob-event mailbox thread");
+        return Mailbox { event, sender, thread };
+    }
+
+    /// Returns a handle for sending messages to this mailbox.
+    pub fn address(&self) -> Address<Msg> {
+        return Address {
+            sender: self.sender.clone(),
+        };
+    }
+
+    /// Subscribes a handler, which receives every message delivered after it subscribed, on
+    /// the mailbox's thread.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Msg) + Send + 'static,
+    {
+        return self.event.subscribe(handler);
+    }
+
+    /// Unsubscribes a handler, returning `true` if it was subscribed.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.event.unsubscribe(id);
+    }
+
+    /// Drops the mailbox and waits for its thread to deliver every message still queued.
+    ///
+    /// The thread only exits once every [`Address`] has been dropped too, so joining while
+    /// an address is kept alive elsewhere waits until it is gone.
+    pub fn join(self) {
+        let Mailbox { event, sender, thread } = self;
+        drop(sender);
+        drop(event);
+        let _ = thread.join();
+    }
+}
+
+impl<Msg> Default for Mailbox<Msg>
+where
+    Msg: Clone + Send + 'static,
+{
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<Msg> Address<Msg> {
+    /// Queues `msg` for delivery.
+    pub fn send(&self, msg: Msg) {
+        // The mailbox's thread runs for as long as any address exists, so the message
+        // always has somewhere to go.
+        let _ = self.sender.send(msg);
+    }
+}
+
+impl<Msg> Clone for Address<Msg> {
+    fn clone(&self) -> Self {
+        return Address {
+            sender: self.sender.clone(),
+        };
+    }
+}
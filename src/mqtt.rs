@@ -0,0 +1,248 @@
+//! Events bridged to MQTT topics, available with the `mqtt` feature.
+//!
+//! An [`MqttBridge`] holds one connection to a broker. [`publish`](MqttBridge::publish)
+//! sends every dispatch of a local event to a topic, and
+//! [`subscribe`](MqttBridge::subscribe) returns a local event invoked with every message
+//! received on a topic filter:
+//!
+//! ```no_run
+//! use blob_event::Event;
+//! use blob_event::mqtt::{self, MqttOptions};
+//!
+//! let bridge = mqtt::connect(MqttOptions::new("thermostat", "broker.local", 1883));
+//!
+//! let setpoint = Event::<f32>::new();
+//! bridge.publish(&setpoint, "home/living-room/setpoint");
+//! setpoint.invoke(21.5);
+//!
+//! let temperatures = bridge.subscribe::<f32>("home/+/temperature").unwrap();
+//! temperatures.subscribe(|celsius| println!("it's {} degrees", celsius));
+//! ```
+//!
+//! Payloads are JSON, so arguments need `serde`'s `Serialize` to be published and
+//! `DeserializeOwned` to be received; received messages that don't deserialize are ignored.
+//! Messages are sent and received with QoS 1, at least once. The bridge reconnects by
+//! itself when the connection drops, subscribing to its topic filters again, and invokes
+//! the subscribed events on a thread it owns.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+use std::time::Duration;
+
+use rumqttc::{Client, Connection, Incoming, Outgoing, QoS};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::Event;
+
+pub use rumqttc::{ClientError, MqttOptions};
+
+// How long to wait before reconnecting after the connection failed.
+const RECONNECT_DELAY: Duration = Duration::from_secs(1);
+
+/// A connection to an MQTT broker that events are bridged through, as returned by
+/// [`connect`].
+///
+/// Dropping the bridge disconnects from the broker; its subscribed events are no longer
+/// invoked.
+pub struct MqttBridge {
+    client: Client,
+    // The thread keeping the connection going, the only one that makes room in the queue of
+    // outgoing requests.
+    thread: ThreadId,
+    routes: Arc<Routes>,
+    // The mirrors tapping the published events, by topic. Dropping one detaches it.
+    published: Mutex<HashMap<String, Box<dyn Any + Send>>>,
+}
+
+// The topic filters subscribed to, with the events receiving their messages.
+struct Routes {
+    filters: Mutex<Vec<Route>>,
+    closed: AtomicBool,
+}
+
+struct Route {
+    filter: String,
+    // The `Event<Args>` itself, and how to invoke it with a payload.
+    event: Box<dyn Any + Send>,
+    dispatch: Dispatch,
+}
+
+type Dispatch = Arc<dyn Fn(&[u8]) + Send + Sync>;
+
+/// Connects to the broker described by `options`, and starts the thread that keeps the
+/// connection going.
+///
+/// Connecting happens in the background, so this doesn't fail; until the broker is
+/// reachable, published messages queue up.
+pub fn connect(options: MqttOptions) -> MqttBridge {
+    let (client, connection) = Client::new(options, 64);
+    let routes = Arc::new(Routes {
+        filters: Mutex::new(Vec::new()),
+        closed: AtomicBool::new(false),
+    });
+    let receiving = Arc::clone(&routes);
+    let resubscribing = client.clone();
+    let handle = thread::Builder::new()
+        .name("blob-event-mqtt".to_string())
+        .spawn(move || run(connection, resubscribing, receiving))
+        .expect("failed to spawn the blob-event MQTT thread");
+    return MqttBridge {
+        client,
+        thread: handle.thread().id(),
+        routes,
+        published: Mutex::new(HashMap::new()),
+    };
+}
+
+fn run(mut connection: Connection, client: Client, routes: Arc<Routes>) {
+    for notification in connection.iter() {
+        match notification {
+            Ok(rumqttc::Event::Incoming(Incoming::Publish(publish))) => {
+                let dispatches: Vec<_> = routes
+                    .filters
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .filter(|route| rumqttc::matches(&publish.topic, &route.filter))
+                    .map(|route| Arc::clone(&route.dispatch))
+                    .collect();
+                for dispatch in dispatches {
+                    dispatch(&publish.payload);
+                }
+            }
+            // A broker that kept no session for us has forgotten the subscriptions.
+            Ok(rumqttc::Event::Incoming(Incoming::ConnAck(ack))) if !ack.session_present => {
+                for route in routes.filters.lock().unwrap().iter() {
+                    let _ = client.try_subscribe(route.filter.as_str(), QoS::AtLeastOnce);
+                }
+            }
+            Ok(rumqttc::Event::Outgoing(Outgoing::Disconnect)) => return,
+            Ok(_) => {}
+            Err(_) => {
+                if routes.closed.load(Ordering::SeqCst) {
+                    return;
+                }
+                thread::sleep(RECONNECT_DELAY);
+            }
+        }
+    }
+}
+
+impl MqttBridge {
+    /// Publishes every dispatch of `event` to `topic`, from now on.
+    ///
+    /// The event is tapped through a [mirror](Event::mirror), so publishing it doesn't count
+    /// as a subscriber. Publishing another event to the same topic replaces the first one.
+    /// While the bridge's queue of outgoing messages is full, dispatching the event waits
+    /// for room in it, except on the bridge's own thread, where the handlers of subscribed
+    /// events run: only that thread makes room, so a dispatch there drops the message instead.
+    pub fn publish<Args>(&self, event: &Event<Args>, topic: &str)
+    where
+        Args: Serialize + Clone + Send + 'static,
+    {
+        let mirror = event.mirror();
+        let client = self.client.clone();
+        let connection_thread = self.thread;
+        let target = topic.to_string();
+        mirror.subscribe(move |args: Args| {
+            let Ok(payload) = serde_json::to_vec(&args) else {
+                return;
+            };
+            if thread::current().id() == connection_thread {
+                let _ = client.try_publish(target.as_str(), QoS::AtLeastOnce, false, payload);
+            } else {
+                let _ = client.publish(target.as_str(), QoS::AtLeastOnce, false, payload);
+            }
+        });
+        self.published
+            .lock()
+            .unwrap()
+            .insert(topic.to_string(), Box::new(mirror));
+    }
+
+    /// Stops publishing the event published to `topic`, returning `true` if there was one.
+    pub fn unpublish(&self, topic: &str) -> bool {
+        return self.published.lock().unwrap().remove(topic).is_some();
+    }
+
+    /// Subscribes to the topic filter `filter`, and returns the local event invoked with
+    /// every message received on a matching topic. Asking for the same filter again returns
+    /// the same event.
+    ///
+    /// Filters can use the MQTT wildcards `+` and `#`. A message whose topic matches several
+    /// subscribed filters invokes each of their events.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the same filter was subscribed to before with a different `Args`.
+    pub fn subscribe<Args>(&self, filter: &str) -> Result<Event<Args>, ClientError>
+    where
+        Args: DeserializeOwned + Clone + Send + 'static,
+    {
+        let mut filters = self.routes.filters.lock().unwrap();
+        if let Some(route) = filters.iter().find(|route| route.filter == filter) {
+            let event = route
+                .event
+                .downcast_ref::<Event<Args>>()
+                .unwrap_or_else(|| panic!("MQTT filter `{}` was subscribed to with another type", filter));
+            return Ok(event.clone());
+        }
+        let event = Event::<Args>::new();
+        let dispatching = event.clone();
+        let dispatch = move |payload: &[u8]| {
+            if let Ok(args) = serde_json::from_slice(payload) {
+                let _ = dispatching.try_invoke(args);
+            }
+        };
+        filters.push(Route {
+            filter: filter.to_string(),
+            event: Box::new(event.clone()),
+            dispatch: Arc::new(dispatch),
+        });
+        drop(filters);
+        // Waiting for room in the queue of outgoing requests needs the connection's thread,
+        // which takes the filters' lock.
+        if let Err(error) = self.client.subscribe(filter, QoS::AtLeastOnce) {
+            self.routes
+                .filters
+                .lock()
+                .unwrap()
+                .retain(|route| route.filter != filter);
+            return Err(error);
+        }
+        return Ok(event);
+    }
+
+    /// Unsubscribes from the topic filter `filter`, returning `true` if it was subscribed.
+    ///
+    /// Its event is no longer invoked, even if it is still held elsewhere.
+    pub fn unsubscribe(&self, filter: &str) -> bool {
+        let mut filters = self.routes.filters.lock().unwrap();
+        let Some(position) = filters.iter().position(|route| route.filter == filter) else {
+            return false;
+        };
+        filters.remove(position);
+        drop(filters);
+        let _ = self.client.try_unsubscribe(filter);
+        return true;
+    }
+}
+
+impl Drop for MqttBridge {
+    fn drop(&mut self) {
+        self.published.get_mut().unwrap().clear();
+        self.routes.closed.store(true, Ordering::SeqCst);
+        // With the queue of outgoing requests full, disconnecting waits for the connection's
+        // thread to make room, which the thread dropping the bridge may be.
+        if let Err(ClientError::TryRequest(_)) = self.client.try_disconnect() {
+            let client = self.client.clone();
+            let _ = thread::Builder::new()
+                .name("blob-event-mqtt".to_string())
+                .spawn(move || client.disconnect());
+        }
+    }
+}
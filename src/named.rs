@@ -0,0 +1,50 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::{Borrowed, ByClone, Cloned, Event, Subscription};
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    /// Returns every current subscription with the name it was given by `subscribe_named`,
+    /// if any, in dispatch order.
+    ///
+    /// Meant for debug overlays and logs that need to show who is listening.
+    pub fn debug_subscribers(&self) -> Vec<(Subscription, Option<&'static str>)> {
+        let snapshot = self.inner.snapshot.load();
+        return snapshot.cells.iter().map(|cell| (cell.id, cell.name)).collect();
+    }
+}
+
+impl<Args: Clone> Event<Args, Cloned> {
+    /// Subscribes a callback like [`subscribe`](Self::subscribe), giving it a name.
+    ///
+    /// The name shows up in [`debug_subscribers`](Self::debug_subscribers),
+    /// [`subscription_info`](Self::subscription_info), and the `tracing` spans and `log`
+    /// messages of the handler, which makes a misbehaving subscriber much easier to find
+    /// than its `Subscription` alone.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// let tick = Event::<f32>::new();
+    /// let physics = tick.subscribe_named("physics_step", |dt| println!("stepping {}s", dt));
+    /// assert_eq!(tick.debug_subscribers(), [(physics, Some("physics_step"))]);
+    /// ```
+    pub fn subscribe_named<F>(&self, name: &'static str, handler: F) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.subscribe_named_handler(0, Some(name), |_| Box::new(ByClone(handler)));
+    }
+}
+
+impl<Args: ?Sized> Event<Args, Borrowed> {
+    /// Subscribes a callback like [`subscribe`](Self::subscribe), giving it a name.
+    ///
+    /// See [`Event::subscribe_named`] for where the name shows up.
+    pub fn subscribe_named<F>(&self, name: &'static str, handler: F) -> Subscription
+    where
+        F: FnMut(&Args) + Send + 'static,
+    {
+        return self.subscribe_named_handler(0, Some(name), |_| Box::new(handler));
+    }
+}
@@ -0,0 +1,161 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll, Waker};
+
+use crate::sync::{Lock, Mutex};
+use crate::{Event, EventError, EventHandler, Subscription};
+
+/// A future that resolves to the arguments of an event's next dispatch, as returned by
+/// [`Event::next`].
+///
+/// It holds a subscription on the event until it resolves or is dropped.
+#[must_use = "futures do nothing unless polled"]
+pub struct Next<Args, D> {
+    event: Event<Args, D>,
+    // `None` if the event refused the subscription.
+    id: Option<Subscription>,
+    state: Arc<Mutex<NextState<Args>>>,
+}
+
+struct NextState<Args> {
+    args: Option<Args>,
+    // Set if the event was closed before it was dispatched, or refused the subscription.
+    failed: Option<EventError>,
+    waker: Option<Waker>,
+}
+
+impl<Args> NextState<Args> {
+    // Marks the event as closed, returning the waker to wake if the future was still pending.
+    fn close(&mut self) -> Option<Waker> {
+        if self.args.is_some() {
+            return None;
+        }
+        self.failed = Some(EventError::Closed);
+        return self.waker.take();
+    }
+}
+
+// The one-shot handler behind a `Next`.
+struct Resolve<Args> {
+    state: Arc<Mutex<NextState<Args>>>,
+}
+
+impl<Args: Clone + Send> EventHandler<Args> for Resolve<Args> {
+    fn call(&mut self, args: &Args) {
+        let waker = {
+            let mut state = self.state.acquire();
+            // Only the first dispatch resolves the future.
+            if state.args.is_some() {
+                return;
+            }
+            state.args = Some(args.clone());
+            state.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+
+    fn close(&mut self) {
+        let waker = self.state.acquire().close();
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<Args, D> Event<Args, D>
+where
+    Args: Clone + Send + 'static,
+{
+    /// Returns a future that resolves to a clone of the arguments of the event's next
+    /// dispatch.
+    ///
+    /// The future subscribes to the event right away, not when first polled, so a dispatch
+    /// that happens between this call and the `.await` is not missed. It counts as a
+    /// subscriber until it resolves or is dropped. No executor or runtime is assumed: the
+    /// future is woken from the dispatching thread.
+    ///
+    /// ```
+    /// # use std::future::Future;
+    /// # use std::pin::pin;
+    /// # use std::task::{Context, Poll, Waker};
+    /// use blob_event::Event;
+    ///
+    /// let connected = Event::<u16>::new();
+    /// let mut next = pin!(connected.next());
+    /// connected.invoke(8080);
+    ///
+    /// let mut cx = Context::from_waker(Waker::noop());
+    /// assert_eq!(next.as_mut().poll(&mut cx), Poll::Ready(8080));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// The future panics when polled if the event is [closed](Self::close) before it is
+    /// dispatched, or if the event has reached its
+    /// [subscriber limit](crate::EventBuilder::max_subscribers) and refused the subscription,
+    /// as [`wait`](Self::wait) does.
+    pub fn next(&self) -> Next<Args, D> {
+        let state = Arc::new(Mutex::new(NextState {
+            args: None,
+            failed: None,
+            waker: None,
+        }));
+        let resolve = Resolve {
+            state: Arc::clone(&state),
+        };
+        // A refused handler is dropped without being told, so the future would never resolve.
+        let id = self
+            .try_subscribe_handler(0, Box::new(resolve))
+            .inspect_err(|error| state.acquire().failed = Some(error.clone()))
+            .ok();
+        Next {
+            event: self.clone(),
+            id,
+            state,
+        }
+    }
+}
+
+impl<Args, D> Future for Next<Args, D> {
+    type Output = Args;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Args> {
+        let this = self.get_mut();
+        let args = {
+            let mut state = this.state.acquire();
+            match state.args.take() {
+                Some(args) => args,
+                None if let Some(error) = state.failed.clone() => {
+                    drop(state);
+                    if error == EventError::Closed {
+                        panic!("awaited the next dispatch of a closed event");
+                    }
+                    panic!("couldn't await the next dispatch of the event: {}", error);
+                }
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+            }
+        };
+        if let Some(id) = this.id {
+            this.event.unsubscribe(id);
+        }
+        return Poll::Ready(args);
+    }
+}
+
+impl<Args, D> Unpin for Next<Args, D> {}
+
+impl<Args, D> Drop for Next<Args, D> {
+    fn drop(&mut self) {
+        // Does nothing if the future already resolved.
+        if let Some(id) = self.id {
+            self.event.unsubscribe(id);
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use alloc::vec::Vec;
+use core::ops::Deref;
+#[cfg(feature = "std")]
+use std::borrow::Borrow;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+
+use crate::Event;
+
+/// A change made to an [`ObservableVec`], as dispatched by its [`changes`](ObservableVec::changes)
+/// event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VecChange<T> {
+    /// `value` was pushed, ending up at `index`.
+    Pushed { index: usize, value: T },
+    /// `value` was inserted at `index`, shifting the later elements up.
+    Inserted { index: usize, value: T },
+    /// `value` was removed from `index`, shifting the later elements down.
+    Removed { index: usize, value: T },
+    /// The element at `index` was replaced, from `old` to `new`.
+    Replaced { index: usize, old: T, new: T },
+    /// Every element was removed; these are the ones the vector held, in order.
+    Cleared(Vec<T>),
+}
+
+/// A `Vec` that dispatches an event for every change made to it, for building reactive
+/// views and state stores on top of [`Event`].
+///
+/// The mutating methods mirror `Vec`'s and each dispatches one [`VecChange`] to the
+/// [`changes`](Self::changes) event, after the change is made. Reading goes through the
+/// vector's slice, which the `ObservableVec` dereferences to; there is no mutable access
+/// that would bypass the event.
+///
+/// ```
+/// use blob_event::{ObservableVec, VecChange};
+///
+/// let mut todos = ObservableVec::new();
+/// todos.changes().subscribe(|change| match change {
+///     VecChange::Pushed { index, value } => println!("new row {}: {}", index, value),
+///     VecChange::Removed { index, .. } => println!("row {} deleted", index),
+///     _ => {}
+/// });
+///
+/// todos.push("water the plants");
+/// todos.push("buy milk");
+/// todos.remove(0);
+/// assert_eq!(todos[..], ["buy milk"]);
+/// ```
+pub struct ObservableVec<T> {
+    items: Vec<T>,
+    changes: Event<VecChange<T>>,
+}
+
+impl<T: Clone> ObservableVec<T> {
+    /// Creates an empty vector with a new change event.
+    pub fn new() -> Self {
+        return Self::from_vec(Vec::new());
+    }
+
+    /// Wraps `items` without dispatching anything for the elements it already holds.
+    pub fn from_vec(items: Vec<T>) -> Self {
+        return ObservableVec {
+            items,
+            changes: Event::new(),
+        };
+    }
+
+    /// Returns the event every change is dispatched to, for subscribing to it.
+    pub fn changes(&self) -> &Event<VecChange<T>> {
+        return &self.changes;
+    }
+
+    /// Appends `value` to the end of the vector.
+    pub fn push(&mut self, value: T) {
+        let index = self.items.len();
+        self.items.push(value.clone());
+        self.changes.invoke(VecChange::Pushed { index, value });
+    }
+
+    /// Inserts `value` at `index`, shifting the elements after it up.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is greater than the length.
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.items.insert(index, value.clone());
+        self.changes.invoke(VecChange::Inserted { index, value });
+    }
+
+    /// Removes and returns the element at `index`, shifting the elements after it down.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn remove(&mut self, index: usize) -> T {
+        let value = self.items.remove(index);
+        self.changes.invoke(VecChange::Removed {
+            index,
+            value: value.clone(),
+        });
+        return value;
+    }
+
+    /// Removes and returns the last element, or `None` if the vector is empty, dispatching
+    /// nothing in that case.
+    pub fn pop(&mut self) -> Option<T> {
+        let value = self.items.pop()?;
+        let index = self.items.len();
+        self.changes.invoke(VecChange::Removed {
+            index,
+            value: value.clone(),
+        });
+        return Some(value);
+    }
+
+    /// Replaces the element at `index` with `value`, returning the old one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn set(&mut self, index: usize, value: T) -> T {
+        let old = core::mem::replace(&mut self.items[index], value.clone());
+        self.changes.invoke(VecChange::Replaced {
+            index,
+            old: old.clone(),
+            new: value,
+        });
+        return old;
+    }
+
+    /// Removes every element. Clearing an empty vector dispatches nothing.
+    pub fn clear(&mut self) {
+        if self.items.is_empty() {
+            return;
+        }
+        let removed = core::mem::take(&mut self.items);
+        self.changes.invoke(VecChange::Cleared(removed));
+    }
+
+    /// Unwraps the vector, leaving the change event's subscribers behind.
+    pub fn into_vec(self) -> Vec<T> {
+        return self.items;
+    }
+}
+
+impl<T: Clone> Default for ObservableVec<T> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<T> Deref for ObservableVec<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        return &self.items;
+    }
+}
+
+impl<T: core::fmt::Debug> core::fmt::Debug for ObservableVec<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return f.debug_list().entries(&self.items).finish();
+    }
+}
+
+/// A change made to an [`ObservableMap`], as dispatched by its
+/// [`changes`](ObservableMap::changes) event.
+#[cfg(feature = "std")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MapChange<K, V> {
+    /// The key wasn't in the map and was inserted with the value.
+    Inserted(K, V),
+    /// The key was in the map, and its value was replaced, from `old` to `new`.
+    Updated { key: K, old: V, new: V },
+    /// The key was removed from the map, with the value it had.
+    Removed(K, V),
+}
+
+/// A `HashMap` that dispatches an event for every change made to it, the counterpart of
+/// [`ObservableVec`] for keyed state.
+///
+/// The mutating methods mirror `HashMap`'s and dispatch a [`MapChange`] to the
+/// [`changes`](Self::changes) event for every entry they insert, update, or remove, after
+/// the change is made. Reading goes through the map, which the `ObservableMap`
+/// dereferences to. Requires `std`.
+///
+/// ```
+/// use blob_event::{MapChange, ObservableMap};
+///
+/// let mut stock = ObservableMap::new();
+/// stock.changes().subscribe(|change| {
+///     if let MapChange::Updated { key, old, new } = change {
+///         println!("{} went from {} to {} in stock", key, old, new);
+///     }
+/// });
+///
+/// stock.insert("bolts", 40);
+/// stock.insert("bolts", 25);
+/// assert_eq!(stock.get("bolts"), Some(&25));
+/// ```
+#[cfg(feature = "std")]
+pub struct ObservableMap<K, V> {
+    entries: HashMap<K, V>,
+    changes: Event<MapChange<K, V>>,
+}
+
+#[cfg(feature = "std")]
+impl<K, V> ObservableMap<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    /// Creates an empty map with a new change event.
+    pub fn new() -> Self {
+        return Self::from_map(HashMap::new());
+    }
+
+    /// Wraps `entries` without dispatching anything for the entries it already holds.
+    pub fn from_map(entries: HashMap<K, V>) -> Self {
+        return ObservableMap {
+            entries,
+            changes: Event::new(),
+        };
+    }
+
+    /// Returns the event every change is dispatched to, for subscribing to it.
+    pub fn changes(&self) -> &Event<MapChange<K, V>> {
+        return &self.changes;
+    }
+
+    /// Inserts `value` under `key`, returning the value it replaced, if any.
+    ///
+    /// Dispatches [`MapChange::Updated`] if the key was already in the map, and
+    /// [`MapChange::Inserted`] otherwise.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let old = self.entries.insert(key.clone(), value.clone());
+        let change = match &old {
+            Some(old) => MapChange::Updated {
+                key,
+                old: old.clone(),
+                new: value,
+            },
+            None => MapChange::Inserted(key, value),
+        };
+        self.changes.invoke(change);
+        return old;
+    }
+
+    /// Removes `key` from the map, returning its value if it was there. Removing a key that
+    /// isn't in the map dispatches nothing.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let (key, value) = self.entries.remove_entry(key)?;
+        self.changes.invoke(MapChange::Removed(key, value.clone()));
+        return Some(value);
+    }
+
+    /// Keeps only the entries for which `keep` returns `true`, dispatching
+    /// [`MapChange::Removed`] for each of the others.
+    pub fn retain(&mut self, mut keep: impl FnMut(&K, &V) -> bool) {
+        let removed: Vec<K> = self
+            .entries
+            .iter()
+            .filter(|(key, value)| !keep(key, value))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in removed {
+            self.remove(&key);
+        }
+    }
+
+    /// Removes every entry, dispatching [`MapChange::Removed`] for each of them.
+    pub fn clear(&mut self) {
+        for (key, value) in self.entries.drain().collect::<Vec<_>>() {
+            self.changes.invoke(MapChange::Removed(key, value));
+        }
+    }
+
+    /// Unwraps the map, leaving the change event's subscribers behind.
+    pub fn into_map(self) -> HashMap<K, V> {
+        return self.entries;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V> Default for ObservableMap<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K, V> Deref for ObservableMap<K, V> {
+    type Target = HashMap<K, V>;
+
+    fn deref(&self) -> &HashMap<K, V> {
+        return &self.entries;
+    }
+}
+
+#[cfg(feature = "std")]
+impl<K: core::fmt::Debug, V: core::fmt::Debug> core::fmt::Debug for ObservableMap<K, V> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return f.debug_map().entries(&self.entries).finish();
+    }
+}
@@ -0,0 +1,126 @@
+use alloc::boxed::Box;
+use core::any::Any;
+use core::marker::PhantomData;
+use core::sync::atomic::Ordering;
+
+use crate::{Borrowed, ByClone, Cloned, Event, EventHandler, Subscription};
+
+// Adds an error callback to a handler, for errors of type `E`.
+struct OnError<H, G, E> {
+    handler: H,
+    on_error: G,
+    error: PhantomData<fn(&E)>,
+}
+
+impl<H, G, E, Args> EventHandler<Args> for OnError<H, G, E>
+where
+    H: EventHandler<Args>,
+    G: FnMut(&E) + Send,
+    E: Any,
+    Args: ?Sized,
+{
+    fn call(&mut self, args: &Args) {
+        self.handler.call(args);
+    }
+
+    fn error(&mut self, error: &dyn Any) -> bool {
+        let Some(error) = error.downcast_ref::<E>() else {
+            return false;
+        };
+        (self.on_error)(error);
+        return true;
+    }
+
+    fn close(&mut self) {
+        self.handler.close();
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    /// Reports a failure of the event's source to the handlers subscribed with
+    /// `subscribe_with_error` for errors of type `E`, in dispatch order.
+    ///
+    /// This gives a producer a way to report that it failed without a second event to manage
+    /// alongside this one. Errors don't go through interceptors, hooks, or mirrors, and other
+    /// handlers never see them. Emitting an error on a closed event does nothing.
+    ///
+    /// Like an invoke, this waits for each error handler's running call to return before
+    /// calling it. A handler may emit an error on its own event: with `std`, it is skipped
+    /// rather than called from inside itself. Without `std` the running handler can't be
+    /// told apart from one running on another thread, so the emit waits for it forever.
+    ///
+    /// Returns how many handlers received the error, so a caller can tell when no one did.
+    pub fn emit_error<E: Any>(&self, error: E) -> usize {
+        let snapshot = self.inner.snapshot.load();
+        if snapshot.closed {
+            return 0;
+        }
+        let mut received = 0;
+        for cell in snapshot.cells.iter() {
+            if !cell.active.load(Ordering::Acquire) {
+                continue;
+            }
+            // A handler emitting an error on its own event would otherwise wait on itself.
+            #[cfg(feature = "std")]
+            if cell.running_on.load(Ordering::Relaxed) == crate::recursion::thread_token() {
+                continue;
+            }
+            if cell.with_handler(|handler| handler.error(&error)) {
+                received += 1;
+            }
+        }
+        return received;
+    }
+}
+
+impl<Args: Clone> Event<Args, Cloned> {
+    /// Subscribes a callback like [`subscribe`](Self::subscribe), along with `on_error`,
+    /// which receives a clone of every error of type `E` passed to
+    /// [`emit_error`](Self::emit_error).
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// #[derive(Debug, Clone)]
+    /// struct Disconnected;
+    ///
+    /// let messages = Event::<String>::new();
+    /// messages.subscribe_with_error(
+    ///     |message| println!("received {}", message),
+    ///     |error: Disconnected| println!("connection lost: {:?}", error),
+    /// );
+    /// assert_eq!(messages.emit_error(Disconnected), 1);
+    /// ```
+    pub fn subscribe_with_error<F, G, E>(&self, handler: F, mut on_error: G) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static,
+        G: FnMut(E) + Send + 'static,
+        E: Any + Clone,
+    {
+        let handler = OnError {
+            handler: ByClone(handler),
+            on_error: move |error: &E| on_error(error.clone()),
+            error: PhantomData,
+        };
+        return self.subscribe_handler(0, Box::new(handler));
+    }
+}
+
+impl<Args: ?Sized> Event<Args, Borrowed> {
+    /// Subscribes a callback like [`subscribe`](Self::subscribe), along with `on_error`,
+    /// which receives a reference to every error of type `E` passed to
+    /// [`emit_error`](Self::emit_error).
+    pub fn subscribe_with_error<F, G, E>(&self, handler: F, on_error: G) -> Subscription
+    where
+        F: FnMut(&Args) + Send + 'static,
+        G: FnMut(&E) + Send + 'static,
+        E: Any,
+    {
+        let handler = OnError {
+            handler,
+            on_error,
+            error: PhantomData,
+        };
+        return self.subscribe_handler(0, Box::new(handler));
+    }
+}
@@ -0,0 +1,290 @@
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::sync::Arc;
+#[cfg(feature = "std")]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::sync::OnceLock;
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+#[cfg(feature = "std")]
+use crate::BatchConfig;
+use crate::Event;
+#[cfg(feature = "std")]
+use crate::EventError;
+use crate::sync::{Lock, Mutex};
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    // Keeps `source` alive for as long as this event is.
+    fn derive_from<S: Send + 'static>(&self, source: &Event<S>) {
+        self.inner.handlers.acquire().sources.push(Box::new(source.clone()));
+    }
+}
+
+// Operators build derived events by subscribing forwarding handlers to their sources. The
+// handlers only hold the derived event weakly, and the derived event keeps its sources alive
+// in turn, so dropping the last handle to it unsubscribes them on their next invocation.
+impl<Args> Event<Args>
+where
+    Args: Clone + Send + 'static,
+{
+    // Subscribes `forward` to this event, calling it with `target` for as long as a handle to
+    // `target` exists. Once none does, the next invocation unsubscribes it.
+    fn forward_to<T, F>(&self, target: &Event<T>, mut forward: F)
+    where
+        T: Send + 'static,
+        F: FnMut(&Event<T>, Args) + Send + 'static,
+    {
+        target.derive_from(self);
+        let target = target.downgrade();
+        let source = self.downgrade();
+        self.subscribe_with_id(move |id| {
+            move |args| match target.upgrade() {
+                Some(target) => forward(&target, args),
+                None => {
+                    if let Some(source) = source.upgrade() {
+                        source.unsubscribe(id);
+                    }
+                }
+            }
+        });
+    }
+
+    /// Creates an event that is invoked whenever any of the `sources` is invoked.
+    ///
+    /// The merged event receives the arguments of whichever source fired. Sources stop
+    /// forwarding to it once every handle to the merged event has been dropped.
+    pub fn merge(sources: &[&Event<Args>]) -> Event<Args> {
+        let merged = Event::new();
+        for source in sources {
+            source.forward_to(&merged, |target, args| target.invoke(args));
+        }
+        return merged;
+    }
+
+    /// Creates an event that forwards this event's invocations, skipping any whose arguments
+    /// are equal to the previously forwarded ones.
+    pub fn distinct(&self) -> Event<Args>
+    where
+        Args: PartialEq,
+    {
+        let distinct = Event::new();
+        let mut last: Option<Args> = None;
+        self.forward_to(&distinct, move |target, args| {
+            if last.as_ref() != Some(&args) {
+                last = Some(args.clone());
+                target.invoke(args);
+            }
+        });
+        return distinct;
+    }
+
+    /// Creates an event that emits a running accumulation of this event's invocations.
+    ///
+    /// Starting from `initial`, every invocation computes the next state with
+    /// `accumulate(&state, args)` and emits it. The state lives inside the operator and is
+    /// updated by one invocation at a time, even when the source is invoked from several
+    /// threads.
+    pub fn scan<State, F>(&self, initial: State, mut accumulate: F) -> Event<State>
+    where
+        State: Clone + Send + 'static,
+        F: FnMut(&State, Args) -> State + Send + 'static,
+    {
+        let scanned = Event::new();
+        let mut state = initial;
+        self.forward_to(&scanned, move |target, args| {
+            state = accumulate(&state, args);
+            target.invoke(state.clone());
+        });
+        return scanned;
+    }
+
+    /// Creates an event that forwards only the first `n` invocations of this event.
+    ///
+    /// After the nth invocation the operator unsubscribes itself from this event, so it
+    /// doesn't linger in the subscription list.
+    pub fn take(&self, n: usize) -> Event<Args> {
+        let taken = Event::new();
+        if n == 0 {
+            return taken;
+        }
+
+        taken.derive_from(self);
+        let target = taken.downgrade();
+        let source = self.downgrade();
+        let mut remaining = n;
+        self.subscribe_with_id(move |id| {
+            move |args| {
+                // A dispatch that snapshotted the handler before it unsubscribed may still
+                // reach it, so the count is checked rather than assumed.
+                if remaining == 0 {
+                    return;
+                }
+                // Stop early if every handle to the taken event was dropped.
+                let target = target.upgrade();
+                remaining = if target.is_some() { remaining - 1 } else { 0 };
+                if remaining == 0
+                    && let Some(source) = source.upgrade()
+                {
+                    source.unsubscribe(id);
+                }
+                if let Some(target) = target {
+                    target.invoke(args);
+                }
+            }
+        });
+        return taken;
+    }
+
+    /// Creates an event that drops the first `n` invocations of this event and forwards
+    /// every one after that.
+    pub fn skip(&self, n: usize) -> Event<Args> {
+        let skipped = Event::new();
+        let mut remaining = n;
+        self.forward_to(&skipped, move |target, args| {
+            if remaining > 0 {
+                remaining -= 1;
+            } else {
+                target.invoke(args);
+            }
+        });
+        return skipped;
+    }
+
+    /// Creates an event that emits this event's arguments in batches of `count`.
+    ///
+    /// A trailing partial batch is held until enough invocations arrive to complete it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `count` is zero.
+    #[cfg(feature = "std")]
+    pub fn buffered(&self, count: usize) -> Event<Vec<Args>> {
+        return self.buffered_with(BatchConfig::new(count));
+    }
+
+    /// Creates an event that emits everything this event received within a time window.
+    ///
+    /// A window opens with the first invocation after the previous batch was emitted and
+    /// closes `window` later, so no empty batches are emitted while the source is idle.
+    /// Batches are emitted from the crate's shared timer thread.
+    #[cfg(feature = "std")]
+    pub fn buffered_by_time(&self, window: Duration) -> Event<Vec<Args>> {
+        return self.buffered_with(BatchConfig::by_time(window));
+    }
+
+    #[cfg(feature = "std")]
+    fn buffered_with(&self, config: BatchConfig) -> Event<Vec<Args>> {
+        let buffered = Event::new();
+        buffered.derive_from(self);
+        let target = buffered.downgrade();
+        let source = self.downgrade();
+        // The batching handler is only given its subscription once it is subscribed, which
+        // is before any batch can be emitted.
+        let own = Arc::new(OnceLock::new());
+        let id = Arc::clone(&own);
+        let subscribed = self.subscribe_batched(config, move |batch| match target.upgrade() {
+            Some(target) => target.invoke(batch),
+            None => {
+                if let (Some(source), Some(id)) = (source.upgrade(), id.get()) {
+                    source.unsubscribe(*id);
+                }
+            }
+        });
+        match subscribed {
+            Ok(id) => {
+                let _ = own.set(id);
+            }
+            Err(error @ EventError::InvalidBatchSize) => panic!("{}", error),
+            // A closed or full source leaves the buffered event with nothing to emit.
+            Err(_) => {}
+        }
+        return buffered;
+    }
+}
+
+impl<A, B> Event<(A, B)>
+where
+    A: Clone + Send + 'static,
+    B: Clone + Send + 'static,
+{
+    /// Creates an event that fires with the latest value of both sources whenever either of
+    /// them fires.
+    ///
+    /// Nothing is emitted until each source has fired at least once.
+    pub fn combine_latest(a: &Event<A>, b: &Event<B>) -> Event<(A, B)> {
+        let combined = Event::new();
+        let latest = Arc::new(Mutex::new((None::<A>, None::<B>)));
+
+        let latest_a = Arc::clone(&latest);
+        a.forward_to(&combined, move |target, value| {
+            // Build the pair under the lock but emit it after releasing it, so handlers of
+            // the combined event can safely invoke the sources again.
+            let pair = {
+                let mut latest = latest_a.acquire();
+                latest.0 = Some(value);
+                latest.0.clone().zip(latest.1.clone())
+            };
+            if let Some(pair) = pair {
+                target.invoke(pair);
+            }
+        });
+
+        b.forward_to(&combined, move |target, value| {
+            let pair = {
+                let mut latest = latest.acquire();
+                latest.1 = Some(value);
+                latest.0.clone().zip(latest.1.clone())
+            };
+            if let Some(pair) = pair {
+                target.invoke(pair);
+            }
+        });
+
+        return combined;
+    }
+
+    /// Creates an event that pairs the nth invocation of `a` with the nth invocation of `b`.
+    ///
+    /// Values from the source that fires ahead are queued until the other source catches up.
+    pub fn zip(a: &Event<A>, b: &Event<B>) -> Event<(A, B)> {
+        let zipped = Event::new();
+        let pending = Arc::new(Mutex::new((VecDeque::<A>::new(), VecDeque::<B>::new())));
+
+        let pending_a = Arc::clone(&pending);
+        a.forward_to(&zipped, move |target, value| {
+            let pair = {
+                let mut pending = pending_a.acquire();
+                match pending.1.pop_front() {
+                    Some(other) => Some((value, other)),
+                    None => {
+                        pending.0.push_back(value);
+                        None
+                    }
+                }
+            };
+            if let Some(pair) = pair {
+                target.invoke(pair);
+            }
+        });
+
+        b.forward_to(&zipped, move |target, value| {
+            let pair = {
+                let mut pending = pending.acquire();
+                match pending.0.pop_front() {
+                    Some(other) => Some((other, value)),
+                    None => {
+                        pending.1.push_back(value);
+                        None
+                    }
+                }
+            };
+            if let Some(pair) = pair {
+                target.invoke(pair);
+            }
+        });
+
+        return zipped;
+    }
+}
@@ -0,0 +1,16 @@
+/// The order in which an event calls its handlers.
+///
+/// Chosen per event with [`EventBuilder::dispatch_order`](crate::EventBuilder::dispatch_order).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum DispatchOrder {
+    /// Handlers run in the order they subscribed.
+    #[default]
+    Insertion,
+    /// The most recently subscribed handler runs first, as in GUI event capture where the
+    /// last registered handler wins.
+    Reverse,
+    /// Handlers with a higher priority run first, and handlers with the same priority run in
+    /// the order they subscribed. Handlers subscribed without a priority have priority 0.
+    Priority,
+}
@@ -0,0 +1,116 @@
+//! Process signals as events, available with the `signals` feature on Unix.
+//!
+//! [`sigint`] and its siblings return an `Event<()>` that is invoked every time the process
+//! receives the signal, so shutdown and reload requests can flow through the same events as
+//! the rest of an application:
+//!
+//! ```no_run
+//! use blob_event::os_signals;
+//!
+//! os_signals::sigint().subscribe(|()| println!("shutting down"));
+//! ```
+//!
+//! The signal is registered the first time its event is asked for, and from then on the
+//! default action no longer runs: a process that listens for `SIGINT` isn't stopped by
+//! Ctrl-C anymore unless a handler exits it. Signal handlers themselves can't do much
+//! safely, so they only record the signal, and a background thread started on first use
+//! invokes the events. Asking for a signal again returns the same event.
+
+use std::collections::HashMap;
+use std::io;
+use std::os::raw::c_int;
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+
+use signal_hook::consts::signal::{SIGHUP, SIGINT, SIGQUIT, SIGTERM, SIGUSR1, SIGUSR2};
+use signal_hook::iterator::{Handle, Signals};
+
+use crate::Event;
+
+// The signals registered so far, and the thread that invokes their events.
+struct Bridge {
+    handle: Handle,
+    events: Mutex<HashMap<c_int, Event<()>>>,
+}
+
+fn bridge() -> &'static Bridge {
+    static BRIDGE: OnceLock<Bridge> = OnceLock::new();
+    return BRIDGE.get_or_init(|| {
+        let mut signals = Signals::new::<[c_int; 0], c_int>([]).expect("failed to set up signal handling");
+        let handle = signals.handle();
+        thread::Builder::new()
+            .name("blob-event-signals".to_string())
+            .spawn(move || {
+                for signal in signals.forever() {
+                    let event = bridge().events.lock().unwrap().get(&signal).cloned();
+                    // A panicking handler must not end the thread, which would leave the signal
+                    // registered with nothing to act on it.
+                    if let Some(event) = event {
+                        let _ = event.try_invoke(());
+                    }
+                }
+            })
+            .expect("failed to spawn the blob-event signal thread");
+        Bridge {
+            handle,
+            events: Mutex::new(HashMap::new()),
+        }
+    });
+}
+
+/// Returns the event invoked whenever the process receives `signal`, registering the signal
+/// on first use.
+///
+/// Fails for signals that can't be caught, such as `SIGKILL`, and for those that are unsafe
+/// to handle this way, such as `SIGSEGV`.
+pub fn signal(signal: c_int) -> io::Result<Event<()>> {
+    if signal_hook::consts::FORBIDDEN.contains(&signal) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("signal {} can't be handled", signal),
+        ));
+    }
+    let bridge = bridge();
+    let mut events = bridge.events.lock().unwrap();
+    if let Some(event) = events.get(&signal) {
+        return Ok(event.clone());
+    }
+    bridge.handle.add_signal(signal)?;
+    let event = Event::new();
+    events.insert(signal, event.clone());
+    return Ok(event);
+}
+
+fn catchable(signum: c_int) -> Event<()> {
+    return signal(signum).expect("registering a catchable signal can't fail");
+}
+
+/// Returns the event invoked on `SIGINT`, which Ctrl-C sends.
+pub fn sigint() -> Event<()> {
+    return catchable(SIGINT);
+}
+
+/// Returns the event invoked on `SIGTERM`, the polite request to terminate.
+pub fn sigterm() -> Event<()> {
+    return catchable(SIGTERM);
+}
+
+/// Returns the event invoked on `SIGHUP`, conventionally a request to reload configuration.
+pub fn sighup() -> Event<()> {
+    return catchable(SIGHUP);
+}
+
+/// Returns the event invoked on `SIGQUIT`.
+pub fn sigquit() -> Event<()> {
+    return catchable(SIGQUIT);
+}
+
+/// Returns the event invoked on `SIGUSR1`.
+pub fn sigusr1() -> Event<()> {
+    return catchable(SIGUSR1);
+}
+
+/// Returns the event invoked on `SIGUSR2`.
+pub fn sigusr2() -> Event<()> {
+    return catchable(SIGUSR2);
+}
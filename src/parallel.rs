@@ -0,0 +1,108 @@
+use core::convert::Infallible;
+
+use rayon::prelude::*;
+
+use crate::{Borrowed, Cloned, DispatchReport, Event, HandlerControl, Snapshot};
+
+impl<Args: ?Sized + Sync, D: Sync> Event<Args, D> {
+    // Calls every subscribed handler with a reference to `args`, spreading the handlers over
+    // the rayon pool. Each handler still runs under its own lock, so a handler is never
+    // called concurrently with itself, only alongside the others.
+    fn dispatch_parallel(&self, args: &Args) {
+        yield_point!(BeforeSnapshot);
+        let snapshot = self.inner.snapshot.load();
+        if snapshot.closed {
+            return;
+        }
+        let Ok(_nested) = crate::recursion::enter(&snapshot, |hooks| hooks.on_recursion_overflow(args)) else {
+            return;
+        };
+        let Some(hooks) = &snapshot.hooks else {
+            self.call_handlers_parallel(&snapshot, args);
+            return;
+        };
+        hooks.on_invoke_start(args);
+        let report = self.call_handlers_parallel(&snapshot, args);
+        hooks.on_invoke_end(Ok(&report));
+    }
+
+    // The body of `dispatch_parallel`, without the hooks.
+    fn call_handlers_parallel(&self, snapshot: &Snapshot<Args>, args: &Args) -> DispatchReport {
+        self.inner.counters.record_invocations(1);
+        // Errors are only returned when catching panics.
+        let Ok(intercepted) = self.intercept(snapshot, args, false) else {
+            return DispatchReport::default();
+        };
+        let Some(args) = intercepted.args(args) else {
+            return DispatchReport {
+                vetoed: true,
+                ..DispatchReport::default()
+            };
+        };
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!("invoke_parallel", handlers = snapshot.cells.len());
+        let handlers_called = snapshot
+            .cells
+            .par_iter()
+            .map(|cell| {
+                if snapshot.skips(cell) {
+                    return 0;
+                }
+                let _timing = self.time(cell);
+                // Handlers run on the pool's threads, so their spans are parented explicitly.
+                #[cfg(feature = "tracing")]
+                let _span =
+                    tracing::trace_span!(parent: &span, "handler", subscription = ?cell.id, name = cell.name).entered();
+                if cell.call(args) == HandlerControl::Unsubscribe {
+                    self.unsubscribe(cell.id);
+                }
+                return 1;
+            })
+            .sum();
+        let _ = self.dispatch_mirrors::<Infallible>(&snapshot.mirrors, |mirror| {
+            mirror.dispatch_parallel(args);
+            Ok(())
+        });
+        return DispatchReport {
+            handlers_called,
+            handlers_skipped: snapshot.cells.len() - handlers_called,
+            ..DispatchReport::default()
+        };
+    }
+}
+
+impl<Args: Clone + Sync> Event<Args, Cloned> {
+    /// Triggers the event like [`invoke`](Self::invoke), but calls the handlers concurrently on
+    /// the rayon thread pool, returning once all of them have finished.
+    ///
+    /// Handlers run in no particular order. Since they have all started by the time one of
+    /// them returns, a handler returning [`HandlerControl::StopPropagation`] doesn't stop the
+    /// others; [`HandlerControl::Unsubscribe`] is honored. If a handler panics, the panic is
+    /// propagated once the other handlers have finished.
+    ///
+    /// Handlers don't need to be `Fn` or `Sync`: each one runs under the same lock as with
+    /// `invoke`, so it is never run concurrently with itself, only alongside the others. A
+    /// handler taking long to run therefore holds up a concurrent `invoke_parallel`, which
+    /// waits for it instead of calling it a second time.
+    pub fn invoke_parallel(&self, args: Args) {
+        self.dispatch_parallel(&args);
+    }
+}
+
+impl<Args: ?Sized + Sync> Event<Args, Borrowed> {
+    /// Triggers the event like [`invoke`](Self::invoke), but calls the handlers concurrently on
+    /// the rayon thread pool, returning once all of them have finished.
+    ///
+    /// Handlers run in no particular order. Since they have all started by the time one of
+    /// them returns, a handler returning [`HandlerControl::StopPropagation`] doesn't stop the
+    /// others; [`HandlerControl::Unsubscribe`] is honored. If a handler panics, the panic is
+    /// propagated once the other handlers have finished.
+    ///
+    /// Handlers don't need to be `Fn` or `Sync`: each one runs under the same lock as with
+    /// `invoke`, so it is never run concurrently with itself, only alongside the others. A
+    /// handler taking long to run therefore holds up a concurrent `invoke_parallel`, which
+    /// waits for it instead of calling it a second time.
+    pub fn invoke_parallel(&self, args: &Args) {
+        self.dispatch_parallel(args);
+    }
+}
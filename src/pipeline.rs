@@ -0,0 +1,83 @@
+use alloc::boxed::Box;
+
+use crate::{Event, EventHandler, HandlerControl, Payload, Subscription};
+
+// Adapts a closure that changes the payload in place.
+struct Mutating<F>(F);
+
+impl<F, Args> EventHandler<Args> for Mutating<F>
+where
+    F: FnMut(&mut Args) + Send,
+    Args: Clone,
+{
+    // A plain invoke can't hand out the caller's payload mutably, so the handler changes a
+    // copy that is thrown away.
+    fn call(&mut self, args: &Args) {
+        (self.0)(&mut args.clone());
+    }
+
+    fn call_mut(&mut self, args: &mut Args) -> HandlerControl {
+        (self.0)(args);
+        return HandlerControl::Continue;
+    }
+}
+
+// The payload of `invoke_mut`, lent mutably to the handlers in turn.
+struct MutPayload<'a, Args>(&'a mut Args);
+
+impl<Args> Payload<Args> for MutPayload<'_, Args> {
+    fn args(&self) -> &Args {
+        return self.0;
+    }
+
+    fn replace(&mut self, args: Box<Args>) {
+        *self.0 = *args;
+    }
+
+    fn call(&mut self, handler: &mut dyn EventHandler<Args>) -> HandlerControl {
+        return handler.call_mut(self.0);
+    }
+}
+
+impl<Args: Clone + 'static, D> Event<Args, D> {
+    /// Subscribes a callback that may change the payload of [`invoke_mut`](Self::invoke_mut),
+    /// for the handlers after it to see.
+    ///
+    /// The other invokes can't lend out the payload mutably, so on those the callback gets
+    /// its own copy, and what it changes there is thrown away.
+    pub fn subscribe_mut<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(&mut Args) + Send + 'static,
+    {
+        return self.subscribe_handler(0, Box::new(Mutating(handler)));
+    }
+
+    /// Triggers the event with a payload the handlers pass along like a pipeline: each
+    /// handler subscribed with [`subscribe_mut`](Self::subscribe_mut) changes `args` in
+    /// place, in dispatch order, and every handler sees the changes of those before it.
+    /// Once they have all run, `args` holds the result.
+    ///
+    /// Interceptors run first as usual, and a replacement they make is written to `args`.
+    /// The event's mirrors receive the payload as the last handler left it. The handlers
+    /// always run on the calling thread, even on a queued event, since they need to borrow
+    /// `args`.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// let render = Event::<Vec<String>>::new();
+    /// render.subscribe_mut(|lines| lines.push("<header>".to_string()));
+    /// render.subscribe_mut(|lines| lines.retain(|line| !line.contains("draft")));
+    /// render.subscribe_mut(|lines| lines.push("<footer>".to_string()));
+    ///
+    /// let mut page = vec!["intro".to_string(), "draft notes".to_string()];
+    /// render.invoke_mut(&mut page);
+    /// assert_eq!(page, ["intro", "<header>", "<footer>"]);
+    /// ```
+    pub fn invoke_mut(&self, args: &mut Args) {
+        yield_point!(BeforeSnapshot);
+        let snapshot = self.inner.snapshot.load();
+        // Without catching panics the dispatch can't fail; a panic unwinds into the caller.
+        let _ = self.dispatch_payload(&snapshot, &mut MutPayload(args), false, |_| ());
+    }
+}
@@ -0,0 +1,67 @@
+use std::collections::VecDeque;
+use std::num::NonZeroUsize;
+use std::sync::{Condvar, Mutex, OnceLock};
+use std::thread;
+
+type Task = Box<dyn FnOnce() + Send>;
+
+// A fixed set of worker threads shared by every feature of the crate that runs handlers off
+// the caller's thread. Tasks are started in the order they were submitted.
+struct Pool {
+    queue: Mutex<VecDeque<Task>>,
+    available: Condvar,
+}
+
+// Upper bound on the number of workers, so machines with many cores don't get a thread per
+// core just for dispatching events.
+const MAX_WORKERS: usize = 4;
+
+fn pool() -> &'static Pool {
+    static POOL: OnceLock<&'static Pool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        let pool: &'static Pool = Box::leak(Box::new(Pool {
+            queue: Mutex::new(VecDeque::new()),
+            available: Condvar::new(),
+        }));
+        let workers = thread::available_parallelism()
+            .map(NonZeroUsize::get)
+            .unwrap_or(1)
+            .min(MAX_WORKERS);
+        for index in 0..workers {
+            thread::Builder::new()
+                .name(format!("blob-event-worker-{}", index))
+                .spawn(move || pool.run())
+                .expect("failed to spawn a blob-event worker thread");
+        }
+        pool
+    })
+}
+
+/// Runs `task` on one of the shared worker threads.
+///
+/// The task must not panic; a panic would take its worker down with it.
+pub(crate) fn spawn<F>(task: F)
+where
+    F: FnOnce() + Send + 'static,
+{
+    let pool = pool();
+    pool.queue.lock().unwrap().push_back(Box::new(task));
+    pool.available.notify_one();
+}
+
+impl Pool {
+    fn run(&self) {
+        loop {
+            let task = {
+                let mut queue = self.queue.lock().unwrap();
+                loop {
+                    match queue.pop_front() {
+                        Some(task) => break task,
+                        None => queue = self.available.wait(queue).unwrap(),
+                    }
+                }
+            };
+            task();
+        }
+    }
+}
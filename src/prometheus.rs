@@ -0,0 +1,164 @@
+//! Prometheus metrics, available with the `prometheus` feature.
+//!
+//! [`Event::export_metrics`] registers three metrics for an event with a
+//! [`prometheus::Registry`] and keeps them up to date:
+//!
+//! - a counter of invocations,
+//! - a gauge of the current number of subscribers,
+//! - a histogram of how long handler calls take, in seconds.
+//!
+//! Their names are chosen with [`MetricNames`]. Events exported to the same registry need
+//! either distinct names or distinct constant labels:
+//!
+//! ```
+//! use blob_event::Event;
+//! use blob_event::prometheus::MetricNames;
+//!
+//! let registry = prometheus::Registry::new();
+//! let clicks = Event::<u32>::new();
+//! let keys = Event::<char>::new();
+//!
+//! clicks.export_metrics(&registry, &MetricNames::with_prefix("ui").label("event", "clicks")).unwrap();
+//! keys.export_metrics(&registry, &MetricNames::with_prefix("ui").label("event", "keys")).unwrap();
+//!
+//! clicks.subscribe(|_| {});
+//! clicks.invoke(1);
+//! assert_eq!(registry.gather().len(), 3);
+//! ```
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use ::prometheus::core::Collector;
+use ::prometheus::{Error, Histogram, HistogramOpts, IntCounter, IntGauge, Opts, Registry};
+
+use crate::Event;
+
+/// The names and constant labels of the metrics exported for an event.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct MetricNames {
+    /// The name of the invocation counter.
+    pub invocations: String,
+    /// The name of the subscriber gauge.
+    pub subscribers: String,
+    /// The name of the handler duration histogram.
+    pub handler_duration: String,
+    /// Labels attached to all three metrics.
+    pub labels: HashMap<String, String>,
+}
+
+impl MetricNames {
+    /// Names the metrics `{prefix}_invocations_total`, `{prefix}_subscribers`, and
+    /// `{prefix}_handler_duration_seconds`.
+    pub fn with_prefix(prefix: &str) -> Self {
+        MetricNames {
+            invocations: format!("{}_invocations_total", prefix),
+            subscribers: format!("{}_subscribers", prefix),
+            handler_duration: format!("{}_handler_duration_seconds", prefix),
+            labels: HashMap::new(),
+        }
+    }
+
+    /// Adds a constant label to all three metrics, for telling events apart when they share
+    /// metric names.
+    pub fn label(mut self, name: &str, value: &str) -> Self {
+        self.labels.insert(name.to_string(), value.to_string());
+        self
+    }
+}
+
+impl Default for MetricNames {
+    /// Uses the prefix `blob_event`.
+    fn default() -> Self {
+        Self::with_prefix("blob_event")
+    }
+}
+
+// The metrics an event updates once it has been exported.
+pub(crate) struct EventMetrics {
+    invocations: IntCounter,
+    subscribers: IntGauge,
+    handler_duration: Histogram,
+}
+
+impl EventMetrics {
+    fn new(names: &MetricNames) -> Result<Self, Error> {
+        let labels = names.labels.clone();
+        let help = "Invocations of the event";
+        let invocations = IntCounter::with_opts(Opts::new(&names.invocations, help).const_labels(labels.clone()))?;
+        let help = "Current number of subscribers of the event";
+        let subscribers = IntGauge::with_opts(Opts::new(&names.subscribers, help).const_labels(labels.clone()))?;
+        let help = "Time taken by calls to the event's handlers";
+        let handler_duration =
+            Histogram::with_opts(HistogramOpts::new(&names.handler_duration, help).const_labels(labels))?;
+        Ok(EventMetrics {
+            invocations,
+            subscribers,
+            handler_duration,
+        })
+    }
+
+    fn collectors(&self) -> [Box<dyn Collector>; 3] {
+        return [
+            Box::new(self.invocations.clone()),
+            Box::new(self.subscribers.clone()),
+            Box::new(self.handler_duration.clone()),
+        ];
+    }
+
+    // Registers all three metrics with `registry`, or none of them.
+    fn register(&self, registry: &Registry) -> Result<(), Error> {
+        for (registered, collector) in self.collectors().into_iter().enumerate() {
+            if let Err(error) = registry.register(collector) {
+                self.unregister(registry, registered);
+                return Err(error);
+            }
+        }
+        return Ok(());
+    }
+
+    // Unregisters the first `count` metrics, which must have been registered by `register`:
+    // the registry matches metrics by name and labels, so it would otherwise remove
+    // someone else's.
+    fn unregister(&self, registry: &Registry, count: usize) {
+        for collector in self.collectors().into_iter().take(count) {
+            let _ = registry.unregister(collector);
+        }
+    }
+
+    pub(crate) fn record_invocations(&self, count: usize) {
+        self.invocations.inc_by(count as u64);
+    }
+
+    pub(crate) fn record_subscribers(&self, count: usize) {
+        self.subscribers.set(count as i64);
+    }
+
+    pub(crate) fn record_call(&self, elapsed: Duration) {
+        self.handler_duration.observe(elapsed.as_secs_f64());
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    /// Registers metrics for this event with `registry`, named after `names`, and keeps them
+    /// up to date from then on. See the [`prometheus`](crate::prometheus) module.
+    ///
+    /// Returns an error if the names are invalid, if the registry already has metrics with
+    /// the same names and labels, or if the event has already been exported. A failed export
+    /// leaves none of its metrics registered.
+    pub fn export_metrics(&self, registry: &Registry, names: &MetricNames) -> Result<(), Error> {
+        let metrics = EventMetrics::new(names)?;
+        if self.inner.counters.metrics.get().is_some() {
+            return Err(Error::Msg("event metrics are already exported".to_string()));
+        }
+        metrics.register(registry)?;
+        metrics.record_subscribers(self.subscriber_count());
+        // Another export may have won the race since the check above.
+        if let Err(metrics) = self.inner.counters.metrics.set(metrics) {
+            metrics.unregister(registry, 3);
+            return Err(Error::Msg("event metrics are already exported".to_string()));
+        }
+        return Ok(());
+    }
+}
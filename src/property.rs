@@ -0,0 +1,171 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+
+use crate::Event;
+#[cfg(feature = "std")]
+use crate::RecursionPolicy;
+use crate::sync::{Lock, Mutex};
+
+/// A value that dispatches an event whenever it changes, the building block for
+/// reactive state such as view models and settings.
+///
+/// [`set`](Self::set) stores the new value and, if it differs from the old one, dispatches
+/// `(old, new)` to the [`changed`](Self::changed) event. Setting a value equal to the
+/// current one dispatches nothing; a property created with
+/// [`with_equality`](Self::with_equality) decides what counts as equal, for example to
+/// ignore changes within a tolerance. Cloning a `Property` returns another handle to the same
+/// value and event.
+///
+/// A handler can't set the property whose change it is handling, directly or through other
+/// properties: with `std`, the change event has a [recursion
+/// limit](crate::EventBuilder::max_recursion_depth) of 1, so such a cycle panics instead
+/// of waiting forever for the running handler.
+///
+/// ```
+/// use blob_event::Property;
+///
+/// let volume = Property::new(50);
+/// volume.changed().subscribe(|(old, new)| println!("volume {} -> {}", old, new));
+///
+/// volume.set(70); // Prints "volume 50 -> 70"
+/// volume.set(70); // Unchanged, prints nothing
+/// assert_eq!(volume.get(), 70);
+/// ```
+pub struct Property<T> {
+    pub(crate) inner: Arc<PropertyInner<T>>,
+}
+
+pub(crate) struct PropertyInner<T> {
+    value: Mutex<T>,
+    equals: Equality<T>,
+    pub(crate) changed: Event<(T, T)>,
+}
+
+type Equality<T> = Box<dyn Fn(&T, &T) -> bool + Send + Sync>;
+
+impl<T> Property<T>
+where
+    T: Clone + PartialEq,
+{
+    /// Creates a property holding `value`, with a new change event.
+    pub fn new(value: T) -> Self {
+        return Self::with_equality(value, |current, new| current == new);
+    }
+}
+
+impl<T: Clone> Property<T> {
+    /// Creates a property holding `value` whose changes are decided by `equals`: setting a
+    /// value for which `equals(&current, &new)` returns `true` neither stores it nor
+    /// dispatches anything.
+    ///
+    /// Since ignored values aren't stored, a slow drift is still reported once it adds up
+    /// to a meaningful change from the last value that was.
+    ///
+    /// ```
+    /// use blob_event::Property;
+    ///
+    /// let temperature = Property::with_equality(20.0_f64, |current, new| (current - new).abs() < 0.5);
+    /// temperature.changed().subscribe(|(_, new)| println!("now {:.1} degrees", new));
+    ///
+    /// temperature.set(20.2); // Sensor noise, ignored
+    /// temperature.set(20.4); // Still within 0.5 of 20.0, ignored
+    /// temperature.set(20.6); // Prints "now 20.6 degrees"
+    /// ```
+    pub fn with_equality<F>(value: T, equals: F) -> Self
+    where
+        F: Fn(&T, &T) -> bool + Send + Sync + 'static,
+    {
+        return Property {
+            inner: Arc::new(PropertyInner {
+                value: Mutex::new(value),
+                equals: Box::new(equals),
+                changed: change_event(),
+            }),
+        };
+    }
+
+    /// Returns a copy of the current value.
+    pub fn get(&self) -> T {
+        return self.inner.value.acquire().clone();
+    }
+
+    /// Stores `value`, and dispatches `(old, new)` to the [`changed`](Self::changed) event
+    /// if it differs from the current value. Returns `true` if it did.
+    ///
+    /// The value is stored before the handlers run, so they can read it with
+    /// [`get`](Self::get).
+    pub fn set(&self, value: T) -> bool {
+        let Some(old) = self.replace(value.clone()) else {
+            return false;
+        };
+        self.inner.changed.invoke((old, value));
+        return true;
+    }
+
+    /// Changes the value in place through `update`, then dispatches the change as
+    /// [`set`](Self::set) does. Returns `true` if the value changed.
+    ///
+    /// `update` runs with the value locked, so concurrent updates are applied one after the
+    /// other and none of them is lost. It must not use the property itself.
+    pub fn update(&self, update: impl FnOnce(&mut T)) -> bool {
+        let change = {
+            let mut current = self.inner.value.acquire();
+            let mut value = current.clone();
+            update(&mut value);
+            if (self.inner.equals)(&current, &value) {
+                None
+            } else {
+                Some((core::mem::replace(&mut *current, value.clone()), value))
+            }
+        };
+        // Dispatched after unlocking, so the handlers can read the value.
+        let Some(change) = change else {
+            return false;
+        };
+        self.inner.changed.invoke(change);
+        return true;
+    }
+
+    /// Returns the event dispatched with `(old, new)` every time the value changes.
+    pub fn changed(&self) -> &Event<(T, T)> {
+        return &self.inner.changed;
+    }
+
+    // Stores `value` without dispatching anything, returning the old value if it changed.
+    pub(crate) fn replace(&self, value: T) -> Option<T> {
+        let mut current = self.inner.value.acquire();
+        if (self.inner.equals)(&current, &value) {
+            return None;
+        }
+        return Some(core::mem::replace(&mut *current, value));
+    }
+}
+
+// The event a property dispatches its changes to, which panics on a change made while
+// dispatching the same change on the same thread.
+fn change_event<T: Clone>() -> Event<(T, T)> {
+    #[cfg(feature = "std")]
+    return Event::builder().max_recursion_depth(1, RecursionPolicy::Panic).build();
+    #[cfg(not(feature = "std"))]
+    return Event::new();
+}
+
+impl<T> Clone for Property<T> {
+    fn clone(&self) -> Self {
+        return Property {
+            inner: Arc::clone(&self.inner),
+        };
+    }
+}
+
+impl<T: Clone + PartialEq + Default> Default for Property<T> {
+    fn default() -> Self {
+        return Self::new(T::default());
+    }
+}
+
+impl<T: Clone + core::fmt::Debug> core::fmt::Debug for Property<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return f.debug_tuple("Property").field(&self.get()).finish();
+    }
+}
@@ -0,0 +1,130 @@
+//! Python bindings, available with the `python` feature.
+//!
+//! [`PyEvent`] is an event whose payloads are Python objects. It is exposed to Python as
+//! `Event`, so Python code can subscribe callables, unsubscribe them, and invoke the event,
+//! while the Rust side keeps a clone of the same event through [`PyEvent::event`].
+//!
+//! For events whose payloads are Rust values, [`Event::subscribe_py`] subscribes a Python
+//! callable directly, converting the arguments on every invoke.
+//!
+//! Python handlers run with the GIL held, and it is acquired as needed, so events may be
+//! invoked from any Rust thread. An exception raised by a Python handler can't propagate
+//! through `invoke`; it is reported through `sys.unraisablehook` and the remaining handlers
+//! still run.
+//!
+//! ```no_run
+//! use blob_event::python;
+//! use pyo3::prelude::*;
+//!
+//! #[pymodule]
+//! fn my_core(module: &Bound<'_, PyModule>) -> PyResult<()> {
+//!     python::register(module)
+//! }
+//! ```
+
+use pyo3::prelude::*;
+
+use crate::{Borrowed, Event, Subscription};
+
+/// An event carrying Python objects, exposed to Python as `Event`.
+#[pyclass(name = "Event", module = "blob_event", frozen)]
+pub struct PyEvent {
+    event: Event<Py<PyAny>, Borrowed>,
+}
+
+/// A subscription to a [`PyEvent`], exposed to Python as `Subscription`.
+#[pyclass(name = "Subscription", module = "blob_event", frozen, eq, hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PySubscription(pub Subscription);
+
+impl PyEvent {
+    /// Returns the underlying event, for invoking it or subscribing Rust handlers from Rust.
+    pub fn event(&self) -> &Event<Py<PyAny>, Borrowed> {
+        return &self.event;
+    }
+}
+
+impl From<Event<Py<PyAny>, Borrowed>> for PyEvent {
+    fn from(event: Event<Py<PyAny>, Borrowed>) -> Self {
+        PyEvent { event }
+    }
+}
+
+#[pymethods]
+impl PyEvent {
+    #[new]
+    fn py_new() -> Self {
+        PyEvent {
+            event: Event::builder().borrowed().build(),
+        }
+    }
+
+    /// Subscribes a callable, which is called with the invoked value.
+    fn subscribe(&self, callback: Py<PyAny>) -> PySubscription {
+        return PySubscription(self.event.subscribe(move |args: &Py<PyAny>| {
+            Python::attach(|py| call_handler(py, &callback, (args.clone_ref(py),)));
+        }));
+    }
+
+    /// Removes a subscription. Returns whether it was found.
+    fn unsubscribe(&self, subscription: PySubscription) -> bool {
+        return self.event.unsubscribe(subscription.0);
+    }
+
+    /// Removes every subscription.
+    fn unsubscribe_all(&self) {
+        self.event.unsubscribe_all();
+    }
+
+    /// Calls every subscribed handler with `value`.
+    fn invoke(&self, py: Python<'_>, value: Py<PyAny>) {
+        // The GIL is released so Rust handlers that wait on other Python-calling threads
+        // can't deadlock; Python handlers take it back while they run.
+        py.detach(|| self.event.invoke(&value));
+    }
+
+    /// Returns the number of subscribed handlers.
+    fn subscriber_count(&self) -> usize {
+        return self.event.subscriber_count();
+    }
+
+    fn __len__(&self) -> usize {
+        return self.event.subscriber_count();
+    }
+}
+
+#[pymethods]
+impl PySubscription {
+    fn __repr__(&self) -> String {
+        return format!("{:?}", self.0);
+    }
+}
+
+impl<Args> Event<Args>
+where
+    Args: Clone + Send + 'static + for<'py> IntoPyObject<'py>,
+{
+    /// Subscribes a Python callable, which is called with the arguments converted to a Python
+    /// object.
+    pub fn subscribe_py(&self, callback: Py<PyAny>) -> Subscription {
+        return self.subscribe(move |args: Args| {
+            Python::attach(|py| call_handler(py, &callback, (args,)));
+        });
+    }
+}
+
+fn call_handler<'py, A>(py: Python<'py>, callback: &Py<PyAny>, args: A)
+where
+    A: pyo3::call::PyCallArgs<'py>,
+{
+    if let Err(error) = callback.call1(py, args) {
+        error.write_unraisable(py, Some(callback.bind(py)));
+    }
+}
+
+/// Adds the `Event` and `Subscription` classes to `module`.
+pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_class::<PyEvent>()?;
+    module.add_class::<PySubscription>()?;
+    return Ok(());
+}
@@ -0,0 +1,180 @@
+use core::cell::RefCell;
+
+use crate::sync::{Lock, Mutex};
+use crate::{Borrowed, Event, HandlerControl, Subscription};
+
+/// An event whose handlers return a value, for asking a chain of providers instead of just
+/// notifying them.
+///
+/// Handlers are subscribed with [`subscribe`](Self::subscribe) and run synchronously, in
+/// the event's dispatch order. [`invoke_fold`](Self::invoke_fold) combines every answer
+/// into one value as it comes, and when the answers are `Option`s,
+/// [`invoke_until`](Self::invoke_until) stops at the first handler that answers. Cloning a
+/// `QueryEvent` returns another handle to the same event.
+///
+/// ```
+/// use blob_event::QueryEvent;
+///
+/// let resolve = QueryEvent::<String, Option<String>>::new();
+/// resolve.subscribe(|name: &String| name.strip_prefix("builtin:").map(|n| format!("/usr/share/{}", n)));
+/// resolve.subscribe(|name: &String| Some(format!("./assets/{}", name)));
+///
+/// assert_eq!(resolve.invoke_until("builtin:font.ttf".to_string()).unwrap(), "/usr/share/font.ttf");
+/// assert_eq!(resolve.invoke_until("hero.png".to_string()).unwrap(), "./assets/hero.png");
+/// ```
+pub struct QueryEvent<Args, R> {
+    event: Event<Query<Args, R>, Borrowed>,
+}
+
+// What the handlers of a `QueryEvent` are invoked with.
+struct Query<Args, R> {
+    args: Args,
+    // Whether an answer ends the dispatch.
+    done: fn(&R) -> bool,
+    // The answer of the handler that ran last, until it is taken.
+    answer: Mutex<Option<R>>,
+}
+
+impl<Args, R> QueryEvent<Args, R>
+where
+    Args: 'static,
+    R: 'static,
+{
+    /// Creates a query event with no subscribers.
+    pub fn new() -> Self {
+        return QueryEvent {
+            event: Event::builder().borrowed().build(),
+        };
+    }
+
+    /// Subscribes a handler, which is asked for an answer on every invocation.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(&Args) -> R + Send + 'static,
+    {
+        let mut handler = handler;
+        return self.event.subscribe_ctl(move |query: &Query<Args, R>| {
+            let answer = handler(&query.args);
+            let done = (query.done)(&answer);
+            *query.answer.acquire() = Some(answer);
+            if done {
+                return HandlerControl::StopPropagation;
+            }
+            return HandlerControl::Continue;
+        });
+    }
+
+    /// Unsubscribes a handler, returning `true` if it was subscribed.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.event.unsubscribe(id);
+    }
+
+    /// Returns the number of handlers.
+    pub fn subscriber_count(&self) -> usize {
+        return self.event.subscriber_count();
+    }
+
+    /// Invokes the handlers with `args` and combines their answers with `fold`, starting
+    /// from `init`, in dispatch order. Each answer is folded in as soon as its handler
+    /// returns, so none are kept around.
+    ///
+    /// ```
+    /// use blob_event::QueryEvent;
+    ///
+    /// let can_quit = QueryEvent::<(), bool>::new();
+    /// can_quit.subscribe(|_| true);
+    /// can_quit.subscribe(|_| false); // Unsaved changes
+    /// assert!(!can_quit.invoke_fold((), true, |all, answer| all && answer));
+    ///
+    /// let load = QueryEvent::<u32, u64>::new();
+    /// load.subscribe(|cpu| *cpu as u64 * 10);
+    /// load.subscribe(|cpu| *cpu as u64 * 20);
+    /// assert_eq!(load.invoke_fold(2, 0, |total, n| total + n), 60);
+    /// ```
+    pub fn invoke_fold<B, F>(&self, args: Args, init: B, fold: F) -> B
+    where
+        F: FnMut(B, R) -> B,
+    {
+        let query = Query {
+            args,
+            done: |_| false,
+            answer: Mutex::new(None),
+        };
+        let folding = Folding {
+            query: &query,
+            acc: RefCell::new(Some(init)),
+            fold: RefCell::new(fold),
+        };
+        let snapshot = self.event.inner.snapshot.load();
+        let _ = self
+            .event
+            .dispatch_snapshot(&snapshot, &query, false, |_| Folded(&folding));
+        return folding.acc.into_inner().expect("fold accumulator missing");
+    }
+
+    // Invokes the handlers with `args` until one of them returns an answer for which `done`
+    // is true, and returns the last answer.
+    pub(crate) fn ask(&self, args: Args, done: fn(&R) -> bool) -> Option<R> {
+        let query = Query {
+            args,
+            done,
+            answer: Mutex::new(None),
+        };
+        self.event.invoke(&query);
+        return query.answer.acquire().take();
+    }
+}
+
+impl<Args, T> QueryEvent<Args, Option<T>>
+where
+    Args: 'static,
+    T: 'static,
+{
+    /// Invokes the handlers with `args` in dispatch order until one returns `Some`, and
+    /// returns its answer. The handlers after it aren't called. Returns `None` if every
+    /// handler returned `None`, or there are none.
+    pub fn invoke_until(&self, args: Args) -> Option<T> {
+        return self.ask(args, Option::is_some).flatten();
+    }
+}
+
+impl<Args, R> Default for QueryEvent<Args, R>
+where
+    Args: 'static,
+    R: 'static,
+{
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<Args, R> Clone for QueryEvent<Args, R> {
+    fn clone(&self) -> Self {
+        return QueryEvent {
+            event: self.event.clone(),
+        };
+    }
+}
+
+// The state of an `invoke_fold`.
+struct Folding<'a, Args, R, B, F> {
+    query: &'a Query<Args, R>,
+    acc: RefCell<Option<B>>,
+    fold: RefCell<F>,
+}
+
+// Kept while a handler runs during an `invoke_fold`, and folds its answer in once it has
+// returned. A handler that panicked left no answer, so nothing is folded while unwinding.
+struct Folded<'a, 'b, Args, R, B, F: FnMut(B, R) -> B>(&'b Folding<'a, Args, R, B, F>);
+
+impl<Args, R, B, F: FnMut(B, R) -> B> Drop for Folded<'_, '_, Args, R, B, F> {
+    fn drop(&mut self) {
+        let Some(answer) = self.0.query.answer.acquire().take() else {
+            return;
+        };
+        let mut acc = self.0.acc.borrow_mut();
+        if let Some(current) = acc.take() {
+            *acc = Some((self.0.fold.borrow_mut())(current, answer));
+        }
+    }
+}
@@ -0,0 +1,142 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::Event;
+use crate::sync::{Lock, Mutex};
+
+/// An invocation captured by an [`EventRecorder`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordedInvocation<Args> {
+    /// When the invocation was dispatched, counted from the start of the recording.
+    pub at: Duration,
+    /// The arguments it was dispatched with.
+    pub args: Args,
+}
+
+/// Captures the invocations of one or more events, with when they happened, so they can be
+/// replayed later into the same events or others.
+///
+/// Recorded invocations can be replayed all at once, or with the same gaps between them as
+/// when they were recorded. Together with [`from_records`](Self::from_records), this turns a
+/// trace captured in production into a regression test.
+///
+/// The recorder taps events through a [mirror](Event::mirror), so it doesn't count as a
+/// subscriber, and it sees the arguments as the handlers did, after any interceptor. Dropping
+/// the recorder, or calling [`stop`](Self::stop), detaches it from every event.
+///
+/// ```
+/// use blob_event::{Event, EventRecorder};
+///
+/// let input = Event::<char>::new();
+/// let mut recorder = EventRecorder::new();
+/// recorder.record(&input);
+/// input.invoke('h');
+/// input.invoke('i');
+/// recorder.stop();
+///
+/// let replayed = Event::<char>::new();
+/// replayed.subscribe(|key| print!("{}", key));
+/// recorder.replay(&replayed);
+/// ```
+pub struct EventRecorder<Args> {
+    started: Instant,
+    records: Arc<Mutex<Vec<RecordedInvocation<Args>>>>,
+    // The mirrors of the recorded events. Dropping them detaches the recorder.
+    taps: Vec<Event<Args>>,
+}
+
+impl<Args: Clone + Send + 'static> EventRecorder<Args> {
+    /// Creates a recorder that isn't recording any event yet. Its clock starts now.
+    pub fn new() -> Self {
+        Self::from_records(Vec::new())
+    }
+
+    /// Creates a recorder holding `records`, such as ones saved from an earlier recording,
+    /// ready to be replayed. Invocations recorded from now on are added after them.
+    pub fn from_records(records: Vec<RecordedInvocation<Args>>) -> Self {
+        EventRecorder {
+            started: Instant::now(),
+            records: Arc::new(Mutex::new(records)),
+            taps: Vec::new(),
+        }
+    }
+
+    /// Starts recording every invocation of `event`, until the recorder is stopped or
+    /// dropped.
+    ///
+    /// Invocations of every recorded event go into the same list, in the order they were
+    /// dispatched.
+    pub fn record(&mut self, event: &Event<Args>) {
+        let tap = event.mirror();
+        let records = Arc::clone(&self.records);
+        let started = self.started;
+        tap.subscribe(move |args| {
+            let at = started.elapsed();
+            records.acquire().push(RecordedInvocation { at, args });
+        });
+        self.taps.push(tap);
+    }
+
+    /// Stops recording every event. The invocations recorded so far are kept.
+    pub fn stop(&mut self) {
+        self.taps.clear();
+    }
+
+    /// Returns a copy of the invocations recorded so far, in the order they were dispatched.
+    pub fn records(&self) -> Vec<RecordedInvocation<Args>> {
+        return self.records.acquire().clone();
+    }
+
+    /// Returns how many invocations have been recorded.
+    pub fn len(&self) -> usize {
+        return self.records.acquire().len();
+    }
+
+    /// Returns `true` if no invocation has been recorded.
+    pub fn is_empty(&self) -> bool {
+        return self.records.acquire().is_empty();
+    }
+
+    /// Forgets every recorded invocation.
+    pub fn clear(&self) {
+        self.records.acquire().clear();
+    }
+
+    /// Invokes `event` with every recorded invocation, in order, as fast as possible.
+    ///
+    /// The invocations are copied before the first one is replayed, so replaying into an
+    /// event that is being recorded records the replay without replaying it again.
+    pub fn replay(&self, event: &Event<Args>) {
+        for record in self.records() {
+            event.invoke(record.args);
+        }
+    }
+
+    /// Invokes `event` with every recorded invocation, in order, blocking the calling thread
+    /// in between so that the invocations are as far apart as when they were recorded.
+    ///
+    /// The first invocation is replayed right away, however long after the start of the
+    /// recording it happened.
+    pub fn replay_timed(&self, event: &Event<Args>) {
+        let records = self.records();
+        let Some(first) = records.first() else {
+            return;
+        };
+        let offset = first.at;
+        let started = Instant::now();
+        for record in records {
+            let due = started + record.at.saturating_sub(offset);
+            let now = Instant::now();
+            if due > now {
+                std::thread::sleep(due - now);
+            }
+            event.invoke(record.args);
+        }
+    }
+}
+
+impl<Args: Clone + Send + 'static> Default for EventRecorder<Args> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,98 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::{DispatchError, EventHooks, Snapshot};
+
+/// What an event with a [recursion limit](crate::EventBuilder::max_recursion_depth) does
+/// with a dispatch that would nest deeper than the limit allows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum RecursionPolicy {
+    /// The invoke panics, naming the event and the limit.
+    #[default]
+    Panic,
+    /// The dispatch is dropped: no handler is called. `try_invoke` returns
+    /// [`DispatchError::RecursionLimit`].
+    Drop,
+    /// The dispatch is dropped as with `Drop`, and its arguments are handed to
+    /// [`EventHooks::on_recursion_overflow`](crate::EventHooks::on_recursion_overflow).
+    Hook,
+}
+
+thread_local! {
+    // The events being dispatched on this thread, innermost last. Only events with a
+    // recursion limit are tracked.
+    static DISPATCHING: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    // Only its address is used, to tell threads apart.
+    static TOKEN: u8 = const { 0 };
+}
+
+// Identifies the calling thread among the running ones. Never 0.
+pub(crate) fn thread_token() -> usize {
+    return TOKEN.with(|token| token as *const u8 as usize);
+}
+
+// Records the calling thread as the one running a handler until dropped, so that dispatches
+// nesting inside the handler can tell it apart from a handler busy on another thread.
+pub(crate) struct Running<'a>(&'a AtomicUsize);
+
+impl<'a> Running<'a> {
+    pub(crate) fn enter(running_on: &'a AtomicUsize) -> Running<'a> {
+        running_on.store(thread_token(), Ordering::Relaxed);
+        return Running(running_on);
+    }
+}
+
+impl Drop for Running<'_> {
+    fn drop(&mut self) {
+        self.0.store(0, Ordering::Relaxed);
+    }
+}
+
+// Marks a dispatch as running on this thread until dropped.
+pub(crate) struct Nested {
+    event: usize,
+}
+
+impl Drop for Nested {
+    fn drop(&mut self) {
+        DISPATCHING.with_borrow_mut(|dispatching| {
+            if let Some(position) = dispatching.iter().rposition(|event| *event == self.event) {
+                dispatching.remove(position);
+            }
+        });
+    }
+}
+
+// Enters a dispatch of the snapshot's event on this thread. Fails, after applying the
+// event's policy, if the event is already being dispatched as many times as its limit allows,
+// whether it invoked itself directly or through other events. `overflow` hands the dropped
+// arguments to the hooks.
+pub(crate) fn enter<Args: ?Sized>(
+    snapshot: &Snapshot<Args>,
+    overflow: impl FnOnce(&dyn EventHooks<Args>),
+) -> Result<Option<Nested>, DispatchError> {
+    let Some((limit, policy)) = snapshot.max_depth else {
+        return Ok(None);
+    };
+    let event = snapshot.event;
+    let depth = DISPATCHING.with_borrow(|dispatching| dispatching.iter().filter(|other| **other == event).count());
+    if depth < limit {
+        DISPATCHING.with_borrow_mut(|dispatching| dispatching.push(event));
+        return Ok(Some(Nested { event }));
+    }
+    #[cfg(feature = "tracing")]
+    tracing::warn!(event, limit, ?policy, "recursion limit reached");
+    #[cfg(feature = "log")]
+    log::warn!("event {} reached its recursion limit of {}", event, limit);
+    match policy {
+        RecursionPolicy::Panic => panic!("event {} exceeded its recursion limit of {}", event, limit),
+        RecursionPolicy::Drop => {}
+        RecursionPolicy::Hook => {
+            if let Some(hooks) = &snapshot.hooks {
+                overflow(hooks.as_ref());
+            }
+        }
+    }
+    return Err(DispatchError::RecursionLimit);
+}
@@ -0,0 +1,181 @@
+//! Events carried over Redis pub/sub channels, available with the `redis` feature.
+//!
+//! [`publish`] sends every dispatch of a local event to a channel, and [`subscribe`]
+//! returns a local event invoked with every message published to a channel, by this process
+//! or any other connected to the same Redis server:
+//!
+//! ```no_run
+//! use blob_event::Event;
+//! use blob_event::redis_bridge::{self, Client};
+//!
+//! let client = Client::open("redis://cache.local/").unwrap();
+//!
+//! let invalidations = redis_bridge::subscribe::<String>(&client, "invalidate").unwrap();
+//! invalidations.subscribe(|key| println!("dropping {} from the local cache", key));
+//!
+//! let writes = Event::<String>::new();
+//! let _publisher = redis_bridge::publish(&client, &writes, "invalidate").unwrap();
+//! writes.invoke("user:42".to_string());
+//! ```
+//!
+//! Payloads are JSON, so arguments need `serde`'s `Serialize` to be published and
+//! `DeserializeOwned` to be received; received messages that don't deserialize are ignored.
+//! Redis pub/sub delivers at most once: messages published while no one is subscribed, or
+//! while a connection is down, are lost.
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+use redis::{Connection, ErrorKind};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::{Cloned, Event, WeakEvent};
+
+pub use redis::{Client, RedisError, RedisResult};
+
+// How often a subscription checks whether its event is still held, and how long it waits
+// before reconnecting after the connection failed.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// How many payloads a publisher queues for its thread before dropping new ones.
+const PUBLISH_BACKLOG: usize = 1024;
+
+/// Publishes every dispatch of `event` to `channel` for as long as the returned
+/// [`Publisher`] is kept, through a connection of its own opened from `client`.
+///
+/// The event is tapped through a [mirror](Event::mirror), so publishing doesn't count as a
+/// subscriber, and a dispatch only queues the payload for a thread owned by the publisher:
+/// `invoke` never waits for Redis. Fails if the connection can't be opened; if it drops
+/// later, the thread opens a new one for the next payload. Payloads that can't be published,
+/// or that arrive while the thread is too far behind, are lost.
+pub fn publish<Args>(client: &Client, event: &Event<Args>, channel: &str) -> RedisResult<Publisher<Args>>
+where
+    Args: Serialize + Clone + Send + 'static,
+{
+    let mut connection = Some(client.get_connection()?);
+    let client = client.clone();
+    let channel = channel.to_string();
+    let (payloads, queued) = mpsc::sync_channel::<Vec<u8>>(PUBLISH_BACKLOG);
+    thread::Builder::new()
+        .name("blob-event-redis".to_string())
+        .spawn(move || {
+            // Ends once the publisher is dropped, along with the mirror holding the sender.
+            for payload in queued {
+                if connection.is_none() {
+                    connection = client.get_connection().ok();
+                }
+                let Some(open) = connection.as_mut() else {
+                    continue;
+                };
+                let published = redis::cmd("PUBLISH").arg(&channel).arg(payload).query::<i64>(open);
+                if published.is_err() {
+                    connection = None;
+                }
+            }
+        })
+        .expect("failed to spawn a blob-event Redis thread");
+    let mirror = event.mirror();
+    mirror.subscribe(move |args: Args| {
+        if let Ok(payload) = serde_json::to_vec(&args) {
+            let _ = payloads.try_send(payload);
+        }
+    });
+    return Ok(Publisher { _mirror: mirror });
+}
+
+/// Publishes an event to a Redis channel, as returned by [`publish`]. Publishing stops once
+/// it is dropped.
+#[must_use = "publishing stops when the publisher is dropped"]
+pub struct Publisher<Args> {
+    _mirror: Event<Args>,
+}
+
+/// Subscribes to `channel` through a connection of its own opened from `client`, and
+/// returns a local event invoked with every message published to it.
+///
+/// The messages are dispatched on a thread owned by the subscription, in the order Redis
+/// delivered them. The subscription reconnects by itself if the connection drops, and ends
+/// once the returned event and all of its clones are dropped. Fails if the connection can't
+/// be opened or the subscription is refused.
+pub fn subscribe<Args>(client: &Client, channel: &str) -> RedisResult<Event<Args>>
+where
+    Args: DeserializeOwned + Clone + Send + 'static,
+{
+    let connection = client.get_connection()?;
+    let event = Event::<Args>::new();
+    let receiving = event.downgrade();
+    let client = client.clone();
+    let channel = channel.to_string();
+    let (subscribed, result) = mpsc::channel();
+    thread::Builder::new()
+        .name("blob-event-redis".to_string())
+        .spawn(move || {
+            let mut connection = connection;
+            let mut subscribed = Some(subscribed);
+            while listen(&mut connection, &channel, &receiving, &mut subscribed).is_err() {
+                loop {
+                    thread::sleep(POLL_INTERVAL);
+                    if receiving.upgrade().is_none() {
+                        return;
+                    }
+                    if let Ok(reconnected) = client.get_connection() {
+                        connection = reconnected;
+                        break;
+                    }
+                }
+            }
+        })
+        .expect("failed to spawn a blob-event Redis thread");
+    result
+        .recv()
+        .unwrap_or_else(|_| Err(RedisError::from((ErrorKind::IoError, "subscription thread stopped"))))?;
+    return Ok(event);
+}
+
+// Subscribes to `channel` and dispatches every message to `event`, reporting the outcome of
+// the subscription through `subscribed` the first time. Returns `Ok` once the event is
+// dropped or the first subscription was refused, or the error that broke the connection.
+fn listen<Args>(
+    connection: &mut Connection,
+    channel: &str,
+    event: &WeakEvent<Args, Cloned>,
+    subscribed: &mut Option<mpsc::Sender<RedisResult<()>>>,
+) -> RedisResult<()>
+where
+    Args: DeserializeOwned + Clone + Send + 'static,
+{
+    let mut pubsub = connection.as_pubsub();
+    let outcome = pubsub
+        .subscribe(channel)
+        .and_then(|()| pubsub.set_read_timeout(Some(POLL_INTERVAL)));
+    if let Some(subscribed) = subscribed.take() {
+        match outcome {
+            Ok(()) => {
+                let _ = subscribed.send(Ok(()));
+            }
+            Err(error) => {
+                let _ = subscribed.send(Err(error));
+                return Ok(());
+            }
+        }
+    } else {
+        outcome?;
+    }
+    loop {
+        let message = match pubsub.get_message() {
+            Ok(message) => Some(message),
+            Err(error) if error.is_timeout() => None,
+            Err(error) => return Err(error),
+        };
+        let Some(event) = event.upgrade() else {
+            return Ok(());
+        };
+        if let Some(message) = message
+            && let Ok(args) = serde_json::from_slice(message.get_payload_bytes())
+        {
+            let _ = event.try_invoke(args);
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use alloc::boxed::Box;
+use core::any::Any;
+use core::marker::PhantomData;
+
+#[cfg(feature = "std")]
+use crate::RecursionPolicy;
+use crate::{Borrowed, Event, EventHandler, HandlerControl, Payload, Subscription};
+
+/// Names a payload type that borrows, such as `(&str, &[u8])`, for a [`RefEvent`].
+///
+/// Rust has no way to write "this type, for any lifetime" on its own, so the payload is
+/// named through a marker type implementing this trait, whose [`Args`](Self::Args) is the
+/// payload for a given lifetime:
+///
+/// ```
+/// use blob_event::BorrowedArgs;
+///
+/// struct Packet;
+///
+/// impl BorrowedArgs for Packet {
+///     type Args<'a> = (&'a str, &'a [u8]);
+/// }
+/// ```
+///
+/// A payload that is a single reference doesn't need this: an
+/// [`Event<str, Borrowed>`](crate::Borrowed) already takes `&str` handlers.
+pub trait BorrowedArgs: 'static {
+    /// The payload, borrowing for `'a`. It is handed to every handler, so it must be `Copy`,
+    /// as references and tuples of them are.
+    type Args<'a>: Copy;
+}
+
+/// An event whose payload borrows from the caller, so that large data can be dispatched
+/// without cloning it or giving it a `'static` owner.
+///
+/// The payload type is named by a [`BorrowedArgs`] marker `P`. Handlers take
+/// `P::Args<'a>` for any lifetime `'a`, and [`invoke`](Self::invoke) accepts a payload
+/// borrowing for as short as the call. Handlers run synchronously, in the order they were
+/// subscribed, and subscriptions behave as on [`Event`]. Cloning a `RefEvent` returns another
+/// handle to the same event.
+///
+/// A handler may invoke its own event: with `std`, the nested dispatch skips the handlers
+/// still running further up the thread, and nesting more than 64 deep panics.
+///
+/// ```
+/// use blob_event::{BorrowedArgs, RefEvent};
+///
+/// struct Packet;
+///
+/// impl BorrowedArgs for Packet {
+///     type Args<'a> = (&'a str, &'a [u8]);
+/// }
+///
+/// let received = RefEvent::<Packet>::new();
+/// received.subscribe(|(peer, bytes)| println!("{} sent {} bytes", peer, bytes.len()));
+///
+/// let buffer = vec![0u8; 64 * 1024];
+/// let peer = String::from("10.0.0.7");
+/// received.invoke((&peer, &buffer[..1500])); // Prints "10.0.0.7 sent 1500 bytes"
+/// ```
+pub struct RefEvent<P> {
+    // Dispatched with only a marker as its arguments; the payload is lent by a `Loan`.
+    event: Event<PhantomData<P>, Borrowed>,
+}
+
+// How deeply dispatches of a `RefEvent` may nest on one thread.
+#[cfg(feature = "std")]
+const MAX_DEPTH: usize = 64;
+
+// Holds a handler taking the payload for any lifetime. It is never called with the marker;
+// a `Loan` finds it through `as_any` instead.
+struct Lent<P: BorrowedArgs>(LentHandler<P>);
+
+type LentHandler<P> = Box<dyn for<'a> FnMut(<P as BorrowedArgs>::Args<'a>) + Send>;
+
+impl<P: BorrowedArgs> EventHandler<PhantomData<P>> for Lent<P> {
+    fn call(&mut self, _marker: &PhantomData<P>) {}
+
+    fn as_any(&mut self) -> Option<&mut dyn Any> {
+        return Some(self);
+    }
+}
+
+// The payload of one `RefEvent::invoke`, lent to every `Lent` handler in turn.
+struct Loan<'a, P: BorrowedArgs> {
+    args: P::Args<'a>,
+    marker: PhantomData<P>,
+}
+
+impl<P: BorrowedArgs> Payload<PhantomData<P>> for Loan<'_, P> {
+    fn args(&self) -> &PhantomData<P> {
+        return &self.marker;
+    }
+
+    // A `RefEvent` has no interceptors.
+    fn replace(&mut self, _marker: Box<PhantomData<P>>) {}
+
+    fn call(&mut self, handler: &mut dyn EventHandler<PhantomData<P>>) -> HandlerControl {
+        if let Some(lent) = handler.as_any().and_then(|any| any.downcast_mut::<Lent<P>>()) {
+            (lent.0)(self.args);
+        }
+        return HandlerControl::Continue;
+    }
+}
+
+impl<P: BorrowedArgs> RefEvent<P> {
+    /// Creates a new event with no subscribers.
+    pub fn new() -> Self {
+        let builder = Event::builder().borrowed();
+        #[cfg(feature = "std")]
+        let builder = builder.max_recursion_depth(MAX_DEPTH, RecursionPolicy::Panic);
+        return RefEvent { event: builder.build() };
+    }
+
+    /// Subscribes a callback taking the payload for any lifetime.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: for<'a> FnMut(P::Args<'a>) + Send + 'static,
+    {
+        return self.event.subscribe_handler(0, Box::new(Lent::<P>(Box::new(handler))));
+    }
+
+    /// Unsubscribes a callback from this event.
+    ///
+    /// Returns `true` if the subscription was found and removed, `false` otherwise.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.event.unsubscribe(id);
+    }
+
+    /// Removes all subscribers from this event.
+    pub fn unsubscribe_all(&self) {
+        self.event.unsubscribe_all();
+    }
+
+    /// Returns the current number of subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        return self.event.subscriber_count();
+    }
+
+    /// Triggers the event, calling every subscribed handler with `args`.
+    ///
+    /// The set of handlers is captured the same way as by [`Event::invoke`].
+    pub fn invoke(&self, args: P::Args<'_>) {
+        yield_point!(BeforeSnapshot);
+        let snapshot = self.event.inner.snapshot.load();
+        let mut loan = Loan::<P> {
+            args,
+            marker: PhantomData,
+        };
+        // Without catching panics the dispatch can't fail; a panic unwinds into the caller.
+        let _ = self.event.dispatch_payload(&snapshot, &mut loan, false, |_| ());
+    }
+}
+
+impl<P: BorrowedArgs> Default for RefEvent<P> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<P> Clone for RefEvent<P> {
+    fn clone(&self) -> Self {
+        return RefEvent {
+            event: self.event.clone(),
+        };
+    }
+}
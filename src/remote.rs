@@ -0,0 +1,309 @@
+//! Events pushed to other processes or machines over TCP, available with the `remote`
+//! feature.
+//!
+//! A [`Server`] started with [`serve`] publishes chosen events under a name, and a
+//! [`Client`] returned by [`connect`] materializes them as local events, which are invoked
+//! whenever the published event dispatches on the server:
+//!
+//! ```no_run
+//! use blob_event::{Event, remote};
+//!
+//! // In the service
+//! let prices = Event::<(String, f64)>::new();
+//! let server = remote::serve("0.0.0.0:9000").unwrap();
+//! server.publish("prices", &prices);
+//! prices.invoke(("ACME".to_string(), 12.5));
+//!
+//! // In the dashboard
+//! let client = remote::connect("service.local:9000").unwrap();
+//! client
+//!     .event::<(String, f64)>("prices")
+//!     .subscribe(|(symbol, price)| println!("{} is at {}", symbol, price));
+//! ```
+//!
+//! Delivery only goes from the server to its clients, and a client receives the dispatches
+//! made after it connected. Each dispatch is sent as one line of JSON naming the event, so
+//! arguments need `serde`'s `Serialize` on the server and `DeserializeOwned` on the client,
+//! where messages for events it never asked for, or that don't deserialize, are ignored.
+//! A client's events are invoked on a thread it owns, in the order the server sent them.
+//!
+//! The server writes to each client from a thread of its own, so invoking a published
+//! event never waits for the network. A client that falls more than a bounded number of
+//! messages behind, or whose socket stops accepting writes for a while, is disconnected
+//! rather than allowed to hold anything up.
+//!
+//! Only this raw TCP protocol is spoken: there is no WebSocket transport, so browsers can't
+//! connect directly and need a bridge in front of the server.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, Shutdown, SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, SyncSender, TrySendError};
+use std::sync::{Arc, Mutex, Weak};
+use std::thread;
+use std::time::Duration;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::Event;
+
+/// Publishes events to every connected [`Client`], as returned by [`serve`].
+///
+/// Dropping the server stops publishing and closes every connection.
+pub struct Server {
+    address: SocketAddr,
+    clients: Arc<Mutex<Vec<Connection>>>,
+    // Set when the server is dropped, so the thread accepting clients stops at the next
+    // connection rather than taking it as a client.
+    stopped: Arc<AtomicBool>,
+    // The mirrors tapping the published events, by name. Dropping one detaches it.
+    published: Mutex<HashMap<String, Box<dyn Any + Send>>>,
+}
+
+// How many messages a client may fall behind before it is disconnected.
+const CLIENT_BACKLOG: usize = 1024;
+
+// How long a write to a client may block before the client is disconnected.
+const WRITE_TIMEOUT: Duration = Duration::from_secs(10);
+
+// A connected client. Its own thread writes the lines queued for it, so a slow client
+// never holds up the thread invoking the published event.
+struct Connection {
+    stream: TcpStream,
+    lines: SyncSender<Arc<[u8]>>,
+}
+
+impl Connection {
+    fn open(stream: TcpStream) -> io::Result<Connection> {
+        stream.set_nodelay(true)?;
+        stream.set_write_timeout(Some(WRITE_TIMEOUT))?;
+        let mut writer = stream.try_clone()?;
+        let (lines, queued) = mpsc::sync_channel::<Arc<[u8]>>(CLIENT_BACKLOG);
+        thread::Builder::new()
+            .name("blob-event-remote".to_string())
+            .spawn(move || {
+                for line in queued {
+                    if writer.write_all(&line).is_err() {
+                        let _ = writer.shutdown(Shutdown::Both);
+                        return;
+                    }
+                }
+            })?;
+        return Ok(Connection { stream, lines });
+    }
+
+    // Queues `line` for the client, returning `false` if the client is gone or too far
+    // behind, in which case it is disconnected.
+    fn send(&self, line: &Arc<[u8]>) -> bool {
+        match self.lines.try_send(Arc::clone(line)) {
+            Ok(()) => return true,
+            Err(TrySendError::Full(_)) => {
+                let _ = self.stream.shutdown(Shutdown::Both);
+                return false;
+            }
+            Err(TrySendError::Disconnected(_)) => return false,
+        }
+    }
+}
+
+/// Receives the events published by a [`Server`], as returned by [`connect`].
+///
+/// Dropping the client closes its connection; its events are no longer invoked.
+pub struct Client {
+    stream: TcpStream,
+    events: Arc<Mutex<HashMap<String, Subscribed>>>,
+}
+
+// An event a client asked for: the `Event<Args>` itself, and how to invoke it with a message.
+struct Subscribed {
+    event: Box<dyn Any + Send>,
+    dispatch: Arc<dyn Fn(serde_json::Value) + Send + Sync>,
+}
+
+#[derive(Serialize)]
+struct Message<'a, Args> {
+    event: &'a str,
+    args: &'a Args,
+}
+
+#[derive(Deserialize)]
+struct RawMessage {
+    event: String,
+    args: serde_json::Value,
+}
+
+/// Starts a server accepting clients at `address`.
+///
+/// Binding port 0 picks a free port, which [`Server::local_addr`] returns.
+pub fn serve(address: impl ToSocketAddrs) -> io::Result<Server> {
+    let listener = TcpListener::bind(address)?;
+    let address = listener.local_addr()?;
+    let clients = Arc::new(Mutex::new(Vec::new()));
+    let accepting = Arc::downgrade(&clients);
+    let stopped = Arc::new(AtomicBool::new(false));
+    let stopping = Arc::clone(&stopped);
+    thread::Builder::new()
+        .name("blob-event-remote".to_string())
+        .spawn(move || accept(listener, accepting, &stopping))?;
+    return Ok(Server {
+        address,
+        clients,
+        stopped,
+        published: Mutex::new(HashMap::new()),
+    });
+}
+
+fn accept(listener: TcpListener, clients: Weak<Mutex<Vec<Connection>>>, stopped: &AtomicBool) {
+    for stream in listener.incoming() {
+        if stopped.load(Ordering::Acquire) {
+            return;
+        }
+        let Some(clients) = clients.upgrade() else {
+            return;
+        };
+        if let Ok(connection) = stream.and_then(Connection::open) {
+            clients.lock().unwrap().push(connection);
+        }
+    }
+}
+
+impl Server {
+    /// Publishes `event` under `name`: from now on, every dispatch of the event is sent to
+    /// the connected clients, which receive it through [`Client::event`] with the same name.
+    ///
+    /// The event is tapped through a [mirror](Event::mirror), so publishing it doesn't count
+    /// as a subscriber. Publishing another event under the same name replaces the first one.
+    pub fn publish<Args>(&self, name: &str, event: &Event<Args>)
+    where
+        Args: Serialize + Clone + Send + 'static,
+    {
+        let mirror = event.mirror();
+        let clients = Arc::clone(&self.clients);
+        let event_name = name.to_string();
+        mirror.subscribe(move |args: Args| {
+            let message = Message {
+                event: &event_name,
+                args: &args,
+            };
+            let Ok(mut line) = serde_json::to_vec(&message) else {
+                return;
+            };
+            line.push(b'\n');
+            let line: Arc<[u8]> = line.into();
+            clients.lock().unwrap().retain(|client| client.send(&line));
+        });
+        self.published
+            .lock()
+            .unwrap()
+            .insert(name.to_string(), Box::new(mirror));
+    }
+
+    /// Stops publishing the event published under `name`, returning `true` if there was one.
+    pub fn unpublish(&self, name: &str) -> bool {
+        return self.published.lock().unwrap().remove(name).is_some();
+    }
+
+    /// Returns the address the server accepts clients at.
+    pub fn local_addr(&self) -> SocketAddr {
+        return self.address;
+    }
+
+    /// Returns how many clients are connected.
+    ///
+    /// A client that went away is only noticed when the next dispatch is sent to it.
+    pub fn client_count(&self) -> usize {
+        return self.clients.lock().unwrap().len();
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        self.stopped.store(true, Ordering::Release);
+        self.published.get_mut().unwrap().clear();
+        for client in self.clients.lock().unwrap().drain(..) {
+            let _ = client.stream.shutdown(Shutdown::Both);
+        }
+        // Wakes the thread accepting clients so it notices the server is gone. A server bound
+        // to every interface is reached through the loopback one.
+        let mut address = self.address;
+        match address.ip() {
+            IpAddr::V4(ip) if ip.is_unspecified() => address.set_ip(Ipv4Addr::LOCALHOST.into()),
+            IpAddr::V6(ip) if ip.is_unspecified() => address.set_ip(Ipv6Addr::LOCALHOST.into()),
+            _ => {}
+        }
+        let _ = TcpStream::connect(address);
+    }
+}
+
+/// Connects to a [`Server`] at `address`.
+pub fn connect(address: impl ToSocketAddrs) -> io::Result<Client> {
+    let stream = TcpStream::connect(address)?;
+    let events = Arc::new(Mutex::new(HashMap::<String, Subscribed>::new()));
+    let reader = BufReader::new(stream.try_clone()?);
+    let receiving = Arc::clone(&events);
+    thread::Builder::new()
+        .name("blob-event-remote".to_string())
+        .spawn(move || {
+            for line in reader.lines() {
+                let Ok(line) = line else {
+                    return;
+                };
+                let Ok(message) = serde_json::from_str::<RawMessage>(&line) else {
+                    continue;
+                };
+                let dispatch = receiving
+                    .lock()
+                    .unwrap()
+                    .get(&message.event)
+                    .map(|subscribed| Arc::clone(&subscribed.dispatch));
+                if let Some(dispatch) = dispatch {
+                    dispatch(message.args);
+                }
+            }
+        })?;
+    return Ok(Client { stream, events });
+}
+
+impl Client {
+    /// Returns the local event invoked whenever the server dispatches the event it published
+    /// under `name`. Asking for the same name again returns the same event.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the same name was asked for before with a different `Args`.
+    pub fn event<Args>(&self, name: &str) -> Event<Args>
+    where
+        Args: DeserializeOwned + Clone + Send + 'static,
+    {
+        let mut events = self.events.lock().unwrap();
+        if let Some(subscribed) = events.get(name) {
+            return subscribed
+                .event
+                .downcast_ref::<Event<Args>>()
+                .unwrap_or_else(|| panic!("remote event `{}` was asked for with another type", name))
+                .clone();
+        }
+        let event = Event::<Args>::new();
+        let dispatching = event.clone();
+        let dispatch = move |value| {
+            if let Ok(args) = serde_json::from_value(value) {
+                let _ = dispatching.try_invoke(args);
+            }
+        };
+        let subscribed = Subscribed {
+            event: Box::new(event.clone()),
+            dispatch: Arc::new(dispatch),
+        };
+        events.insert(name.to_string(), subscribed);
+        return event;
+    }
+}
+
+impl Drop for Client {
+    fn drop(&mut self) {
+        let _ = self.stream.shutdown(Shutdown::Both);
+    }
+}
@@ -0,0 +1,87 @@
+use alloc::boxed::Box;
+use alloc::sync::Arc;
+use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use crate::sync::Mutex;
+use crate::{Borrowed, ByClone, Cloned, Event, EventHandler, EventHandlers, HandlerSlot, Subscription};
+
+impl<Args: ?Sized> EventHandlers<Args> {
+    // Puts `handler` behind the subscription in a new cell, keeping its place in the dispatch
+    // order. The old cell is deactivated rather than changed, since its handler may be
+    // running, possibly the one doing the replacing.
+    fn replace(&mut self, id: Subscription, handler: Box<dyn EventHandler<Args>>) -> bool {
+        if id.event != self.event {
+            return false;
+        }
+        let Some(entry) = self.handlers.get_mut(id.index, id.generation) else {
+            return false;
+        };
+        let old = &entry.cell;
+        old.active.store(false, Ordering::Release);
+        entry.cell = Arc::new(HandlerSlot {
+            id,
+            name: old.name,
+            active: AtomicBool::new(true),
+            handler: Mutex::new(handler),
+            calls: AtomicUsize::new(old.calls.load(Ordering::Relaxed)),
+            closing: AtomicBool::new(false),
+            #[cfg(feature = "std")]
+            subscribed_at: old.subscribed_at,
+            #[cfg(feature = "std")]
+            counters: old.counters.carry_over(),
+            #[cfg(feature = "std")]
+            running_on: AtomicUsize::new(0),
+        });
+        #[cfg(feature = "tracing")]
+        tracing::debug!(subscription = ?id, "replaced handler");
+        #[cfg(feature = "log")]
+        log::debug!("replaced handler of {:?}", id);
+        return true;
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    fn replace_handler(&self, id: Subscription, handler: Box<dyn EventHandler<Args>>) -> bool {
+        return self.inner.update(|handlers| handlers.replace(id, handler));
+    }
+}
+
+impl<Args: Clone> Event<Args, Cloned> {
+    /// Swaps the callback behind `id` for `handler`, returning `false` if `id` isn't
+    /// subscribed to this event.
+    ///
+    /// The subscription keeps its identity, name, priority, place in the dispatch order, and
+    /// statistics, so behavior can be hot-swapped, for example on a config reload, without
+    /// the reordering that unsubscribing and resubscribing would cause. Dispatches that
+    /// started before the swap and haven't reached the subscription yet skip it; later ones
+    /// call the new handler. The old callback is dropped once no dispatch holds it anymore.
+    /// Hooks aren't notified, since nothing subscribed or unsubscribed.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// let frame = Event::<u64>::new();
+    /// let render = frame.subscribe(|n| println!("frame {}", n));
+    /// assert!(frame.replace(render, |n| println!("frame {} (wireframe)", n)));
+    /// frame.invoke(1); // Prints: frame 1 (wireframe)
+    /// ```
+    pub fn replace<F>(&self, id: Subscription, handler: F) -> bool
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.replace_handler(id, Box::new(ByClone(handler)));
+    }
+}
+
+impl<Args: ?Sized> Event<Args, Borrowed> {
+    /// Swaps the callback behind `id` for `handler`, returning `false` if `id` isn't
+    /// subscribed to this event.
+    ///
+    /// See [`Event::replace`].
+    pub fn replace<F>(&self, id: Subscription, handler: F) -> bool
+    where
+        F: FnMut(&Args) + Send + 'static,
+    {
+        return self.replace_handler(id, Box::new(handler));
+    }
+}
@@ -0,0 +1,143 @@
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::time::{Duration, Instant};
+
+use crate::{Borrowed, Event, Subscription};
+
+/// An event whose handlers can answer: [`ask`](Self::ask) invokes the handlers with a
+/// request and hands each of them a [`Responder`], through which it may send back a
+/// response.
+///
+/// A handler can answer right away, or keep its responder and answer later from anywhere,
+/// such as another thread. The asking side waits until every responder has either answered
+/// or been dropped, so handlers that don't want to answer just ignore theirs. The `_timeout`
+/// variants stop waiting at a deadline instead, for handlers that might hold on to their
+/// responders. Cloning a `RequestEvent` returns another handle to the same event.
+///
+/// ```
+/// use blob_event::RequestEvent;
+///
+/// let lookup = RequestEvent::<String, u32>::new();
+/// lookup.subscribe(|name: &String, responder| {
+///     if name == "port" {
+///         responder.respond(8080);
+///     }
+/// });
+/// assert_eq!(lookup.ask("port".to_string()), Some(8080));
+/// assert_eq!(lookup.ask("host".to_string()), None);
+/// ```
+pub struct RequestEvent<Req, Resp> {
+    event: Event<Request<Req, Resp>, Borrowed>,
+}
+
+// What the handlers of a `RequestEvent` are invoked with.
+struct Request<Req, Resp> {
+    request: Req,
+    responses: Sender<Resp>,
+}
+
+/// Sends a handler's answer to the caller of [`RequestEvent::ask`].
+pub struct Responder<Resp> {
+    responses: Sender<Resp>,
+}
+
+impl<Resp> Responder<Resp> {
+    /// Answers the request, returning `false` if the asker has stopped waiting.
+    pub fn respond(self, response: Resp) -> bool {
+        return self.responses.send(response).is_ok();
+    }
+}
+
+impl<Req, Resp> RequestEvent<Req, Resp>
+where
+    Req: 'static,
+    Resp: Send + 'static,
+{
+    /// Creates a request event with no subscribers.
+    pub fn new() -> Self {
+        return RequestEvent {
+            event: Event::builder().borrowed().build(),
+        };
+    }
+
+    /// Subscribes a handler, which receives every request along with a responder.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(&Req, Responder<Resp>) + Send + 'static,
+    {
+        let mut handler = handler;
+        return self.event.subscribe(move |request: &Request<Req, Resp>| {
+            let responder = Responder {
+                responses: request.responses.clone(),
+            };
+            handler(&request.request, responder);
+        });
+    }
+
+    /// Unsubscribes a handler, returning `true` if it was subscribed.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.event.unsubscribe(id);
+    }
+
+    /// Returns the number of handlers.
+    pub fn subscriber_count(&self) -> usize {
+        return self.event.subscriber_count();
+    }
+
+    /// Invokes the handlers with `request` and returns the first response, or `None` if
+    /// every responder was dropped without answering.
+    pub fn ask(&self, request: Req) -> Option<Resp> {
+        return self.send(request).recv().ok();
+    }
+
+    /// Like [`ask`](Self::ask), but gives up and returns `None` if no response arrived
+    /// within `timeout`.
+    pub fn ask_timeout(&self, request: Req, timeout: Duration) -> Option<Resp> {
+        return self.send(request).recv_timeout(timeout).ok();
+    }
+
+    /// Invokes the handlers with `request` and returns every response, in the order they
+    /// arrived, once every responder has answered or been dropped.
+    pub fn ask_all(&self, request: Req) -> Vec<Resp> {
+        return self.send(request).iter().collect();
+    }
+
+    /// Like [`ask_all`](Self::ask_all), but returns the responses that arrived within
+    /// `timeout` if some responders haven't answered by then.
+    pub fn ask_all_timeout(&self, request: Req, timeout: Duration) -> Vec<Resp> {
+        let deadline = Instant::now() + timeout;
+        let responses = self.send(request);
+        let mut received = Vec::new();
+        loop {
+            match responses.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                Ok(response) => received.push(response),
+                Err(RecvTimeoutError::Timeout | RecvTimeoutError::Disconnected) => return received,
+            }
+        }
+    }
+
+    // Invokes the handlers, returning where their responses arrive. Responses already sent
+    // stay queued after the handlers' responders are gone.
+    fn send(&self, request: Req) -> Receiver<Resp> {
+        let (responses, received) = mpsc::channel();
+        self.event.invoke(&Request { request, responses });
+        return received;
+    }
+}
+
+impl<Req, Resp> Default for RequestEvent<Req, Resp>
+where
+    Req: 'static,
+    Resp: Send + 'static,
+{
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<Req, Resp> Clone for RequestEvent<Req, Resp> {
+    fn clone(&self) -> Self {
+        return RequestEvent {
+            event: self.event.clone(),
+        };
+    }
+}
@@ -0,0 +1,301 @@
+//! Events invoked on a cron-style schedule.
+//!
+//! [`cron`] parses a standard five-field cron expression and returns a [`CronJob`], whose
+//! event is invoked at every matching minute with the time it was due. Being an ordinary
+//! `Event<Instant>`, it composes with the crate's operators like any other:
+//!
+//! ```
+//! use blob_event::schedule;
+//!
+//! let every_five_minutes = schedule::cron("*/5 * * * *").unwrap();
+//! every_five_minutes.event().subscribe(|due| println!("rotating logs, due at {:?}", due));
+//!
+//! // Later, to stop it
+//! every_five_minutes.cancel();
+//! ```
+//!
+//! The fields are, in order, the minute (0–59), hour (0–23), day of the month (1–31), month
+//! (1–12), and day of the week (0–7, where both 0 and 7 are Sunday). Each is `*`, a number,
+//! a range `a-b`, any of these followed by a step `/n`, or a comma-separated list of them.
+//! As in cron, when both the day of the month and the day of the week are restricted, a day
+//! matching either one matches. A day field starting with `*`, such as `*/2`, doesn't count as
+//! restricted: a day then has to match both. Times are in UTC.
+//!
+//! Jobs run on the crate's [`Scheduler`], so a job created inside
+//! [`with_scheduler`](crate::with_scheduler) follows its clock, and handlers run on the
+//! scheduler's thread. A handler that panics doesn't stop the job.
+
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::{Event, Scheduler, WeakEvent};
+
+/// A schedule parsed from a cron expression, as returned by [`cron`].
+pub struct CronJob {
+    event: Event<Instant>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CronJob {
+    /// Returns the event invoked at every time matching the schedule, with the time it was
+    /// due on the scheduler's clock.
+    ///
+    /// The job stops by itself once the event and every clone of it are dropped.
+    pub fn event(&self) -> Event<Instant> {
+        return self.event.clone();
+    }
+
+    /// Stops the job. The event is no longer invoked, not even if it is already due.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` if the job has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        return self.cancelled.load(Ordering::SeqCst);
+    }
+}
+
+/// Returned by [`cron`] for an expression that can't be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCronError {
+    field: &'static str,
+    value: String,
+}
+
+impl fmt::Display for ParseCronError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.value.is_empty() {
+            return write!(f, "cron expression is missing its {} field", self.field);
+        }
+        return write!(f, "invalid cron {} field `{}`", self.field, self.value);
+    }
+}
+
+impl std::error::Error for ParseCronError {}
+
+/// Starts invoking an event on the schedule described by a cron expression.
+///
+/// See the [module documentation](self) for the syntax.
+pub fn cron(expression: &str) -> Result<CronJob, ParseCronError> {
+    let schedule = Arc::new(Schedule::parse(expression)?);
+    let event = Event::new();
+    let cancelled = Arc::new(AtomicBool::new(false));
+    let scheduler = crate::timer::current();
+    // Wall-clock times are derived from the scheduler's clock, so schedules follow a
+    // virtual clock too.
+    let clock = Clock {
+        start: scheduler.now(),
+        wall: SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default(),
+    };
+    let job = Job {
+        schedule,
+        clock,
+        event: event.downgrade(),
+        cancelled: Arc::clone(&cancelled),
+    };
+    job.arm(scheduler, clock.start);
+    return Ok(CronJob { event, cancelled });
+}
+
+// Maps the scheduler's clock to time since the Unix epoch.
+#[derive(Clone, Copy)]
+struct Clock {
+    start: Instant,
+    wall: Duration,
+}
+
+impl Clock {
+    fn wall(&self, at: Instant) -> Duration {
+        return self.wall + at.saturating_duration_since(self.start);
+    }
+}
+
+struct Job {
+    schedule: Arc<Schedule>,
+    clock: Clock,
+    event: WeakEvent<Instant, crate::Cloned>,
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Job {
+    // Schedules the first run after `after`, if the schedule ever matches again.
+    fn arm(self, scheduler: Arc<dyn Scheduler>, after: Instant) {
+        let wall = self.clock.wall(after);
+        let Some(next) = self.schedule.next_after(wall.as_secs()) else {
+            return;
+        };
+        let deadline = after + (Duration::from_secs(next) - wall);
+        let rearm = Arc::clone(&scheduler);
+        scheduler.schedule(
+            deadline,
+            Box::new(move || {
+                if self.cancelled.load(Ordering::SeqCst) {
+                    return;
+                }
+                let Some(event) = self.event.upgrade() else {
+                    return;
+                };
+                let _ = event.try_invoke(deadline);
+                drop(event);
+                self.arm(rearm, deadline);
+            }),
+        );
+    }
+}
+
+// The times a cron expression matches, as bit sets.
+struct Schedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    // Whether the day fields start with `*`, which decides how they combine.
+    any_day: bool,
+    any_weekday: bool,
+}
+
+const SECS_PER_DAY: u64 = 24 * 60 * 60;
+
+impl Schedule {
+    fn parse(expression: &str) -> Result<Schedule, ParseCronError> {
+        let mut fields = expression.split_whitespace();
+        let mut next = |name: &'static str, min: u32, max: u32| {
+            let value = fields.next().unwrap_or("");
+            return parse_field(value, min, max)
+                .map(|bits| (bits, value.starts_with('*')))
+                .ok_or_else(|| ParseCronError {
+                    field: name,
+                    value: value.to_string(),
+                });
+        };
+        let (minutes, _) = next("minute", 0, 59)?;
+        let (hours, _) = next("hour", 0, 23)?;
+        let (days, any_day) = next("day of month", 1, 31)?;
+        let (months, _) = next("month", 1, 12)?;
+        let (mut weekdays, any_weekday) = next("day of week", 0, 7)?;
+        if let Some(extra) = fields.next() {
+            return Err(ParseCronError {
+                field: "trailing",
+                value: extra.to_string(),
+            });
+        }
+        // Sunday is both 0 and 7.
+        if weekdays & (1 << 7) != 0 {
+            weekdays |= 1;
+        }
+        return Ok(Schedule {
+            minutes,
+            hours,
+            days,
+            months,
+            weekdays,
+            any_day,
+            any_weekday,
+        });
+    }
+
+    fn day_matches(&self, day: u32, weekday: u32) -> bool {
+        let day = self.days & (1 << day) != 0;
+        let weekday = self.weekdays & (1 << weekday) != 0;
+        // As in Vixie cron, a day field starting with `*`, stepped or not, narrows the other
+        // one instead of adding to it.
+        if self.any_day || self.any_weekday {
+            return day && weekday;
+        }
+        return day || weekday;
+    }
+
+    // Returns the first matching minute strictly after `after`, in seconds since the Unix
+    // epoch, or `None` if nothing matches within the next few years, as for February 30.
+    fn next_after(&self, after: u64) -> Option<u64> {
+        let mut t = (after / 60 + 1) * 60;
+        let limit = t + 5 * 366 * SECS_PER_DAY;
+        while t < limit {
+            let days = t / SECS_PER_DAY;
+            let (year, month, day) = civil_from_days(days as i64);
+            if self.months & (1 << month) == 0 {
+                let (year, month) = if month == 12 { (year + 1, 1) } else { (year, month + 1) };
+                t = days_from_civil(year, month, 1) as u64 * SECS_PER_DAY;
+                continue;
+            }
+            // The Unix epoch was a Thursday.
+            let weekday = ((days + 4) % 7) as u32;
+            if !self.day_matches(day, weekday) {
+                t = (days + 1) * SECS_PER_DAY;
+                continue;
+            }
+            let hour = (t % SECS_PER_DAY / 3600) as u32;
+            if self.hours & (1 << hour) == 0 {
+                t = (t / 3600 + 1) * 3600;
+                continue;
+            }
+            let minute = (t % 3600 / 60) as u32;
+            if self.minutes & (1 << minute) == 0 {
+                t += 60;
+                continue;
+            }
+            return Some(t);
+        }
+        return None;
+    }
+}
+
+// Parses one field into a bit set of the values it matches, or `None` if it is invalid.
+fn parse_field(field: &str, min: u32, max: u32) -> Option<u64> {
+    let mut bits = 0;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|step| *step > 0)?),
+            None => (part, 1),
+        };
+        let (start, end) = match range {
+            "*" => (min, max),
+            _ => match range.split_once('-') {
+                Some((start, end)) => (start.parse().ok()?, end.parse().ok()?),
+                // A single value with a step runs to the end of the range, as in `5/15`.
+                None if step > 1 => (range.parse().ok()?, max),
+                None => {
+                    let value = range.parse().ok()?;
+                    (value, value)
+                }
+            },
+        };
+        if start < min || end > max || start > end {
+            return None;
+        }
+        for value in (start..=end).step_by(step as usize) {
+            bits |= 1 << value;
+        }
+    }
+    return Some(bits);
+}
+
+// The proleptic Gregorian calendar date of a day counted from the Unix epoch, as
+// (year, month, day). From Howard Hinnant's `chrono`-compatible date algorithms.
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    return (year, month, day);
+}
+
+// The inverse of `civil_from_days`.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year.rem_euclid(400);
+    let month = i64::from(month);
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    return era * 146_097 + doe - 719_468;
+}
@@ -0,0 +1,97 @@
+use std::any::Any;
+use std::boxed::Box;
+use std::cell::RefCell;
+use std::sync::Arc;
+use std::vec::Vec;
+
+use crate::{Cloned, Event, EventHandler, Subscription};
+
+thread_local! {
+    // The payloads `invoke_shared` is dispatching on this thread, innermost last.
+    static SHARING: RefCell<Vec<Box<dyn Any>>> = const { RefCell::new(Vec::new()) };
+}
+
+// Adapts a closure taking an `Arc` of the arguments. It gets the `Arc` passed to
+// `invoke_shared` when the arguments it is called with are the ones inside it, and a new one
+// holding a clone otherwise.
+struct ByArc<F>(F);
+
+impl<F, Args> EventHandler<Args> for ByArc<F>
+where
+    F: FnMut(Arc<Args>) + Send,
+    Args: Clone + 'static,
+{
+    fn call(&mut self, args: &Args) {
+        let shared = SHARING.with_borrow(|sharing| {
+            return sharing.iter().rev().find_map(|payload| {
+                payload
+                    .downcast_ref::<Arc<Args>>()
+                    .filter(|shared| core::ptr::eq(Arc::as_ptr(shared), args))
+                    .cloned()
+            });
+        });
+        (self.0)(shared.unwrap_or_else(|| Arc::new(args.clone())));
+    }
+}
+
+impl<Args: Clone + 'static> Event<Args, Cloned> {
+    /// Subscribes a callback that receives an `Arc` of the arguments instead of a clone.
+    /// Requires `std`.
+    ///
+    /// Invoked with [`invoke_shared`](Self::invoke_shared), the callback gets a reference
+    /// counted handle to the caller's payload, so nothing is cloned for it. On a plain
+    /// [`invoke`](Self::invoke), and when an interceptor replaced the payload, it gets a new
+    /// `Arc` holding a clone.
+    pub fn subscribe_shared<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Arc<Args>) + Send + 'static,
+    {
+        return self.subscribe_handler(0, Box::new(ByArc(handler)));
+    }
+
+    /// Triggers the event with a payload that is already shared. Requires `std`.
+    ///
+    /// Handlers subscribed with [`subscribe_shared`](Self::subscribe_shared) receive a
+    /// handle to `args`, which only bumps its reference count, so a large payload is never
+    /// copied for them. The other handlers each get their own clone, as with
+    /// [`invoke`](Self::invoke). On an event created with a [`DeliveryMode`](crate::DeliveryMode)
+    /// other than `Immediate`, the arguments are taken out of the `Arc` and queued as
+    /// `invoke` would.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    /// use std::sync::Arc;
+    ///
+    /// let frame_ready = Event::<Vec<u8>>::new();
+    /// frame_ready.subscribe_shared(|frame: Arc<Vec<u8>>| println!("encoding {} bytes", frame.len()));
+    /// frame_ready.subscribe_shared(|frame: Arc<Vec<u8>>| println!("previewing {} bytes", frame.len()));
+    ///
+    /// let frame = Arc::new(vec![0; 1920 * 1080 * 4]);
+    /// frame_ready.invoke_shared(Arc::clone(&frame)); // The frame is never copied
+    /// ```
+    pub fn invoke_shared(&self, args: Arc<Args>) {
+        if let Some(queue) = &self.inner.queue {
+            queue.enqueue(Arc::unwrap_or_clone(args));
+            return;
+        }
+        let _sharing = Sharing::push(&args);
+        self.dispatch(&args);
+    }
+}
+
+// Makes a payload available to `subscribe_shared` handlers on this thread until dropped.
+struct Sharing;
+
+impl Sharing {
+    fn push<Args: 'static>(args: &Arc<Args>) -> Sharing {
+        SHARING.with_borrow_mut(|sharing| sharing.push(Box::new(Arc::clone(args))));
+        return Sharing;
+    }
+}
+
+impl Drop for Sharing {
+    fn drop(&mut self) {
+        // Dropped outside the borrow, in case the payload's destructor dispatches too.
+        let _payload = SHARING.with_borrow_mut(|sharing| sharing.pop());
+    }
+}
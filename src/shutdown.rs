@@ -0,0 +1,78 @@
+//! A process-wide shutdown event, for subsystems that need to wind down together.
+//!
+//! Every subsystem subscribes to [`requested`], and whoever decides it is time to stop calls
+//! [`request_shutdown`]. With the `signals` feature on Unix, Ctrl-C and `SIGTERM` request a
+//! shutdown too, once `requested` has been called.
+//!
+//! ```
+//! use blob_event::shutdown;
+//!
+//! shutdown::requested().subscribe(|reason| println!("stopping the server: {:?}", reason));
+//!
+//! shutdown::request_shutdown(); // Prints: stopping the server: Requested
+//! assert!(shutdown::is_requested());
+//! ```
+
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::Event;
+
+/// Why a shutdown was requested.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ShutdownReason {
+    /// The process received `SIGINT`, usually from Ctrl-C.
+    Interrupt,
+    /// The process received `SIGTERM`.
+    Terminate,
+    /// [`request_shutdown`] was called.
+    Requested,
+}
+
+static REQUESTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Returns the shutdown event, invoked every time a shutdown is requested.
+///
+/// The first call also starts listening for `SIGINT` and `SIGTERM` when the `signals`
+/// feature is enabled on Unix, which replaces their default action of stopping the process.
+/// A second Ctrl-C doesn't stop it either, so subscribers that want to stop immediately on
+/// a repeated request can check whether [`request_count`] is above 1.
+pub fn requested() -> Event<ShutdownReason> {
+    static EVENT: OnceLock<Event<ShutdownReason>> = OnceLock::new();
+    return EVENT
+        .get_or_init(|| {
+            let event = Event::new();
+            #[cfg(all(feature = "signals", unix))]
+            {
+                let interrupt = event.clone();
+                crate::os_signals::sigint().subscribe(move |()| request(&interrupt, ShutdownReason::Interrupt));
+                let terminate = event.clone();
+                crate::os_signals::sigterm().subscribe(move |()| request(&terminate, ShutdownReason::Terminate));
+            }
+            event
+        })
+        .clone();
+}
+
+/// Requests a shutdown, invoking the shutdown event with [`ShutdownReason::Requested`] on
+/// the calling thread.
+pub fn request_shutdown() {
+    request(&requested(), ShutdownReason::Requested);
+}
+
+/// Returns `true` once a shutdown has been requested.
+pub fn is_requested() -> bool {
+    return request_count() > 0;
+}
+
+/// Returns how many times a shutdown has been requested. The request being dispatched is
+/// already counted, so its subscribers see 1 for the first request.
+pub fn request_count() -> usize {
+    return REQUESTS.load(Ordering::SeqCst);
+}
+
+fn request(event: &Event<ShutdownReason>, reason: ShutdownReason) {
+    REQUESTS.fetch_add(1, Ordering::SeqCst);
+    event.invoke(reason);
+}
@@ -0,0 +1,115 @@
+use alloc::boxed::Box;
+
+use crate::sync::{Lock, Mutex};
+use crate::{Borrowed, Event, EventError, EventHandler, SubscriberLimit, Subscription};
+
+/// An event with at most one subscriber, which receives the arguments by value.
+///
+/// Since no one else needs them, [`invoke`](Self::invoke) moves the arguments into the
+/// handler instead of cloning them, so payloads that can't be cloned, such as sockets or file
+/// handles, can be handed over. Subscribing while a handler is subscribed is refused, as on
+/// an event built with [`max_subscribers`](crate::EventBuilder::max_subscribers) of 1.
+/// Cloning a `SingleConsumerEvent` returns another handle to the same event.
+///
+/// ```
+/// use blob_event::SingleConsumerEvent;
+/// use std::fs::File;
+///
+/// let opened = SingleConsumerEvent::<File>::new();
+/// opened.subscribe(|file: File| println!("took {:?}", file));
+///
+/// if let Ok(file) = File::open("Cargo.toml") {
+///     assert!(opened.invoke(file).is_ok());
+/// }
+/// ```
+pub struct SingleConsumerEvent<Args> {
+    event: Event<Handoff<Args>, Borrowed>,
+}
+
+// The arguments of an invocation, until the handler takes them.
+struct Handoff<Args>(Mutex<Option<Args>>);
+
+struct Consumer<F>(F);
+
+impl<F, Args> EventHandler<Handoff<Args>> for Consumer<F>
+where
+    F: FnMut(Args) + Send,
+{
+    fn call(&mut self, handoff: &Handoff<Args>) {
+        let Some(args) = handoff.0.acquire().take() else {
+            return;
+        };
+        (self.0)(args);
+    }
+}
+
+impl<Args> SingleConsumerEvent<Args> {
+    /// Creates a new event with no subscriber.
+    pub fn new() -> Self {
+        return SingleConsumerEvent {
+            event: Event::builder()
+                .borrowed()
+                .max_subscribers(1, SubscriberLimit::Reject)
+                .build(),
+        };
+    }
+
+    /// Subscribes the consumer of this event.
+    ///
+    /// If the event already has one, the subscription is refused and the returned
+    /// subscription matches no handler; use [`try_subscribe`](Self::try_subscribe) to find
+    /// out.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.event.subscribe_handler(0, Box::new(Consumer(handler)));
+    }
+
+    /// Subscribes the consumer of this event like [`subscribe`](Self::subscribe), but
+    /// returns [`EventError::TooManySubscribers`] if the event already has one.
+    pub fn try_subscribe<F>(&self, handler: F) -> Result<Subscription, EventError>
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.event.try_subscribe_handler(0, Box::new(Consumer(handler)));
+    }
+
+    /// Unsubscribes the consumer, returning `true` if `id` was subscribed. Another one can
+    /// subscribe after that.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.event.unsubscribe(id);
+    }
+
+    /// Returns `true` if the event has a consumer.
+    pub fn has_subscriber(&self) -> bool {
+        return self.event.subscriber_count() > 0;
+    }
+
+    /// Triggers the event, moving `args` into the consumer.
+    ///
+    /// Returns the arguments back as the error if there was no consumer to take them, so
+    /// a resource isn't dropped by accident.
+    pub fn invoke(&self, args: Args) -> Result<(), Args> {
+        let handoff = Handoff(Mutex::new(Some(args)));
+        self.event.invoke(&handoff);
+        if let Some(args) = handoff.0.acquire().take() {
+            return Err(args);
+        }
+        return Ok(());
+    }
+}
+
+impl<Args> Default for SingleConsumerEvent<Args> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<Args> Clone for SingleConsumerEvent<Args> {
+    fn clone(&self) -> Self {
+        return SingleConsumerEvent {
+            event: self.event.clone(),
+        };
+    }
+}
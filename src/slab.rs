@@ -0,0 +1,122 @@
+use alloc::vec::Vec;
+
+use smallvec::SmallVec;
+
+use crate::INLINE_HANDLERS;
+
+// Generational slot storage for an event's handlers. Subscriptions are a slot index plus the
+// slot's generation, so finding a handler is an index and a compare, and a subscription that
+// outlived its handler never matches the one that reuses its slot.
+//
+// Slots are allocated before they are filled, so a handler can be built knowing its own
+// subscription, and interceptors can take subscriptions from the same slots without storing
+// anything in them.
+pub(crate) struct Slab<T> {
+    slots: SmallVec<[Slot<T>; INLINE_HANDLERS]>,
+    // Indices of slots free for reuse.
+    free: SmallVec<[u32; INLINE_HANDLERS]>,
+    len: usize,
+}
+
+struct Slot<T> {
+    // Bumped every time the slot is released.
+    generation: u32,
+    value: Option<T>,
+}
+
+impl<T> Slab<T> {
+    pub(crate) fn with_capacity(capacity: usize) -> Self {
+        Slab {
+            slots: SmallVec::with_capacity(capacity),
+            free: SmallVec::new(),
+            len: 0,
+        }
+    }
+
+    // Reserves an empty slot, returning its index and generation.
+    pub(crate) fn allocate(&mut self) -> (u32, u32) {
+        if let Some(index) = self.free.pop() {
+            return (index, self.slots[index as usize].generation);
+        }
+        let index = u32::try_from(self.slots.len()).expect("too many subscriptions");
+        self.slots.push(Slot {
+            generation: 0,
+            value: None,
+        });
+        return (index, 0);
+    }
+
+    // Fills a slot reserved with `allocate`.
+    pub(crate) fn insert(&mut self, index: u32, value: T) {
+        let slot = &mut self.slots[index as usize];
+        debug_assert!(slot.value.is_none());
+        slot.value = Some(value);
+        self.len += 1;
+    }
+
+    pub(crate) fn get(&self, index: u32, generation: u32) -> Option<&T> {
+        let slot = self.slots.get(index as usize)?;
+        if slot.generation != generation {
+            return None;
+        }
+        return slot.value.as_ref();
+    }
+
+    pub(crate) fn get_mut(&mut self, index: u32, generation: u32) -> Option<&mut T> {
+        let slot = self.slots.get_mut(index as usize)?;
+        if slot.generation != generation {
+            return None;
+        }
+        return slot.value.as_mut();
+    }
+
+    // Empties and releases the slot, returning what it held.
+    pub(crate) fn remove(&mut self, index: u32, generation: u32) -> Option<T> {
+        let slot = self.slots.get_mut(index as usize)?;
+        if slot.generation != generation {
+            return None;
+        }
+        let value = slot.value.take()?;
+        self.len -= 1;
+        self.release(index);
+        return Some(value);
+    }
+
+    // Releases a slot that is empty, making it available to `allocate` again.
+    pub(crate) fn release(&mut self, index: u32) {
+        let generation = &mut self.slots[index as usize].generation;
+        // A slot whose generation would wrap around is retired instead of reused.
+        if let Some(next) = generation.checked_add(1) {
+            *generation = next;
+            self.free.push(index);
+        }
+    }
+
+    // Empties and releases every slot, returning the values in slot order.
+    pub(crate) fn drain(&mut self) -> Vec<T> {
+        let mut values = Vec::with_capacity(self.len);
+        for index in 0..self.slots.len() {
+            if let Some(value) = self.slots[index].value.take() {
+                values.push(value);
+                self.release(index as u32);
+            }
+        }
+        self.len = 0;
+        return values;
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = &T> {
+        return self.slots.iter().filter_map(|slot| slot.value.as_ref());
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        return self.len;
+    }
+
+    // Slots themselves are never freed: each one remembers its generation so stale
+    // subscriptions keep failing to match.
+    pub(crate) fn shrink_to_fit(&mut self) {
+        self.slots.shrink_to_fit();
+        self.free.shrink_to_fit();
+    }
+}
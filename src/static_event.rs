@@ -0,0 +1,170 @@
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::sync::{Lock, Mutex};
+use crate::{EventError, Subscription, next_event_id};
+
+/// A handler of a [`StaticEvent`]: a reference to a function or closure that lives for the
+/// rest of the program, such as a `fn` item or a closure stored in a `static`.
+pub type StaticHandler<Args> = &'static (dyn Fn(&Args) + Sync);
+
+/// An event with room for at most `N` handlers that never allocates.
+///
+/// All storage is inline, so a `StaticEvent` can live in a `static` and be used where memory
+/// use has to be known up front, such as firmware and real-time code. Subscribing beyond the
+/// capacity fails with [`EventError::TooManySubscribers`] instead of growing.
+///
+/// Handlers receive the arguments by reference, like an event in the
+/// [`Borrowed`](crate::Borrowed) mode. Because they are stored as `&'static` references, any
+/// state they use must live in statics, typically atomics or mutexes.
+///
+/// ```
+/// use blob_event::StaticEvent;
+/// use std::sync::atomic::{AtomicU32, Ordering};
+///
+/// static BUTTON_PRESSED: StaticEvent<u8, 4> = StaticEvent::new();
+/// static PRESSES: AtomicU32 = AtomicU32::new(0);
+///
+/// fn count_press(_button: &u8) {
+///     PRESSES.fetch_add(1, Ordering::Relaxed);
+/// }
+///
+/// BUTTON_PRESSED.subscribe(&count_press).unwrap();
+/// BUTTON_PRESSED.invoke(&2);
+/// assert_eq!(PRESSES.load(Ordering::Relaxed), 1);
+/// ```
+pub struct StaticEvent<Args: ?Sized + 'static, const N: usize> {
+    // Assigned on the first subscription, since `new` is `const` and can't draw one.
+    event: AtomicUsize,
+    slots: Mutex<Slots<Args, N>>,
+}
+
+struct Slots<Args: ?Sized + 'static, const N: usize> {
+    handlers: [Option<Slot<Args>>; N],
+    generations: [u32; N],
+}
+
+struct Slot<Args: ?Sized + 'static> {
+    id: Subscription,
+    handler: StaticHandler<Args>,
+}
+
+impl<Args: ?Sized> Clone for Slot<Args> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<Args: ?Sized> Copy for Slot<Args> {}
+
+const UNASSIGNED: usize = usize::MAX;
+
+impl<Args: ?Sized + 'static, const N: usize> StaticEvent<Args, N> {
+    /// Creates an event with no subscribers.
+    pub const fn new() -> Self {
+        StaticEvent {
+            event: AtomicUsize::new(UNASSIGNED),
+            slots: Mutex::new(Slots {
+                handlers: [const { None }; N],
+                generations: [0; N],
+            }),
+        }
+    }
+
+    /// Returns the maximum number of handlers the event can hold.
+    pub const fn capacity(&self) -> usize {
+        N
+    }
+
+    fn event_id(&self) -> usize {
+        let event = self.event.load(Ordering::Acquire);
+        if event != UNASSIGNED {
+            return event;
+        }
+        let fresh = next_event_id();
+        match self
+            .event
+            .compare_exchange(UNASSIGNED, fresh, Ordering::AcqRel, Ordering::Acquire)
+        {
+            Ok(_) => return fresh,
+            Err(assigned) => return assigned,
+        }
+    }
+
+    /// Subscribes a handler to this event.
+    ///
+    /// Returns [`EventError::TooManySubscribers`] if the event already has `N` handlers.
+    pub fn subscribe(&self, handler: StaticHandler<Args>) -> Result<Subscription, EventError> {
+        let event = self.event_id();
+        let mut slots = self.slots.acquire();
+        let Some(index) = slots.handlers.iter().position(Option::is_none) else {
+            return Err(EventError::TooManySubscribers);
+        };
+        let id = Subscription {
+            event,
+            index: index as u32,
+            generation: slots.generations[index],
+        };
+        slots.handlers[index] = Some(Slot { id, handler });
+        return Ok(id);
+    }
+
+    /// Unsubscribes a handler from this event.
+    ///
+    /// Returns `true` if the subscription was found and removed, `false` otherwise.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        let mut slots = self.slots.acquire();
+        let index = id.index as usize;
+        if slots
+            .handlers
+            .get(index)
+            .copied()
+            .flatten()
+            .is_none_or(|slot| slot.id != id)
+        {
+            return false;
+        }
+        slots.handlers[index] = None;
+        slots.generations[index] = slots.generations[index].wrapping_add(1);
+        return true;
+    }
+
+    /// Removes all subscribers from this event.
+    pub fn unsubscribe_all(&self) {
+        let mut slots = self.slots.acquire();
+        for index in 0..N {
+            if slots.handlers[index].take().is_some() {
+                slots.generations[index] = slots.generations[index].wrapping_add(1);
+            }
+        }
+    }
+
+    /// Returns the current number of subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        let slots = self.slots.acquire();
+        return slots.handlers.iter().filter(|slot| slot.is_some()).count();
+    }
+
+    /// Triggers the event, calling all subscribed handlers with a reference to `args`.
+    ///
+    /// Handlers run in the order of their slots, which is the order they subscribed in until a
+    /// removed handler's slot is reused. The handlers are copied to the stack before any of
+    /// them runs, so handlers may subscribe and unsubscribe during the dispatch. As with
+    /// [`Event`](crate::Event), handlers removed during the dispatch are not called if they
+    /// haven't run yet.
+    pub fn invoke(&self, args: &Args) {
+        let snapshot = self.slots.acquire().handlers;
+        for slot in snapshot.iter().flatten() {
+            let subscribed =
+                self.slots.acquire().handlers[slot.id.index as usize].is_some_and(|current| current.id == slot.id);
+            if subscribed {
+                (slot.handler)(args);
+            }
+        }
+    }
+}
+
+impl<Args: ?Sized + 'static, const N: usize> Default for StaticEvent<Args, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,151 @@
+#[cfg(feature = "prometheus")]
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+#[cfg(feature = "prometheus")]
+use crate::prometheus::EventMetrics;
+
+use crate::{Event, Subscription};
+
+/// Counters collected by an event built with
+/// [`EventBuilder::collect_stats`](crate::EventBuilder::collect_stats), as returned by
+/// [`Event::stats`].
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub struct EventStats {
+    /// How many times the event has been invoked. Each item of an `invoke_batch` counts.
+    pub invocations: u64,
+    /// The counters of every current subscription, in dispatch order. Counters of removed
+    /// subscriptions are dropped with them.
+    pub handlers: Vec<HandlerStats>,
+}
+
+/// The counters of one subscription, part of [`EventStats`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct HandlerStats {
+    /// The subscription these counters belong to.
+    pub subscription: Subscription,
+    /// How many times its handler has been called.
+    pub calls: u64,
+    /// How long its handler has run in total.
+    pub total_duration: Duration,
+}
+
+impl HandlerStats {
+    /// Returns the mean time a call to the handler took, or `None` if it was never called.
+    pub fn average_duration(&self) -> Option<Duration> {
+        return average(self.total_duration, self.calls);
+    }
+}
+
+impl EventStats {
+    /// Returns how long the current subscriptions' handlers have run in total.
+    pub fn total_handler_duration(&self) -> Duration {
+        return self.handlers.iter().map(|handler| handler.total_duration).sum();
+    }
+
+    /// Returns the mean time a handler call took across the current subscriptions, or `None`
+    /// if none of them was called.
+    pub fn average_handler_duration(&self) -> Option<Duration> {
+        let calls = self.handlers.iter().map(|handler| handler.calls).sum();
+        return average(self.total_handler_duration(), calls);
+    }
+}
+
+fn average(total: Duration, calls: u64) -> Option<Duration> {
+    if calls == 0 {
+        return None;
+    }
+    return Some(Duration::from_nanos((total.as_nanos() / u128::from(calls)) as u64));
+}
+
+// The counters of an event, kept next to its subscription list.
+pub(crate) struct EventCounters {
+    pub(crate) enabled: bool,
+    invocations: AtomicU64,
+    // Set once the event is exported to Prometheus.
+    #[cfg(feature = "prometheus")]
+    pub(crate) metrics: OnceLock<EventMetrics>,
+}
+
+impl EventCounters {
+    pub(crate) fn new(enabled: bool) -> Self {
+        EventCounters {
+            enabled,
+            invocations: AtomicU64::new(0),
+            #[cfg(feature = "prometheus")]
+            metrics: OnceLock::new(),
+        }
+    }
+
+    pub(crate) fn record_invocations(&self, count: usize) {
+        if self.enabled {
+            self.invocations.fetch_add(count as u64, Ordering::Relaxed);
+        }
+        #[cfg(feature = "prometheus")]
+        if let Some(metrics) = self.metrics.get() {
+            metrics.record_invocations(count);
+        }
+    }
+
+    // Called whenever the subscription list changes.
+    pub(crate) fn record_subscribers(&self, _count: usize) {
+        #[cfg(feature = "prometheus")]
+        if let Some(metrics) = self.metrics.get() {
+            metrics.record_subscribers(_count);
+        }
+    }
+}
+
+// The counters of one subscription, kept in its handler's cell.
+#[derive(Default)]
+pub(crate) struct CallCounters {
+    calls: AtomicU64,
+    nanos: AtomicU64,
+}
+
+impl CallCounters {
+    pub(crate) fn record(&self, elapsed: Duration) {
+        self.calls.fetch_add(1, Ordering::Relaxed);
+        self.nanos.fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+
+    // Counters starting from the current values, for a subscription that changes cells.
+    pub(crate) fn carry_over(&self) -> Self {
+        CallCounters {
+            calls: AtomicU64::new(self.calls.load(Ordering::Relaxed)),
+            nanos: AtomicU64::new(self.nanos.load(Ordering::Relaxed)),
+        }
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    /// Returns the invocation count and per-subscription counters of an event built with
+    /// [`EventBuilder::collect_stats`](crate::EventBuilder::collect_stats), or `None` for
+    /// other events.
+    ///
+    /// Use it to find out which handler is hot without reaching for a profiler. Invocations
+    /// are counted when they are dispatched, so queued ones count once their turn comes.
+    pub fn stats(&self) -> Option<EventStats> {
+        let counters = &self.inner.counters;
+        if !counters.enabled {
+            return None;
+        }
+        let snapshot = self.inner.snapshot.load();
+        let handlers = snapshot
+            .cells
+            .iter()
+            .map(|cell| HandlerStats {
+                subscription: cell.id,
+                calls: cell.counters.calls.load(Ordering::Relaxed),
+                total_duration: Duration::from_nanos(cell.counters.nanos.load(Ordering::Relaxed)),
+            })
+            .collect();
+        Some(EventStats {
+            invocations: counters.invocations.load(Ordering::Relaxed),
+            handlers,
+        })
+    }
+}
@@ -0,0 +1,170 @@
+// The locking primitives used for all of the crate's event state.
+//
+// Every mutex in the crate goes through the `Lock` trait, so the event logic is written once
+// and the backend is picked with features:
+//
+// - `std::sync::Mutex` by default
+// - `parking_lot::Mutex` with the `parking_lot` feature
+// - the crate's own spin lock with the `spin` feature, or whenever `std` is disabled
+//
+// A lock whose holder panicked is recovered rather than propagating the panic to every later
+// caller. `std` marks such a lock as poisoned; `acquire` clears the mark, while
+// `acquire_unpoisoned` reports it so the fallible API can surface it instead. The other
+// backends never poison.
+
+use alloc::sync::Arc;
+use core::ops::DerefMut;
+
+#[cfg(any(feature = "spin", not(feature = "std")))]
+pub(crate) type Mutex<T> = crate::spin::Mutex<T>;
+
+#[cfg(all(feature = "parking_lot", not(feature = "spin")))]
+pub(crate) type Mutex<T> = parking_lot::Mutex<T>;
+
+#[cfg(all(feature = "std", not(feature = "parking_lot"), not(feature = "spin")))]
+pub(crate) type Mutex<T> = std::sync::Mutex<T>;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+// The interface the crate needs from a mutex. It is sealed so that only the backends listed
+// above can implement it.
+pub(crate) trait Lock<T>: sealed::Sealed {
+    type Guard<'a>: DerefMut<Target = T>
+    where
+        Self: 'a;
+
+    fn acquire(&self) -> Self::Guard<'_>;
+
+    // Like `acquire`, but returns `None` instead of recovering if the lock is poisoned.
+    fn acquire_unpoisoned(&self) -> Option<Self::Guard<'_>>;
+
+    // Returns `None` only if the lock is currently held.
+    fn try_acquire(&self) -> Option<Self::Guard<'_>>;
+}
+
+#[cfg(all(feature = "std", not(feature = "parking_lot"), not(feature = "spin")))]
+impl<T> sealed::Sealed for std::sync::Mutex<T> {}
+
+#[cfg(all(feature = "std", not(feature = "parking_lot"), not(feature = "spin")))]
+impl<T> Lock<T> for std::sync::Mutex<T> {
+    type Guard<'a>
+        = std::sync::MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    fn acquire(&self) -> Self::Guard<'_> {
+        return self.lock().unwrap_or_else(|poisoned| {
+            self.clear_poison();
+            poisoned.into_inner()
+        });
+    }
+
+    fn acquire_unpoisoned(&self) -> Option<Self::Guard<'_>> {
+        return self.lock().ok();
+    }
+
+    fn try_acquire(&self) -> Option<Self::Guard<'_>> {
+        match self.try_lock() {
+            Ok(guard) => Some(guard),
+            Err(std::sync::TryLockError::Poisoned(poisoned)) => Some(poisoned.into_inner()),
+            Err(std::sync::TryLockError::WouldBlock) => None,
+        }
+    }
+}
+
+#[cfg(all(feature = "parking_lot", not(feature = "spin")))]
+impl<T> sealed::Sealed for parking_lot::Mutex<T> {}
+
+#[cfg(all(feature = "parking_lot", not(feature = "spin")))]
+impl<T> Lock<T> for parking_lot::Mutex<T> {
+    type Guard<'a>
+        = parking_lot::MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    fn acquire(&self) -> Self::Guard<'_> {
+        return self.lock();
+    }
+
+    fn acquire_unpoisoned(&self) -> Option<Self::Guard<'_>> {
+        return Some(self.lock());
+    }
+
+    fn try_acquire(&self) -> Option<Self::Guard<'_>> {
+        return self.try_lock();
+    }
+}
+
+#[cfg(any(feature = "spin", not(feature = "std")))]
+impl<T> sealed::Sealed for crate::spin::Mutex<T> {}
+
+#[cfg(any(feature = "spin", not(feature = "std")))]
+impl<T> Lock<T> for crate::spin::Mutex<T> {
+    type Guard<'a>
+        = crate::spin::MutexGuard<'a, T>
+    where
+        T: 'a;
+
+    fn acquire(&self) -> Self::Guard<'_> {
+        return self.lock();
+    }
+
+    fn acquire_unpoisoned(&self) -> Option<Self::Guard<'_>> {
+        return Some(self.lock());
+    }
+
+    fn try_acquire(&self) -> Option<Self::Guard<'_>> {
+        return self.try_lock();
+    }
+}
+
+// A value that is replaced as a whole and read far more often than it is replaced. With `std`
+// it is an `ArcSwap`, so reading never takes a lock; without it, reads briefly take a lock to
+// clone the `Arc`.
+#[cfg(feature = "std")]
+pub(crate) struct Published<T>(arc_swap::ArcSwap<T>);
+
+#[cfg(not(feature = "std"))]
+pub(crate) struct Published<T>(Mutex<Arc<T>>);
+
+#[cfg(feature = "std")]
+pub(crate) type Loaded<T> = arc_swap::Guard<Arc<T>>;
+
+#[cfg(not(feature = "std"))]
+pub(crate) type Loaded<T> = Arc<T>;
+
+impl<T> Published<T> {
+    #[cfg(feature = "std")]
+    pub(crate) fn new(value: T) -> Self {
+        Published(arc_swap::ArcSwap::from_pointee(value))
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn new(value: T) -> Self {
+        Published(Mutex::new(Arc::new(value)))
+    }
+
+    #[cfg(feature = "std")]
+    pub(crate) fn load(&self) -> Loaded<T> {
+        return self.0.load();
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn load(&self) -> Loaded<T> {
+        return Arc::clone(&self.0.acquire());
+    }
+
+    // Publishes `value`, returning the previous value. Dropping it may drop handlers, so
+    // callers hold on to it until they have released their own locks.
+    #[cfg(feature = "std")]
+    pub(crate) fn swap(&self, value: T) -> Arc<T> {
+        return self.0.swap(Arc::new(value));
+    }
+
+    #[cfg(not(feature = "std"))]
+    pub(crate) fn swap(&self, value: T) -> Arc<T> {
+        return core::mem::replace(&mut *self.0.acquire(), Arc::new(value));
+    }
+}
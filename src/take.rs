@@ -0,0 +1,66 @@
+use alloc::boxed::Box;
+
+use crate::{Borrowed, Cloned, Event, HandlerCell, Subscription};
+
+// A handler of a `Borrowed` event, as handed back by `take_handler`.
+type BorrowedHandler<Args> = Box<dyn FnMut(&Args) + Send>;
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    // Unsubscribes `id`, returning its cell. The cell is inactive, so dispatches that still
+    // hold it skip it, but its handler can be called directly.
+    fn take_cell(&self, id: Subscription) -> Option<HandlerCell<Args>> {
+        yield_point!(BeforeUnsubscribe);
+        let cell = self.inner.update(|handlers| handlers.remove_cell(id))?;
+        self.inner.notify(|hooks| hooks.on_unsubscribe(id));
+        return Some(cell);
+    }
+}
+
+impl<Args: Clone + 'static> Event<Args, Cloned> {
+    /// Unsubscribes `id` and returns its handler, or `None` if `id` isn't subscribed to this
+    /// event.
+    ///
+    /// The handler keeps its state, so it can be subscribed to another event or called
+    /// directly, for example to migrate subscribers while reconfiguring. Like
+    /// [`unsubscribe`](Self::unsubscribe), this never waits for the handler: if it is
+    /// running, calling the returned handler waits for that call to return first.
+    ///
+    /// The returned closure only calls the handler. Its return value, if it was subscribed with
+    /// [`subscribe_ctl`](Self::subscribe_ctl), is dropped, so it can't unsubscribe itself or
+    /// stop propagation anymore, and the error and close callbacks of
+    /// [`subscribe_with_error`](Self::subscribe_with_error) and
+    /// [`subscribe_with_close`](Self::subscribe_with_close) are never run. The handler also
+    /// gets its own clone of the arguments the closure is called with, so subscribing the
+    /// closure to another event clones them twice per invocation.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// let old_bus = Event::<String>::new();
+    /// let new_bus = Event::<String>::new();
+    /// let logger = old_bus.subscribe(|msg| println!("log: {}", msg));
+    ///
+    /// let handler = old_bus.take_handler(logger).unwrap();
+    /// new_bus.subscribe(handler);
+    /// new_bus.invoke("moved".to_string()); // Prints: log: moved
+    /// ```
+    pub fn take_handler(&self, id: Subscription) -> Option<Box<dyn FnMut(Args) + Send>> {
+        let cell = self.take_cell(id)?;
+        return Some(Box::new(move |args: Args| {
+            cell.with_handler(|handler| handler.call(&args))
+        }));
+    }
+}
+
+impl<Args: ?Sized + 'static> Event<Args, Borrowed> {
+    /// Unsubscribes `id` and returns its handler, or `None` if `id` isn't subscribed to this
+    /// event.
+    ///
+    /// See [`Event::take_handler`], including what the returned closure no longer does.
+    pub fn take_handler(&self, id: Subscription) -> Option<BorrowedHandler<Args>> {
+        let cell = self.take_cell(id)?;
+        return Some(Box::new(move |args: &Args| {
+            cell.with_handler(|handler| handler.call(args))
+        }));
+    }
+}
@@ -0,0 +1,250 @@
+//! Utilities for testing code that uses events.
+//!
+//! [`Event::spy`](crate::Event::spy) returns a [`Spy`] that records an event's dispatches and
+//! asserts on them, which saves writing the same recording closure in every test.
+//! [`ManualScheduler`] is a virtual clock for testing time-based features without sleeping.
+//!
+//! Events pass through a [`YieldPoint`] every time they are about to take one of their
+//! internal locks. Threads started through a [`Schedule`] stop at each yield point until the
+//! schedule gives them the turn, so a specific interleaving of concurrent subscribe,
+//! unsubscribe, and invoke calls can be reproduced on every run.
+//!
+//! ```
+//! use blob_event::Event;
+//! use blob_event::testing::Schedule;
+//! use std::sync::Arc;
+//! use std::sync::atomic::{AtomicUsize, Ordering};
+//!
+//! let event = Event::<()>::new();
+//! let calls = Arc::new(AtomicUsize::new(0));
+//! let calls_clone = Arc::clone(&calls);
+//! let sub = event.subscribe(move |()| {
+//!     calls_clone.fetch_add(1, Ordering::SeqCst);
+//! });
+//!
+//! // The invoking thread takes its snapshot, then the other thread unsubscribes before the
+//! // handler is reached.
+//! let mut schedule = Schedule::new(["invoke", "unsubscribe", "invoke"]);
+//! let invoking = event.clone();
+//! schedule.spawn("invoke", move || invoking.invoke(()));
+//! let unsubscribing = event.clone();
+//! schedule.spawn("unsubscribe", move || {
+//!     unsubscribing.unsubscribe(sub);
+//! });
+//! schedule.join();
+//!
+//! assert_eq!(calls.load(Ordering::SeqCst), 0);
+//! ```
+
+use std::cell::RefCell;
+use std::collections::{HashSet, VecDeque};
+use std::panic;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+
+mod scheduler;
+mod spy;
+
+pub use scheduler::ManualScheduler;
+pub use spy::Spy;
+
+/// A place where an event is about to take one of its internal locks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum YieldPoint {
+    /// A subscription is about to be added.
+    BeforeSubscribe,
+    /// A subscription, or all of them, is about to be removed.
+    BeforeUnsubscribe,
+    /// An invoke is about to take its snapshot of the subscribed handlers.
+    BeforeSnapshot,
+    /// An invoke is about to run the next handler from its snapshot.
+    BeforeHandler,
+}
+
+/// Runs a set of named threads in a fixed interleaving.
+///
+/// The schedule is a list of thread names. Each time a scheduled thread reaches a
+/// [`YieldPoint`] it waits until its name is at the front of the list, then keeps the turn
+/// until it reaches its next yield point or finishes, at which point its name is removed.
+/// Names of threads that have already finished are skipped, and once the list is exhausted
+/// every thread runs freely.
+///
+/// A scheduled thread must not reach a yield point while holding a lock another scheduled
+/// thread needs, such as when a handler invokes another event, or the schedule can deadlock.
+pub struct Schedule {
+    state: Arc<ScheduleState>,
+    handles: Vec<JoinHandle<()>>,
+}
+
+struct ScheduleState {
+    inner: Mutex<ScheduleInner>,
+    changed: Condvar,
+}
+
+struct ScheduleInner {
+    steps: VecDeque<String>,
+    // Threads that have been spawned and haven't finished yet.
+    alive: HashSet<String>,
+    started: bool,
+    // The thread whose turn it is. Its turn lasts until it reaches its next yield point or
+    // finishes, so the next thread doesn't start while it is still between two yield points.
+    holder: Option<String>,
+    trace: Vec<(String, YieldPoint)>,
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<(Arc<ScheduleState>, String)>> = const { RefCell::new(None) };
+}
+
+impl Schedule {
+    /// Creates a schedule that hands out turns in the order of `steps`.
+    pub fn new<I, S>(steps: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Schedule {
+            state: Arc::new(ScheduleState {
+                inner: Mutex::new(ScheduleInner {
+                    steps: steps.into_iter().map(Into::into).collect(),
+                    alive: HashSet::new(),
+                    started: false,
+                    holder: None,
+                    trace: Vec::new(),
+                }),
+                changed: Condvar::new(),
+            }),
+            handles: Vec::new(),
+        }
+    }
+
+    /// Spawns a thread named `name` that runs `body` under this schedule.
+    ///
+    /// The thread doesn't start running until [`Schedule::join`] is called, so every thread
+    /// is registered before the first turn is handed out.
+    pub fn spawn<F>(&mut self, name: &str, body: F)
+    where
+        F: FnOnce() + Send + 'static,
+    {
+        let name = name.to_string();
+        self.state.inner.lock().unwrap().alive.insert(name.clone());
+
+        let state = Arc::clone(&self.state);
+        let handle = thread::Builder::new()
+            .name(name.clone())
+            .spawn(move || {
+                // Marks the thread as finished even if `body` panics, so the others don't wait
+                // for turns it will never take.
+                let _finished = Finished {
+                    state: Arc::clone(&state),
+                    name: name.clone(),
+                };
+                state.wait_until_started();
+                CURRENT.with(|current| *current.borrow_mut() = Some((state, name)));
+                body();
+            })
+            .expect("failed to spawn scheduled thread");
+        self.handles.push(handle);
+    }
+
+    /// Starts every spawned thread and waits for all of them to finish.
+    ///
+    /// Returns the yield points the threads passed through, in the order they passed them.
+    /// If any thread panicked, the panic is propagated after all threads have finished.
+    pub fn join(mut self) -> Vec<(String, YieldPoint)> {
+        self.state.start();
+
+        let mut panicked = None;
+        for handle in std::mem::take(&mut self.handles) {
+            if let Err(payload) = handle.join() {
+                panicked.get_or_insert(payload);
+            }
+        }
+        if let Some(payload) = panicked {
+            panic::resume_unwind(payload);
+        }
+
+        let mut inner = self.state.inner.lock().unwrap();
+        return std::mem::take(&mut inner.trace);
+    }
+}
+
+impl Drop for Schedule {
+    // A schedule dropped without being joined still lets its threads run, rather than
+    // leaving them parked forever.
+    fn drop(&mut self) {
+        self.state.start();
+    }
+}
+
+impl ScheduleState {
+    fn start(&self) {
+        let mut inner = self.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        inner.started = true;
+        self.changed.notify_all();
+    }
+
+    fn wait_until_started(&self) {
+        let mut inner = self.inner.lock().unwrap();
+        while !inner.started {
+            inner = self.changed.wait(inner).unwrap();
+        }
+    }
+
+    fn take_turn(&self, name: &str, point: YieldPoint) {
+        let mut inner = self.inner.lock().unwrap();
+        inner.end_turn(name);
+        self.changed.notify_all();
+        loop {
+            while let Some(front) = inner.steps.front()
+                && !inner.alive.contains(front)
+            {
+                inner.steps.pop_front();
+            }
+            match inner.steps.front() {
+                Some(front) if front != name => inner = self.changed.wait(inner).unwrap(),
+                front => {
+                    if front.is_some() {
+                        inner.holder = Some(name.to_string());
+                    }
+                    inner.trace.push((name.to_string(), point));
+                    self.changed.notify_all();
+                    return;
+                }
+            }
+        }
+    }
+}
+
+impl ScheduleInner {
+    fn end_turn(&mut self, name: &str) {
+        if self.holder.as_deref() == Some(name) {
+            self.holder = None;
+            self.steps.pop_front();
+        }
+    }
+}
+
+struct Finished {
+    state: Arc<ScheduleState>,
+    name: String,
+}
+
+impl Drop for Finished {
+    fn drop(&mut self) {
+        let mut inner = self.state.inner.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        inner.end_turn(&self.name);
+        inner.alive.remove(&self.name);
+        self.state.changed.notify_all();
+    }
+}
+
+// Called by events at every yield point. Threads not started through a `Schedule` pass
+// straight through.
+pub(crate) fn yield_point(point: YieldPoint) {
+    let current = CURRENT.with(|current| current.borrow().clone());
+    if let Some((state, name)) = current {
+        state.take_turn(&name, point);
+    }
+}
@@ -0,0 +1,81 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::timer::{Scheduler, Task, TimerQueue};
+
+/// A [`Scheduler`] whose clock only moves when told to, for testing time-based features
+/// without sleeping.
+///
+/// Install it with [`with_scheduler`](crate::with_scheduler) while setting up the feature
+/// under test, then call [`advance`](Self::advance) to move the clock forward. Timers that
+/// come due run on the thread calling `advance`, in deadline order, before it returns, so
+/// tests are deterministic and take no real time.
+///
+/// Clones share the same clock.
+#[derive(Clone)]
+pub struct ManualScheduler {
+    state: Arc<Mutex<ManualState>>,
+}
+
+struct ManualState {
+    now: Instant,
+    queue: TimerQueue,
+}
+
+impl ManualScheduler {
+    /// Creates a scheduler whose clock starts at the current time and stands still.
+    pub fn new() -> Self {
+        ManualScheduler {
+            state: Arc::new(Mutex::new(ManualState {
+                now: Instant::now(),
+                queue: TimerQueue::default(),
+            })),
+        }
+    }
+
+    /// Moves the clock forward by `by`, running every timer that comes due on the way.
+    ///
+    /// Timers see the clock at their own deadline while they run, and timers they schedule
+    /// run in the same call if they come due before its end.
+    pub fn advance(&self, by: Duration) {
+        let target = self.state.lock().unwrap().now + by;
+        loop {
+            let due = {
+                let mut state = self.state.lock().unwrap();
+                let due = state.queue.pop_due(target);
+                if let Some((deadline, _)) = &due {
+                    state.now = state.now.max(*deadline);
+                }
+                due
+            };
+            // Tasks run without the state locked so they can schedule follow-ups.
+            match due {
+                Some((_, task)) => task(),
+                None => break,
+            }
+        }
+        let mut state = self.state.lock().unwrap();
+        state.now = state.now.max(target);
+    }
+
+    /// Returns how many timers are waiting for the clock to reach their deadline.
+    pub fn pending(&self) -> usize {
+        return self.state.lock().unwrap().queue.len();
+    }
+}
+
+impl Scheduler for ManualScheduler {
+    fn now(&self) -> Instant {
+        return self.state.lock().unwrap().now;
+    }
+
+    fn schedule(&self, deadline: Instant, task: Task) {
+        self.state.lock().unwrap().queue.push(deadline, task);
+    }
+}
+
+impl Default for ManualScheduler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
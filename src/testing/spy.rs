@@ -0,0 +1,106 @@
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+
+use crate::{Borrowed, Cloned, Event};
+
+/// Records every dispatch of an event for assertions in tests, as returned by
+/// [`Event::spy`].
+///
+/// The spy watches the event through a [mirror](Event::mirror), so it doesn't count as a
+/// subscriber and sees each dispatch after the event's handlers have run. It stops recording
+/// when dropped.
+///
+/// ```
+/// use blob_event::Event;
+///
+/// let saved = Event::<String>::new();
+/// let spy = saved.spy();
+///
+/// saved.invoke("draft.txt".to_string());
+/// saved.invoke("final.txt".to_string());
+///
+/// spy.assert_invoked_times(2);
+/// spy.assert_last_args(&"final.txt".to_string());
+/// ```
+pub struct Spy<Args, D = Cloned> {
+    received: Arc<Mutex<Vec<Args>>>,
+    _tap: Event<Args, D>,
+}
+
+impl<Args: Clone + Send + 'static> Event<Args, Cloned> {
+    /// Returns a [`Spy`] that records every dispatch of this event from now on.
+    pub fn spy(&self) -> Spy<Args, Cloned> {
+        let tap = self.mirror();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&received);
+        tap.subscribe(move |args| log.lock().unwrap().push(args));
+        Spy { received, _tap: tap }
+    }
+}
+
+impl<Args: Clone + Send + 'static> Event<Args, Borrowed> {
+    /// Returns a [`Spy`] that records a clone of the arguments of every dispatch of this
+    /// event from now on.
+    pub fn spy(&self) -> Spy<Args, Borrowed> {
+        let tap = self.mirror();
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let log = Arc::clone(&received);
+        tap.subscribe(move |args: &Args| log.lock().unwrap().push(args.clone()));
+        Spy { received, _tap: tap }
+    }
+}
+
+impl<Args: Clone, D> Spy<Args, D> {
+    /// Returns the arguments of every dispatch recorded so far, in order.
+    pub fn received(&self) -> Vec<Args> {
+        return self.received.lock().unwrap().clone();
+    }
+
+    /// Returns the arguments of the latest recorded dispatch, if any.
+    pub fn last(&self) -> Option<Args> {
+        return self.received.lock().unwrap().last().cloned();
+    }
+
+    /// Returns how many dispatches have been recorded.
+    pub fn count(&self) -> usize {
+        return self.received.lock().unwrap().len();
+    }
+
+    /// Forgets every recorded dispatch.
+    pub fn clear(&self) {
+        self.received.lock().unwrap().clear();
+    }
+
+    /// Panics unless exactly `times` dispatches have been recorded.
+    #[track_caller]
+    pub fn assert_invoked_times(&self, times: usize) {
+        let count = self.count();
+        assert!(
+            count == times,
+            "expected the event to be invoked {} times, but it was invoked {} times",
+            times,
+            count
+        );
+    }
+
+    /// Panics if any dispatch has been recorded.
+    #[track_caller]
+    pub fn assert_not_invoked(&self) {
+        self.assert_invoked_times(0);
+    }
+
+    /// Panics unless the latest recorded dispatch had arguments equal to `expected`.
+    #[track_caller]
+    pub fn assert_last_args(&self, expected: &Args)
+    where
+        Args: PartialEq + Debug,
+    {
+        match self.last() {
+            Some(last) => assert_eq!(&last, expected, "the event was last invoked with other arguments"),
+            None => panic!(
+                "expected the event to be invoked with {:?}, but it was never invoked",
+                expected
+            ),
+        }
+    }
+}
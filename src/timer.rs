@@ -0,0 +1,207 @@
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Condvar, Mutex, OnceLock};
+use std::thread;
+use std::time::Instant;
+
+/// A task handed to a [`Scheduler`].
+pub type Task = Box<dyn FnOnce() + Send>;
+
+/// The clock and timer behind the crate's time-based features: the latency of
+/// [batched subscriptions](crate::Event::subscribe_batched), which
+/// [`buffered_by_time`](crate::Event::buffered_by_time) is built on,
+/// [handler time limits](crate::EventBuilder::handler_timeout), and
+/// [time-limited subscriptions](crate::Event::subscribe_for).
+///
+/// By default these run on a background thread shared by the whole crate. Code run inside
+/// [`with_scheduler`] uses another scheduler instead, such as the virtual clock of
+/// `testing::ManualScheduler`, which lets tests advance time rather than sleep.
+pub trait Scheduler: Send + Sync {
+    /// Returns the current time on this scheduler's clock.
+    fn now(&self) -> Instant;
+
+    /// Runs `task` once this scheduler's clock has reached `deadline`. Tasks with the same
+    /// deadline run in the order they were scheduled.
+    fn schedule(&self, deadline: Instant, task: Task);
+}
+
+// The crate's shared timer thread, as a `Scheduler`.
+struct SharedTimer;
+
+impl Scheduler for SharedTimer {
+    fn now(&self) -> Instant {
+        return Instant::now();
+    }
+
+    fn schedule(&self, deadline: Instant, task: Task) {
+        let timer = timer();
+        timer.queue.lock().unwrap().push(deadline, task);
+        timer.wakeup.notify_one();
+    }
+}
+
+thread_local! {
+    static CURRENT: RefCell<Option<Arc<dyn Scheduler>>> = const { RefCell::new(None) };
+}
+
+/// Runs `body` with `scheduler` driving the time-based features it sets up.
+///
+/// The scheduler is picked when a feature is set up, not when its timers fire: a batched
+/// subscription made inside `body` keeps using `scheduler` for its latency afterwards, and so
+/// does an event with a handler time limit created inside it. Only the calling thread is
+/// affected, and calls can be nested.
+///
+/// ```
+/// use std::time::Duration;
+/// use blob_event::{Event, with_scheduler};
+/// use blob_event::testing::ManualScheduler;
+///
+/// let clock = ManualScheduler::new();
+/// let clicks = Event::<u32>::new();
+/// let batches = with_scheduler(clock.clone(), || clicks.buffered_by_time(Duration::from_secs(1)));
+/// let spy = batches.spy();
+///
+/// clicks.invoke(1);
+/// clock.advance(Duration::from_millis(999));
+/// spy.assert_not_invoked();
+/// clock.advance(Duration::from_millis(1));
+/// spy.assert_last_args(&vec![1]);
+/// ```
+pub fn with_scheduler<S, R>(scheduler: S, body: impl FnOnce() -> R) -> R
+where
+    S: Scheduler + 'static,
+{
+    // Restores the previous scheduler even if `body` panics.
+    struct Restore(Option<Arc<dyn Scheduler>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            let previous = self.0.take();
+            CURRENT.with(|current| *current.borrow_mut() = previous);
+        }
+    }
+
+    let scheduler: Arc<dyn Scheduler> = Arc::new(scheduler);
+    let previous = CURRENT.with(|current| current.borrow_mut().replace(scheduler));
+    let _restore = Restore(previous);
+    return body();
+}
+
+// Returns the scheduler that time-based features set up on this thread should use.
+pub(crate) fn current() -> Arc<dyn Scheduler> {
+    static SHARED: OnceLock<Arc<dyn Scheduler>> = OnceLock::new();
+    if let Some(scheduler) = CURRENT.with(|current| current.borrow().clone()) {
+        return scheduler;
+    }
+    return Arc::clone(SHARED.get_or_init(|| Arc::new(SharedTimer)));
+}
+
+// A single background thread shared by every time-based feature of the crate. Tasks run on
+// that thread once their deadline has passed, in deadline order.
+struct Timer {
+    queue: Mutex<TimerQueue>,
+    wakeup: Condvar,
+}
+
+// Tasks waiting for their deadline, earliest first.
+#[derive(Default)]
+pub(crate) struct TimerQueue {
+    entries: BinaryHeap<Entry>,
+    next_seq: u64,
+}
+
+struct Entry {
+    deadline: Instant,
+    // Keeps tasks with the same deadline in the order they were scheduled.
+    seq: u64,
+    task: Task,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    // Reversed so the BinaryHeap pops the earliest deadline first.
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .deadline
+            .cmp(&self.deadline)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+impl TimerQueue {
+    pub(crate) fn push(&mut self, deadline: Instant, task: Task) {
+        let seq = self.next_seq;
+        self.next_seq += 1;
+        self.entries.push(Entry { deadline, seq, task });
+    }
+
+    // Removes and returns the earliest task if its deadline is no later than `now`, with
+    // that deadline.
+    pub(crate) fn pop_due(&mut self, now: Instant) -> Option<(Instant, Task)> {
+        if self.entries.peek()?.deadline > now {
+            return None;
+        }
+        let entry = self.entries.pop()?;
+        return Some((entry.deadline, entry.task));
+    }
+
+    #[cfg(feature = "testing")]
+    pub(crate) fn len(&self) -> usize {
+        return self.entries.len();
+    }
+}
+
+fn timer() -> &'static Timer {
+    static TIMER: OnceLock<&'static Timer> = OnceLock::new();
+    TIMER.get_or_init(|| {
+        let timer: &'static Timer = Box::leak(Box::new(Timer {
+            queue: Mutex::new(TimerQueue::default()),
+            wakeup: Condvar::new(),
+        }));
+        thread::Builder::new()
+            .name("blob-event-timer".to_string())
+            .spawn(move || timer.run())
+            .expect("failed to spawn the blob-event timer thread");
+        timer
+    })
+}
+
+impl Timer {
+    fn run(&self) {
+        let mut queue = self.queue.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            if let Some((_, task)) = queue.pop_due(now) {
+                // Tasks run without the queue locked so they can schedule follow-ups. A task
+                // that panics, such as a batch handler, doesn't take the thread and every other
+                // task down with it.
+                drop(queue);
+                let _ = panic::catch_unwind(AssertUnwindSafe(task));
+                queue = self.queue.lock().unwrap();
+                continue;
+            }
+            match queue.entries.peek() {
+                None => queue = self.wakeup.wait(queue).unwrap(),
+                Some(entry) => {
+                    let timeout = entry.deadline - now;
+                    queue = self.wakeup.wait_timeout(queue, timeout).unwrap().0;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,85 @@
+use alloc::boxed::Box;
+
+use crate::{Borrowed, ByClone, Cloned, Event, EventHandler, HandlerControl, Subscription};
+
+// Adapts a handler that unsubscribes itself after running a given number of times. The
+// count is kept under the handler's lock, so concurrent dispatches never run it once too
+// often.
+struct Limited<H> {
+    handler: H,
+    remaining: usize,
+}
+
+impl<H, Args> EventHandler<Args> for Limited<H>
+where
+    H: EventHandler<Args>,
+    Args: ?Sized,
+{
+    fn call(&mut self, args: &Args) {
+        let _ = self.call_ctl(args);
+    }
+
+    fn call_ctl(&mut self, args: &Args) -> HandlerControl {
+        if self.remaining == 0 {
+            return HandlerControl::Unsubscribe;
+        }
+        self.remaining -= 1;
+        let control = self.handler.call_ctl(args);
+        if self.remaining == 0 {
+            return HandlerControl::Unsubscribe;
+        }
+        return control;
+    }
+}
+
+impl<Args: Clone + 'static> Event<Args, Cloned> {
+    /// Subscribes a callback that is unsubscribed on its own after it has run `times` times,
+    /// generalizing a one-shot handler.
+    ///
+    /// The count is exact even when the event is invoked from several threads at once: the
+    /// callback never runs more than `times` times. With `times` of 0 it is never called,
+    /// and is removed by the next dispatch.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// let frame = Event::<u64>::new();
+    /// // Sample the first three frame times only
+    /// frame.subscribe_times(3, |micros| println!("frame took {} us", micros));
+    ///
+    /// for micros in [16_600, 16_800, 17_100, 16_500] {
+    ///     frame.invoke(micros); // The last one isn't printed
+    /// }
+    /// assert_eq!(frame.subscriber_count(), 0);
+    /// ```
+    pub fn subscribe_times<F>(&self, times: usize, handler: F) -> Subscription
+    where
+        F: FnMut(Args) + Send + 'static,
+    {
+        return self.subscribe_handler(
+            0,
+            Box::new(Limited {
+                handler: ByClone(handler),
+                remaining: times,
+            }),
+        );
+    }
+}
+
+impl<Args: ?Sized + 'static> Event<Args, Borrowed> {
+    /// Subscribes a callback that is unsubscribed on its own after it has run `times` times.
+    ///
+    /// See [`Event::subscribe_times`].
+    pub fn subscribe_times<F>(&self, times: usize, handler: F) -> Subscription
+    where
+        F: FnMut(&Args) + Send + 'static,
+    {
+        return self.subscribe_handler(
+            0,
+            Box::new(Limited {
+                handler,
+                remaining: times,
+            }),
+        );
+    }
+}
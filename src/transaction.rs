@@ -0,0 +1,93 @@
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+
+use crate::Event;
+
+type PendingInvoke = Box<dyn FnOnce() + Send>;
+
+/// A batch of event invocations that are dispatched together or not at all.
+///
+/// Invocations queued with [`Transaction::invoke`] don't reach any handler until
+/// [`Transaction::commit`] is called, at which point they are dispatched in the order they
+/// were queued. Calling [`Transaction::rollback`], or dropping the transaction without
+/// committing, discards them.
+///
+/// ```
+/// use blob_event::{Event, Transaction};
+///
+/// let saved = Event::<u32>::new();
+/// let indexed = Event::<String>::new();
+///
+/// let result: Result<(), &str> = Transaction::run(|tx| {
+///     tx.invoke(&saved, 7);
+///     tx.invoke(&indexed, "record 7".to_string());
+///     Err("disk full") // Neither event fires
+/// });
+/// assert!(result.is_err());
+/// ```
+#[must_use = "queued invocations are discarded unless the transaction is committed"]
+pub struct Transaction {
+    pending: Vec<PendingInvoke>,
+}
+
+impl Transaction {
+    /// Starts an empty transaction.
+    pub fn new() -> Self {
+        Transaction { pending: Vec::new() }
+    }
+
+    /// Runs `body` in a new transaction, committing it if `body` returns `Ok` and rolling it
+    /// back if it returns `Err`.
+    pub fn run<T, E, F>(body: F) -> Result<T, E>
+    where
+        F: FnOnce(&mut Transaction) -> Result<T, E>,
+    {
+        let mut tx = Transaction::new();
+        let result = body(&mut tx);
+        match result {
+            Ok(_) => tx.commit(),
+            Err(_) => tx.rollback(),
+        }
+        return result;
+    }
+
+    /// Queues an invocation of `event` with `args`, to be dispatched on commit.
+    pub fn invoke<Args>(&mut self, event: &Event<Args>, args: Args)
+    where
+        Args: Clone + Send + 'static,
+    {
+        let event = event.clone();
+        self.pending.push(Box::new(move || event.invoke(args)));
+    }
+
+    /// Returns the number of queued invocations.
+    pub fn len(&self) -> usize {
+        return self.pending.len();
+    }
+
+    /// Returns `true` if no invocations have been queued.
+    pub fn is_empty(&self) -> bool {
+        return self.pending.is_empty();
+    }
+
+    /// Dispatches every queued invocation in the order it was queued.
+    ///
+    /// If a handler panics, the invocations queued after the one being dispatched are
+    /// discarded.
+    pub fn commit(mut self) {
+        for invoke in self.pending.drain(..) {
+            invoke();
+        }
+    }
+
+    /// Discards every queued invocation without dispatching it.
+    pub fn rollback(mut self) {
+        self.pending.clear();
+    }
+}
+
+impl Default for Transaction {
+    fn default() -> Self {
+        Self::new()
+    }
+}
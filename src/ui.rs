@@ -0,0 +1,101 @@
+use std::boxed::Box;
+use std::sync::mpsc::Sender;
+use std::sync::{Arc, Mutex};
+
+use crate::{Event, Subscription};
+
+/// A piece of work posted to a [`UiLoop`], to be run on the loop's thread.
+///
+/// The loop receiving it calls [`run`](Self::run) once it gets to it.
+pub struct UiTask(Box<dyn FnOnce() + Send>);
+
+impl UiTask {
+    /// Wraps `task` so it can be posted to a [`UiLoop`].
+    pub fn new(task: impl FnOnce() + Send + 'static) -> Self {
+        return UiTask(Box::new(task));
+    }
+
+    /// Runs the task. Call it from the UI loop's thread.
+    pub fn run(self) {
+        (self.0)();
+    }
+}
+
+impl core::fmt::Debug for UiTask {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        return f.write_str("UiTask");
+    }
+}
+
+/// A handle for posting work to a UI event loop, such as a window's, which runs it on the
+/// loop's own thread.
+///
+/// Implemented for [`Sender<UiTask>`], for loops that drain a channel of tasks each frame,
+/// and, with the `winit` feature, for winit's `EventLoopProxy<T>` when `T: From<UiTask>`.
+/// An event loop built with `EventLoop::<UiTask>::with_user_event()` then receives the
+/// tasks in `ApplicationHandler::user_event`, which should [run](UiTask::run) them.
+///
+/// ```
+/// use std::sync::mpsc;
+///
+/// use blob_event::{Event, UiTask};
+///
+/// let (ui, tasks) = mpsc::channel::<UiTask>();
+/// let downloaded = Event::<u32>::new();
+/// downloaded.subscribe_on(ui, |percent| println!("progress bar at {}%", percent));
+///
+/// std::thread::spawn(move || downloaded.invoke(50)).join().unwrap();
+///
+/// // Once per frame, on the UI thread
+/// for task in tasks.try_iter() {
+///     task.run();
+/// }
+/// ```
+pub trait UiLoop: Send + Sync + 'static {
+    /// Posts `task` to the loop, returning `false` if the loop has closed and the task was
+    /// dropped instead.
+    fn post(&self, task: UiTask) -> bool;
+}
+
+impl UiLoop for Sender<UiTask> {
+    fn post(&self, task: UiTask) -> bool {
+        return self.send(task).is_ok();
+    }
+}
+
+#[cfg(feature = "winit")]
+impl<T> UiLoop for winit::event_loop::EventLoopProxy<T>
+where
+    T: From<UiTask> + Send + 'static,
+{
+    fn post(&self, task: UiTask) -> bool {
+        return self.send_event(T::from(task)).is_ok();
+    }
+}
+
+impl<Args> Event<Args>
+where
+    Args: Clone + Send + 'static,
+{
+    /// Subscribes a UI-bound handler: every invocation, from any thread, is posted to `ui`,
+    /// and the handler runs with the arguments inside that loop, on its thread.
+    ///
+    /// Invoking the event returns as soon as the work is posted, without waiting for the
+    /// handler to run. Invocations posted before the handler is unsubscribed, but not yet
+    /// run, are skipped; those posted after the loop has closed are dropped.
+    pub fn subscribe_on<L, F>(&self, ui: L, handler: F) -> Subscription
+    where
+        L: UiLoop,
+        F: FnMut(Args) + Send + 'static,
+    {
+        let handler = Arc::new(Mutex::new(handler));
+        return self.subscribe(move |args: Args| {
+            let handler = Arc::downgrade(&handler);
+            ui.post(UiTask::new(move || {
+                if let Some(handler) = handler.upgrade() {
+                    (handler.lock().unwrap())(args);
+                }
+            }));
+        });
+    }
+}
@@ -0,0 +1,82 @@
+use crate::{QueryEvent, Subscription};
+
+/// A handler's answer to a [`VetoEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    /// The handler doesn't object.
+    Allow,
+    /// The handler vetoes the action.
+    Deny,
+}
+
+/// An event that asks its handlers whether an action may go ahead, any of which can veto
+/// it, as in "can this window close?".
+///
+/// [`invoke`](Self::invoke) asks the handlers in dispatch order and returns `true` only if
+/// every one of them returned [`Verdict::Allow`]. The first [`Verdict::Deny`] settles it:
+/// the handlers after it aren't asked. Cloning a `VetoEvent` returns another handle to the
+/// same event.
+///
+/// ```
+/// use blob_event::{Verdict, VetoEvent};
+///
+/// let closing = VetoEvent::<u32>::new();
+/// closing.subscribe(|unsaved_files| {
+///     if *unsaved_files > 0 { Verdict::Deny } else { Verdict::Allow }
+/// });
+///
+/// assert!(!closing.invoke(2));
+/// assert!(closing.invoke(0));
+/// ```
+pub struct VetoEvent<Args> {
+    event: QueryEvent<Args, Verdict>,
+}
+
+impl<Args: 'static> VetoEvent<Args> {
+    /// Creates a veto event with no subscribers.
+    pub fn new() -> Self {
+        return VetoEvent {
+            event: QueryEvent::new(),
+        };
+    }
+
+    /// Subscribes a handler, which is asked for its verdict on every invocation that no
+    /// earlier handler denied.
+    pub fn subscribe<F>(&self, handler: F) -> Subscription
+    where
+        F: FnMut(&Args) -> Verdict + Send + 'static,
+    {
+        return self.event.subscribe(handler);
+    }
+
+    /// Unsubscribes a handler, returning `true` if it was subscribed.
+    pub fn unsubscribe(&self, id: Subscription) -> bool {
+        return self.event.unsubscribe(id);
+    }
+
+    /// Returns the number of handlers.
+    pub fn subscriber_count(&self) -> usize {
+        return self.event.subscriber_count();
+    }
+
+    /// Asks the handlers about `args`, returning `true` if none of them denied it. An event
+    /// without handlers allows everything.
+    pub fn invoke(&self, args: Args) -> bool {
+        let verdict = self.event.ask(args, |verdict| *verdict == Verdict::Deny);
+        return verdict != Some(Verdict::Deny);
+    }
+}
+
+impl<Args: 'static> Default for VetoEvent<Args> {
+    fn default() -> Self {
+        return Self::new();
+    }
+}
+
+impl<Args> Clone for VetoEvent<Args> {
+    fn clone(&self) -> Self {
+        return VetoEvent {
+            event: self.event.clone(),
+        };
+    }
+}
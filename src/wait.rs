@@ -0,0 +1,168 @@
+use std::sync::{Arc, Condvar, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::{Event, EventError, EventHandler};
+
+// Where a waiting thread is handed the arguments it waits for.
+struct Slot<Args> {
+    args: Mutex<Waiting<Args>>,
+    filled: Condvar,
+}
+
+enum Waiting<Args> {
+    Pending,
+    Received(Args),
+    // The event was closed, so nothing will be received.
+    Closed,
+}
+
+// The handler a waiting thread subscribes for as long as it waits.
+struct Waiter<Args, P> {
+    slot: Arc<Slot<Args>>,
+    accept: P,
+}
+
+impl<Args, P> EventHandler<Args> for Waiter<Args, P>
+where
+    Args: Clone + Send,
+    P: FnMut(&Args) -> bool + Send,
+{
+    fn call(&mut self, args: &Args) {
+        let mut slot = self.slot.args.lock().unwrap();
+        // Only the first accepted invocation is kept.
+        if matches!(*slot, Waiting::Pending) && (self.accept)(args) {
+            *slot = Waiting::Received(args.clone());
+            self.slot.filled.notify_all();
+        }
+    }
+
+    fn close(&mut self) {
+        let mut slot = self.slot.args.lock().unwrap();
+        if matches!(*slot, Waiting::Pending) {
+            *slot = Waiting::Closed;
+            self.slot.filled.notify_all();
+        }
+    }
+}
+
+impl<Args, D> Event<Args, D>
+where
+    Args: Clone + Send + 'static,
+{
+    /// Blocks the calling thread until the event is next dispatched, and returns a clone of
+    /// the arguments.
+    ///
+    /// The waiting thread counts as a subscriber while it waits, and only dispatches that
+    /// start after the call are seen. Waiting from one of the event's own handlers, or on a
+    /// [`DeliveryMode::Pumped`](crate::DeliveryMode::Pumped) event from the thread that pumps
+    /// it, never returns.
+    ///
+    /// ```
+    /// use blob_event::Event;
+    ///
+    /// let ready = Event::<u32>::new();
+    /// let worker = ready.clone();
+    /// std::thread::spawn(move || {
+    ///     while worker.subscriber_count() == 0 {
+    ///         std::thread::yield_now(); // Until the main thread waits
+    ///     }
+    ///     worker.invoke(42);
+    /// });
+    /// assert_eq!(ready.wait(), 42);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event is [closed](Self::close) before it is dispatched, or if it has
+    /// reached its [subscriber limit](crate::EventBuilder::max_subscribers) and refuses the
+    /// waiting thread.
+    pub fn wait(&self) -> Args {
+        return self.wait_forever(|_| true);
+    }
+
+    /// Blocks like [`wait`](Self::wait), but for at most `timeout`, returning `None` if the
+    /// event wasn't dispatched in that time, was closed, or refused the waiting thread.
+    pub fn wait_timeout(&self, timeout: Duration) -> Option<Args> {
+        return self.wait_for(|_| true, Some(Instant::now() + timeout)).ok().flatten();
+    }
+
+    /// Blocks like [`wait`](Self::wait) until the event is dispatched with arguments that
+    /// satisfy `predicate`, and returns a clone of them. Other dispatches are skipped.
+    ///
+    /// `predicate` runs on the dispatching thread, as part of the dispatch.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the event is [closed](Self::close) before a matching dispatch, or if it
+    /// refuses the waiting thread, as [`wait`](Self::wait) does.
+    pub fn wait_until<P>(&self, predicate: P) -> Args
+    where
+        P: FnMut(&Args) -> bool + Send + 'static,
+    {
+        return self.wait_forever(predicate);
+    }
+
+    /// Blocks like [`wait_until`](Self::wait_until), but for at most `timeout`, returning
+    /// `None` if no matching dispatch happened in that time, the event was closed, or it
+    /// refused the waiting thread.
+    pub fn wait_until_timeout<P>(&self, predicate: P, timeout: Duration) -> Option<Args>
+    where
+        P: FnMut(&Args) -> bool + Send + 'static,
+    {
+        return self.wait_for(predicate, Some(Instant::now() + timeout)).ok().flatten();
+    }
+
+    // Waits like `wait_for` without a deadline, panicking if nothing can be received.
+    fn wait_forever<P>(&self, accept: P) -> Args
+    where
+        P: FnMut(&Args) -> bool + Send + 'static,
+    {
+        match self.wait_for(accept, None) {
+            Ok(Some(args)) => return args,
+            Ok(None) => unreachable!("a wait without a deadline timed out"),
+            Err(EventError::Closed) => panic!("waited on a closed event"),
+            Err(error) => panic!("couldn't wait on the event: {}", error),
+        }
+    }
+
+    // Waits until a dispatch whose arguments pass `accept`, returning `None` once `deadline`
+    // passes. Fails if the event refuses the waiter, or is closed before such a dispatch.
+    fn wait_for<P>(&self, accept: P, deadline: Option<Instant>) -> Result<Option<Args>, EventError>
+    where
+        P: FnMut(&Args) -> bool + Send + 'static,
+    {
+        let slot = Arc::new(Slot {
+            args: Mutex::new(Waiting::Pending),
+            filled: Condvar::new(),
+        });
+        let waiter = Waiter {
+            slot: Arc::clone(&slot),
+            accept,
+        };
+        // A refused waiter is dropped without being told, so it would wait forever.
+        let id = self.try_subscribe_handler(0, Box::new(waiter))?;
+
+        let args = {
+            let mut args = slot.args.lock().unwrap();
+            while matches!(*args, Waiting::Pending) {
+                match deadline {
+                    None => args = slot.filled.wait(args).unwrap(),
+                    Some(deadline) => {
+                        let now = Instant::now();
+                        if now >= deadline {
+                            break;
+                        }
+                        args = slot.filled.wait_timeout(args, deadline - now).unwrap().0;
+                    }
+                }
+            }
+            match std::mem::replace(&mut *args, Waiting::Pending) {
+                Waiting::Received(args) => Ok(Some(args)),
+                Waiting::Closed => Err(EventError::Closed),
+                Waiting::Pending => Ok(None),
+            }
+        };
+        self.unsubscribe(id);
+        return args;
+    }
+}
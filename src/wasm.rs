@@ -0,0 +1,19 @@
+use wasm_bindgen::JsValue;
+
+use crate::{LocalEvent, Subscription};
+
+impl<Args> LocalEvent<Args>
+where
+    Args: Clone + Into<JsValue> + 'static,
+{
+    /// Subscribes a JavaScript function to this event.
+    ///
+    /// The function is called with the arguments converted to a `JsValue` and `this` set to
+    /// `undefined`. An exception thrown by the function is discarded, so it doesn't stop the
+    /// remaining handlers from running.
+    pub fn subscribe_js(&self, callback: js_sys::Function) -> Subscription {
+        return self.subscribe(move |args: Args| {
+            let _ = callback.call1(&JsValue::UNDEFINED, &args.into());
+        });
+    }
+}
@@ -0,0 +1,262 @@
+use std::sync::{Arc, OnceLock, Weak};
+use std::time::{Duration, Instant};
+
+use crate::builder::EventConfig;
+#[cfg(feature = "prometheus")]
+use crate::prometheus::EventMetrics;
+use crate::stats::CallCounters;
+use crate::sync::{Lock, Mutex};
+use crate::timer::{self, Scheduler};
+use crate::{Event, EventInner, HandlerSlot, Subscription};
+
+/// What happens to a handler that runs longer than the limit set with
+/// [`EventBuilder::handler_timeout`](crate::EventBuilder::handler_timeout).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum TimeoutAction {
+    /// The handler is reported through [`Event::handler_timeouts`] and stays subscribed.
+    #[default]
+    Report,
+    /// The handler is reported and unsubscribed, so once it returns it is never called again.
+    Unsubscribe,
+}
+
+/// A handler call that took longer than the threshold set with
+/// [`EventBuilder::slow_handler_threshold`](crate::EventBuilder::slow_handler_threshold).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct SlowHandler {
+    /// The subscription whose handler was slow.
+    pub subscription: Subscription,
+    /// How long the call took.
+    pub elapsed: Duration,
+}
+
+// Watches the handlers of an event, reporting the ones that run longer than the configured
+// limits: the time limit of queued and detached dispatches, and the slow-handler threshold
+// of every dispatch.
+pub(crate) struct Watchdog {
+    timeout: Option<(Duration, TimeoutAction)>,
+    // Times the limit, if there is one. Picked when the event is created.
+    scheduler: Option<Arc<dyn Scheduler>>,
+    // The watched calls still running. One check at a time is scheduled for the earliest of
+    // them, so finished calls leave nothing behind in the scheduler.
+    running: Mutex<RunningCalls>,
+    slow_threshold: Option<Duration>,
+    // Created by the first call to `handler_timeouts` and `slow_handlers`.
+    reports: OnceLock<Event<Subscription>>,
+    slow_reports: OnceLock<Event<SlowHandler>>,
+}
+
+#[derive(Default)]
+struct RunningCalls {
+    // Every call has the same limit, so the calls are in deadline order.
+    calls: Vec<RunningCall>,
+    next_key: u64,
+    check_scheduled: bool,
+}
+
+struct RunningCall {
+    key: u64,
+    subscription: Subscription,
+    deadline: Instant,
+}
+
+// Marks a watched handler call as finished when dropped, including when the handler panics.
+struct Watch<'a> {
+    running: &'a Mutex<RunningCalls>,
+    key: u64,
+}
+
+impl Drop for Watch<'_> {
+    fn drop(&mut self) {
+        let mut running = self.running.acquire();
+        // Calls found past their deadline have already been removed by the check.
+        if let Some(index) = running.calls.iter().position(|call| call.key == self.key) {
+            running.calls.remove(index);
+        }
+    }
+}
+
+// Times a handler call. When dropped, it adds the call to the handler's counters and reports
+// it if it took longer than the slow-handler threshold.
+pub(crate) struct Timing<'a> {
+    slow: Option<(&'a Event<SlowHandler>, Duration)>,
+    counters: Option<&'a CallCounters>,
+    #[cfg(feature = "prometheus")]
+    metrics: Option<&'a EventMetrics>,
+    subscription: Subscription,
+    started: Instant,
+}
+
+impl Drop for Timing<'_> {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed();
+        if let Some(counters) = self.counters {
+            counters.record(elapsed);
+        }
+        #[cfg(feature = "prometheus")]
+        if let Some(metrics) = self.metrics {
+            metrics.record_call(elapsed);
+        }
+        // A report handler panicking while a handler's panic unwinds would abort.
+        if let Some((reports, threshold)) = self.slow
+            && elapsed > threshold
+            && !std::thread::panicking()
+        {
+            reports.invoke(SlowHandler {
+                subscription: self.subscription,
+                elapsed,
+            });
+        }
+    }
+}
+
+impl Watchdog {
+    pub(crate) fn new(config: &EventConfig) -> Self {
+        Watchdog {
+            timeout: config.handler_timeout,
+            scheduler: config.handler_timeout.map(|_| timer::current()),
+            running: Mutex::new(RunningCalls::default()),
+            slow_threshold: config.slow_handler_threshold,
+            reports: OnceLock::new(),
+            slow_reports: OnceLock::new(),
+        }
+    }
+}
+
+impl<Args: ?Sized, D> Event<Args, D> {
+    /// Returns an event that fires with the subscription of every handler found running
+    /// longer than the limit set with
+    /// [`EventBuilder::handler_timeout`](crate::EventBuilder::handler_timeout).
+    ///
+    /// Handlers can't be interrupted, so a handler is reported while it is still running,
+    /// once the limit has passed. The reports are invoked from the crate's shared timer
+    /// thread, so their handlers should return quickly. Events without a limit never fire it.
+    pub fn handler_timeouts(&self) -> Event<Subscription> {
+        return self.inner.watchdog.reports.get_or_init(Event::new).clone();
+    }
+
+    /// Returns an event that fires after every handler call that took longer than the
+    /// threshold set with
+    /// [`EventBuilder::slow_handler_threshold`](crate::EventBuilder::slow_handler_threshold).
+    ///
+    /// Reports are invoked on the thread that ran the slow handler, right after it returns.
+    /// Handlers are only timed once this has been called, so events nobody watches don't pay
+    /// for reading the clock. Events without a threshold never fire it.
+    pub fn slow_handlers(&self) -> Event<SlowHandler> {
+        return self.inner.watchdog.slow_reports.get_or_init(Event::new).clone();
+    }
+
+    // Starts timing a call to the handler in `cell`, if the event collects stats, is exported
+    // to Prometheus, or has a slow-handler threshold that someone listens to. The returned
+    // guard must be kept until the handler returns.
+    pub(crate) fn time<'a>(&'a self, cell: &'a HandlerSlot<Args>) -> Option<Timing<'a>> {
+        let watchdog = &self.inner.watchdog;
+        let slow = match (watchdog.slow_reports.get(), watchdog.slow_threshold) {
+            (Some(reports), Some(threshold)) => Some((reports, threshold)),
+            _ => None,
+        };
+        let counters = self.inner.counters.enabled.then_some(&cell.counters);
+        #[cfg(feature = "prometheus")]
+        let metrics = self.inner.counters.metrics.get();
+        #[cfg(not(feature = "prometheus"))]
+        let metrics: Option<()> = None;
+        if slow.is_none() && counters.is_none() && metrics.is_none() {
+            return None;
+        }
+        Some(Timing {
+            slow,
+            counters,
+            #[cfg(feature = "prometheus")]
+            metrics,
+            subscription: cell.id,
+            started: Instant::now(),
+        })
+    }
+}
+
+impl<Args: ?Sized + 'static, D> Event<Args, D> {
+    // Dispatches like `dispatch_with`, but watches every handler call if the event has a
+    // time limit. Used by the dispatches that run on the crate's own threads or are pumped.
+    pub(crate) fn dispatch_watched(&self, args: &Args, catch_panics: bool) {
+        yield_point!(BeforeSnapshot);
+        let snapshot = self.inner.snapshot.load();
+        let _ = self.dispatch_snapshot(&snapshot, args, catch_panics, |id| self.watch(id));
+    }
+
+    // Starts watching a call to the handler of `id`, if the event has a time limit. The
+    // returned guard must be kept until the handler returns.
+    fn watch(&self, id: Subscription) -> Option<Watch<'_>> {
+        let (limit, _) = self.inner.watchdog.timeout?;
+        let scheduler = self.inner.watchdog.scheduler.as_ref()?;
+        let watchdog = &self.inner.watchdog;
+        let (key, deadline, schedule) = {
+            let mut running = watchdog.running.acquire();
+            // Read under the lock so the calls stay in deadline order.
+            let deadline = scheduler.now() + limit;
+            let key = running.next_key;
+            running.next_key += 1;
+            running.calls.push(RunningCall {
+                key,
+                subscription: id,
+                deadline,
+            });
+            let schedule = !running.check_scheduled;
+            running.check_scheduled = true;
+            (key, deadline, schedule)
+        };
+        if schedule {
+            schedule_check(scheduler.as_ref(), Arc::downgrade(&self.inner), deadline);
+        }
+        return Some(Watch {
+            running: &watchdog.running,
+            key,
+        });
+    }
+}
+
+fn schedule_check<Args: ?Sized + 'static>(
+    scheduler: &dyn Scheduler,
+    event: Weak<EventInner<Args>>,
+    deadline: Instant,
+) {
+    scheduler.schedule(deadline, Box::new(move || check_running(event)));
+}
+
+// Reports the watched calls of the event that are past their deadline, then schedules the
+// next check for the earliest call still running, if any.
+fn check_running<Args: ?Sized + 'static>(event: Weak<EventInner<Args>>) {
+    let Some(inner) = event.upgrade() else {
+        return;
+    };
+    let watchdog = &inner.watchdog;
+    let (Some((_, action)), Some(scheduler)) = (watchdog.timeout, watchdog.scheduler.as_ref())
+    else {
+        return;
+    };
+    let now = scheduler.now();
+    let (overdue, next) = {
+        let mut running = watchdog.running.acquire();
+        let due = running.calls.partition_point(|call| call.deadline <= now);
+        let overdue: Vec<Subscription> = running
+            .calls
+            .drain(..due)
+            .map(|call| call.subscription)
+            .collect();
+        let next = running.calls.first().map(|call| call.deadline);
+        running.check_scheduled = next.is_some();
+        (overdue, next)
+    };
+    if let Some(deadline) = next {
+        schedule_check(scheduler.as_ref(), Weak::clone(&event), deadline);
+    }
+    for id in overdue {
+        if action == TimeoutAction::Unsubscribe && inner.update(|handlers| handlers.remove(id)) {
+            inner.notify(|hooks| hooks.on_unsubscribe(id));
+        }
+        if let Some(reports) = watchdog.reports.get() {
+            reports.invoke(id);
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::future::Future;
+use std::pin::{Pin, pin};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use blob_event::{AsyncDispatch, AsyncEvent};
+
+// Completes on its second poll, waking the task in between.
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            return Poll::Ready(());
+        }
+        self.0 = true;
+        cx.waker().wake_by_ref();
+        Poll::Pending
+    }
+}
+
+// Drives a future that only waits on `YieldNow` to completion.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn logging_event(dispatch: AsyncDispatch, log: &Arc<Mutex<Vec<String>>>) -> AsyncEvent<u32, String> {
+    let event = AsyncEvent::with_dispatch(dispatch);
+    for name in ["a", "b"] {
+        let log = Arc::clone(log);
+        event.subscribe(move |n: u32| {
+            let log = Arc::clone(&log);
+            async move {
+                log.lock().unwrap().push(format!("{} start {}", name, n));
+                YieldNow(false).await;
+                log.lock().unwrap().push(format!("{} end", name));
+                if name == "b" {
+                    Err(format!("{} failed", name))
+                } else {
+                    Ok(())
+                }
+            }
+        });
+    }
+    event
+}
+
+#[test]
+fn test_sequential_dispatch_runs_handlers_one_after_another() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let event = logging_event(AsyncDispatch::Sequential, &log);
+
+    let failures = block_on(event.invoke(1));
+
+    assert_eq!(failures.len(), 1);
+    assert_eq!(failures[0].1, "b failed");
+    assert_eq!(*log.lock().unwrap(), ["a start 1", "a end", "b start 1", "b end"]);
+}
+
+#[test]
+fn test_concurrent_dispatch_interleaves_handlers() {
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let event = logging_event(AsyncDispatch::Concurrent, &log);
+
+    let failures = block_on(event.invoke(2));
+
+    assert_eq!(
+        failures.iter().map(|(_, error)| error.as_str()).collect::<Vec<_>>(),
+        ["b failed"]
+    );
+    assert_eq!(*log.lock().unwrap(), ["a start 2", "b start 2", "a end", "b end"]);
+}
+
+#[test]
+fn test_invoke_is_send_and_captures_handlers_eagerly() {
+    fn assert_send<T: Send>(value: T) -> T {
+        value
+    }
+
+    let event = AsyncEvent::<u32, ()>::new();
+    let calls = Arc::new(Mutex::new(0));
+    let calls_clone = Arc::clone(&calls);
+    let sub = event.subscribe(move |_| {
+        *calls_clone.lock().unwrap() += 1;
+        async { Ok(()) }
+    });
+
+    let invocation = assert_send(event.invoke(1));
+    assert_eq!(*calls.lock().unwrap(), 1);
+    event.unsubscribe(sub);
+    assert!(block_on(invocation).is_empty());
+}
@@ -0,0 +1,120 @@
+use blob_event::{BatchConfig, Event, EventError, SubscriberLimit};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn test_batches_delivered_when_full() {
+    let event = Event::<i32>::new();
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let batches_clone = Arc::clone(&batches);
+
+    let sub = event
+        .subscribe_batched(BatchConfig::new(3), move |batch| {
+            batches_clone.lock().unwrap().push(batch);
+        })
+        .unwrap();
+
+    for x in 1..=7 {
+        event.invoke(x);
+    }
+
+    // The seventh item waits for two more to complete its batch
+    assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    event.unsubscribe(sub);
+}
+
+#[test]
+fn test_partial_batch_delivered_after_max_latency() {
+    let event = Event::<i32>::new();
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let batches_clone = Arc::clone(&batches);
+
+    let config = BatchConfig::new(100).max_latency(Duration::from_millis(20));
+    let sub = event
+        .subscribe_batched(config, move |batch| {
+            batches_clone.lock().unwrap().push(batch);
+        })
+        .unwrap();
+
+    event.invoke(1);
+    event.invoke(2);
+    assert!(batches.lock().unwrap().is_empty());
+
+    thread::sleep(Duration::from_millis(200));
+    assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2]]);
+
+    event.unsubscribe(sub);
+}
+
+#[test]
+fn test_panicking_batch_handler_keeps_timer_running() {
+    let failing = Event::<i32>::new();
+    let config = BatchConfig::new(100).max_latency(Duration::from_millis(10));
+    failing
+        .subscribe_batched(config, |_| panic!("batch handler failed"))
+        .unwrap();
+    failing.invoke(1);
+
+    // A later batch on another event is still delivered by the shared timer. Printing the
+    // panic can take a while, so wait on the delivery rather than a fixed sleep.
+    let event = Event::<i32>::new();
+    let (sender, batches) = mpsc::channel();
+    event
+        .subscribe_batched(config, move |batch| sender.send(batch).unwrap())
+        .unwrap();
+    event.invoke(2);
+    assert_eq!(batches.recv_timeout(Duration::from_secs(5)).unwrap(), vec![2]);
+}
+
+#[test]
+fn test_latency_timer_does_not_cut_next_batch_short() {
+    let event = Event::<i32>::new();
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let batches_clone = Arc::clone(&batches);
+
+    let config = BatchConfig::new(2).max_latency(Duration::from_millis(100));
+    let sub = event
+        .subscribe_batched(config, move |batch| {
+            batches_clone.lock().unwrap().push(batch);
+        })
+        .unwrap();
+
+    // Fills the first batch right away, then starts a second one
+    event.invoke(1);
+    event.invoke(2);
+    event.invoke(3);
+    assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2]]);
+
+    thread::sleep(Duration::from_millis(400));
+    assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2], vec![3]]);
+
+    event.unsubscribe(sub);
+}
+
+#[test]
+fn test_zero_batch_size_is_rejected() {
+    let event = Event::<i32>::new();
+    let result = event.subscribe_batched(BatchConfig::new(0), |_| {});
+
+    assert_eq!(result, Err(EventError::InvalidBatchSize));
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_batched_subscription_reports_refusal() {
+    let closed = Event::<i32>::new();
+    closed.close();
+    assert_eq!(
+        closed.subscribe_batched(BatchConfig::new(2), |_| {}),
+        Err(EventError::Closed)
+    );
+
+    let full: Event<i32> = Event::builder().max_subscribers(1, SubscriberLimit::Reject).build();
+    full.subscribe(|_| {});
+    assert_eq!(
+        full.subscribe_batched(BatchConfig::new(2), |_| {}),
+        Err(EventError::TooManySubscribers)
+    );
+}
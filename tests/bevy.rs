@@ -0,0 +1,98 @@
+#![cfg(feature = "bevy")]
+
+use std::sync::{Arc, Mutex};
+
+use bevy_app::{App, Update};
+use bevy_ecs::message::{Message, MessageReader, MessageWriter};
+use bevy_ecs::resource::Resource;
+use bevy_ecs::system::ResMut;
+use blob_event::bevy::{BlobEventAppExt, BlobEventPlugin};
+use blob_event::{DeliveryMode, Event, EventQueue};
+
+#[derive(Message, Clone, Debug, PartialEq)]
+struct Scored(u32);
+
+#[derive(Resource, Default)]
+struct Seen(Vec<u32>);
+
+fn collecting(event: &Event<u32>) -> Arc<Mutex<Vec<u32>>> {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |n| seen_clone.lock().unwrap().push(n));
+    seen
+}
+
+#[test]
+fn test_pumped_event_dispatches_each_frame() {
+    let event: Event<u32> = Event::builder().delivery_mode(DeliveryMode::Pumped).build();
+    let seen = collecting(&event);
+    let mut app = App::new();
+    app.add_plugins(BlobEventPlugin).pump_event(event.clone());
+
+    event.invoke(1);
+    event.invoke(2);
+    assert!(seen.lock().unwrap().is_empty());
+    app.update();
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+    event.invoke(3);
+    app.update();
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_queue_drains_each_frame() {
+    let queue = EventQueue::<u32>::new();
+    let seen = collecting(queue.event());
+    let producer = queue.clone();
+    // Registering adds the plugin if it is missing
+    let mut app = App::new();
+    app.drain_queue(queue);
+
+    std::thread::spawn(move || producer.push(7)).join().unwrap();
+    assert!(seen.lock().unwrap().is_empty());
+    app.update();
+    assert_eq!(*seen.lock().unwrap(), vec![7]);
+}
+
+#[test]
+fn test_messages_are_forwarded_to_event() {
+    let event = Event::<Scored>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |scored| seen_clone.lock().unwrap().push(scored));
+
+    let mut app = App::new();
+    app.forward_messages(event)
+        .add_systems(Update, |mut writer: MessageWriter<Scored>| {
+            writer.write(Scored(10));
+        });
+    app.update();
+    assert_eq!(*seen.lock().unwrap(), vec![Scored(10)]);
+    app.update();
+    assert_eq!(*seen.lock().unwrap(), vec![Scored(10), Scored(10)]);
+}
+
+#[test]
+fn test_invocations_are_emitted_as_messages() {
+    let event = Event::<Scored>::new();
+    let mut app = App::new();
+    app.init_resource::<Seen>().emit_messages(&event).add_systems(
+        Update,
+        |mut reader: MessageReader<Scored>, mut seen: ResMut<Seen>| {
+            seen.0.extend(reader.read().map(|scored| scored.0));
+        },
+    );
+    assert_eq!(event.subscriber_count(), 0);
+
+    event.invoke(Scored(1));
+    std::thread::spawn({
+        let event = event.clone();
+        move || event.invoke(Scored(2))
+    })
+    .join()
+    .unwrap();
+    app.update();
+    assert_eq!(app.world().resource::<Seen>().0, vec![1, 2]);
+    app.update();
+    assert_eq!(app.world().resource::<Seen>().0, vec![1, 2]);
+}
@@ -0,0 +1,111 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{BusNode, Phase};
+
+// Which node saw each click.
+type Seen = Arc<Mutex<Vec<(&'static str, u32)>>>;
+
+// A window containing a panel containing a button, recording which node saw each click.
+fn tree() -> (BusNode<u32>, BusNode<u32>, BusNode<u32>, Seen) {
+    let window = BusNode::root();
+    let panel = window.child();
+    let button = panel.child();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    for (node, name) in [(&window, "window"), (&panel, "panel"), (&button, "button")] {
+        let seen = Arc::clone(&seen);
+        node.subscribe(move |click| seen.lock().unwrap().push((name, *click.args())));
+    }
+    (window, panel, button, seen)
+}
+
+#[test]
+fn test_publish_bubbles_to_ancestors() {
+    let (window, panel, button, seen) = tree();
+    assert!(!button.publish(1));
+    assert!(!panel.publish(2));
+    assert!(!window.publish(3));
+    assert_eq!(
+        *seen.lock().unwrap(),
+        [
+            ("button", 1),
+            ("panel", 1),
+            ("window", 1),
+            ("panel", 2),
+            ("window", 2),
+            ("window", 3)
+        ]
+    );
+}
+
+#[test]
+fn test_consumed_message_stops_bubbling() {
+    let (_window, panel, button, seen) = tree();
+    let consumer = panel.subscribe(|click| click.consume());
+
+    assert!(button.publish(1));
+    // The panel's other handler still sees the message it consumed
+    assert_eq!(*seen.lock().unwrap(), [("button", 1), ("panel", 1)]);
+
+    assert!(panel.unsubscribe(consumer));
+    assert!(!button.unsubscribe(consumer));
+    assert!(!button.publish(2));
+    assert_eq!(seen.lock().unwrap().len(), 5);
+}
+
+#[test]
+fn test_children_keep_ancestors_alive() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let button = {
+        let window = BusNode::root();
+        let seen_clone = Arc::clone(&seen);
+        window.subscribe(move |click| seen_clone.lock().unwrap().push(*click.args()));
+        window.child()
+    };
+    button.publish(7);
+    assert_eq!(*seen.lock().unwrap(), [7]);
+    assert!(button.parent().unwrap().parent().is_none());
+}
+
+#[test]
+fn test_capture_handlers_run_root_to_target_before_bubbling() {
+    let (window, panel, button, seen) = tree();
+    let phases = Arc::new(Mutex::new(Vec::new()));
+    for (node, name) in [(&window, "window"), (&panel, "panel"), (&button, "button")] {
+        let (seen, phases) = (Arc::clone(&seen), Arc::clone(&phases));
+        node.subscribe_capture(move |click| {
+            seen.lock().unwrap().push((name, *click.args()));
+            phases.lock().unwrap().push(click.phase());
+        });
+    }
+
+    button.publish(1);
+    assert_eq!(
+        *seen.lock().unwrap(),
+        [
+            ("window", 1),
+            ("panel", 1),
+            ("button", 1),
+            ("button", 1),
+            ("panel", 1),
+            ("window", 1)
+        ]
+    );
+    assert_eq!(*phases.lock().unwrap(), [Phase::Capture, Phase::Capture, Phase::Target]);
+}
+
+#[test]
+fn test_capture_handler_consumes_before_target() {
+    let (window, _panel, button, seen) = tree();
+    let interceptor = window.subscribe_capture(|click| {
+        if *click.args() == 0 {
+            click.consume();
+        }
+    });
+
+    assert!(button.publish(0));
+    assert!(seen.lock().unwrap().is_empty());
+
+    assert!(window.unsubscribe(interceptor));
+    assert!(!button.publish(0));
+    assert_eq!(seen.lock().unwrap().len(), 3);
+}
@@ -0,0 +1,97 @@
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread;
+use std::time::Duration;
+
+use blob_event::{Borrowed, DispatchError, Event, EventError};
+
+#[test]
+fn test_close_notifies_and_unsubscribes_handlers() {
+    let event = Event::<i32>::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    for name in ["a", "b"] {
+        let (on_next, on_close) = (Arc::clone(&log), Arc::clone(&log));
+        event.subscribe_with_close(
+            move |n| on_next.lock().unwrap().push(format!("{} {}", name, n)),
+            move || on_close.lock().unwrap().push(format!("{} closed", name)),
+        );
+    }
+    event.subscribe(|_| {});
+
+    event.invoke(1);
+    assert!(!event.is_closed());
+    event.close();
+    event.close();
+
+    assert!(event.is_closed());
+    assert_eq!(event.subscriber_count(), 0);
+    assert_eq!(*log.lock().unwrap(), ["a 1", "b 1", "a closed", "b closed"]);
+}
+
+#[test]
+fn test_closed_event_ignores_invokes_and_subscriptions() {
+    let event = Event::<i32>::new();
+    event.close();
+
+    let calls = Arc::new(Mutex::new(0));
+    let calls_clone = Arc::clone(&calls);
+    let sub = event.subscribe(move |_| *calls_clone.lock().unwrap() += 1);
+    assert_eq!(event.subscriber_count(), 0);
+    assert!(!event.unsubscribe(sub));
+    assert_eq!(event.try_subscribe(|_| {}), Err(EventError::Closed));
+
+    event.invoke(1);
+    event.invoke_batch(&[2, 3]);
+    assert_eq!(event.invoke_counted(4), 0);
+    assert_eq!(event.try_invoke(5), Err(DispatchError::Closed));
+    assert_eq!(*calls.lock().unwrap(), 0);
+}
+
+#[test]
+fn test_close_releases_waiting_threads() {
+    let event: Event<i32, Borrowed> = Event::builder().borrowed().build();
+    let waiter = event.clone();
+    let handle = thread::spawn(move || waiter.wait_timeout(Duration::from_secs(60)));
+    while event.subscriber_count() == 0 {
+        thread::yield_now();
+    }
+    event.close();
+    assert_eq!(handle.join().unwrap(), None);
+    assert_eq!(event.wait_timeout(Duration::from_secs(60)), None);
+}
+
+struct Flag(AtomicBool);
+
+impl Wake for Flag {
+    fn wake(self: Arc<Self>) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+}
+
+#[test]
+fn test_close_completes_pending_next() {
+    let event = Event::<i32>::new();
+    let mut next = Box::pin(event.next());
+    let woken = Arc::new(Flag(AtomicBool::new(false)));
+    let waker = Waker::from(Arc::clone(&woken));
+    let mut cx = Context::from_waker(&waker);
+    assert_eq!(next.as_mut().poll(&mut cx), Poll::Pending);
+
+    event.close();
+    assert!(woken.0.load(Ordering::SeqCst));
+    let polled = panic::catch_unwind(AssertUnwindSafe(|| next.as_mut().poll(&mut cx)));
+    assert!(polled.is_err());
+}
+
+#[test]
+fn test_next_on_closed_event_doesnt_pend() {
+    let event = Event::<i32>::new();
+    event.close();
+    let mut next = Box::pin(event.next());
+    let mut cx = Context::from_waker(Waker::noop());
+    let polled = panic::catch_unwind(AssertUnwindSafe(|| next.as_mut().poll(&mut cx)));
+    assert!(polled.is_err());
+}
@@ -0,0 +1,59 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::Event;
+
+#[test]
+fn test_publish_attaches_with_first_subscriber_and_detaches_with_last() {
+    let source = Event::<i32>::new();
+    let shared = source.publish();
+    assert!(!shared.is_connected());
+    assert_eq!(source.subscriber_count(), 0);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let (r1, r2) = (Arc::clone(&received), Arc::clone(&received));
+    let first = shared.subscribe(move |n| r1.lock().unwrap().push(("first", n)));
+    let second = shared.subscribe(move |n| r2.lock().unwrap().push(("second", n)));
+    assert!(shared.is_connected());
+    assert_eq!(source.subscriber_count(), 1);
+
+    source.invoke(1);
+    shared.unsubscribe(first);
+    assert!(shared.is_connected());
+    source.invoke(2);
+    shared.unsubscribe(second);
+    assert!(!shared.is_connected());
+    assert_eq!(source.subscriber_count(), 0);
+    source.invoke(3);
+
+    assert_eq!(*received.lock().unwrap(), [("first", 1), ("second", 1), ("second", 2)]);
+}
+
+#[test]
+fn test_publish_reconnects_and_detaches_on_unsubscribe_all() {
+    let source = Event::<i32>::new();
+    let shared = source.publish();
+    let calls = Arc::new(Mutex::new(0));
+
+    for _ in 0..2 {
+        let calls_clone = Arc::clone(&calls);
+        shared.subscribe(move |_| *calls_clone.lock().unwrap() += 1);
+        shared.subscribe(|_| {});
+        assert_eq!(source.subscriber_count(), 1);
+        source.invoke(1);
+        shared.unsubscribe_all();
+        assert_eq!(source.subscriber_count(), 0);
+    }
+    assert_eq!(*calls.lock().unwrap(), 2);
+}
+
+#[test]
+fn test_dropped_connectable_leaves_the_source() {
+    let source = Event::<i32>::new();
+    let shared = source.publish();
+    shared.subscribe(|_| {});
+    drop(shared);
+    assert_eq!(source.subscriber_count(), 1);
+
+    source.invoke(1);
+    assert_eq!(source.subscriber_count(), 0);
+}
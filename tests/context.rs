@@ -0,0 +1,57 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Borrowed, Event};
+
+#[test]
+fn test_unsubscribe_self_keeps_other_handlers() {
+    let event = Event::<i32>::new();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let calls_clone = Arc::clone(&calls);
+    event.subscribe(move |x| calls_clone.lock().unwrap().push(("before", x)));
+    let calls_clone = Arc::clone(&calls);
+    let once = event.subscribe_with_context(move |x, ctx| {
+        calls_clone.lock().unwrap().push(("once", x));
+        assert!(ctx.unsubscribe_self());
+        // Already gone
+        assert!(!ctx.unsubscribe_self());
+    });
+    let calls_clone = Arc::clone(&calls);
+    event.subscribe(move |x| calls_clone.lock().unwrap().push(("after", x)));
+
+    event.invoke(1);
+    event.invoke(2);
+    assert!(!event.contains(once));
+    assert_eq!(
+        *calls.lock().unwrap(),
+        [("before", 1), ("once", 1), ("after", 1), ("before", 2), ("after", 2)]
+    );
+}
+
+#[test]
+fn test_context_knows_own_subscription() {
+    let event = Event::<i32>::new();
+    let seen = Arc::new(Mutex::new(None));
+    let seen_clone = Arc::clone(&seen);
+    let sub = event.subscribe_with_context(move |_, ctx| *seen_clone.lock().unwrap() = Some(ctx.subscription()));
+    event.invoke(0);
+    assert_eq!(*seen.lock().unwrap(), Some(sub));
+}
+
+#[test]
+fn test_unsubscribe_self_skips_nested_dispatch() {
+    let event: Event<str, Borrowed> = Event::builder().borrowed().build();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let (calls_clone, inner) = (Arc::clone(&calls), event.clone());
+    event.subscribe_with_context(move |_: &str, ctx| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+        ctx.unsubscribe_self();
+        // The nested dispatch skips the handler instead of waiting for it
+        inner.invoke("again");
+    });
+
+    event.invoke("first");
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(event.subscriber_count(), 0);
+}
@@ -0,0 +1,100 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Borrowed, Event, HandlerControl};
+
+// Subscribes a handler recording what it sees under `name`.
+fn record(event: &Event<u32>, log: &Arc<Mutex<Vec<(&'static str, u32)>>>, name: &'static str) {
+    let log = Arc::clone(log);
+    event.subscribe(move |n| log.lock().unwrap().push((name, n)));
+}
+
+#[test]
+fn test_subscribe_ctl_unsubscribe_makes_one_shot_handler() {
+    let event = Event::<u32>::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let log_clone = Arc::clone(&log);
+    event.subscribe_ctl(move |n| {
+        log_clone.lock().unwrap().push(("once", n));
+        HandlerControl::Unsubscribe
+    });
+    record(&event, &log, "after");
+
+    event.invoke(1);
+    event.invoke(2);
+
+    assert_eq!(event.subscriber_count(), 1);
+    assert_eq!(*log.lock().unwrap(), vec![("once", 1), ("after", 1), ("after", 2)]);
+}
+
+#[test]
+fn test_subscribe_ctl_stop_propagation_skips_later_handlers() {
+    let event = Event::<u32>::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    record(&event, &log, "before");
+    event.subscribe_ctl(|n| {
+        if n % 2 == 0 {
+            HandlerControl::StopPropagation
+        } else {
+            HandlerControl::Continue
+        }
+    });
+    record(&event, &log, "after");
+    let mirror = event.mirror();
+    record(&mirror, &log, "mirror");
+
+    let report = event.try_invoke(2).unwrap();
+    assert!(report.stopped);
+    assert_eq!(report.handlers_called, 2);
+    assert!(!event.try_invoke(3).unwrap().stopped);
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![("before", 2), ("mirror", 2), ("before", 3), ("after", 3), ("mirror", 3)]
+    );
+}
+
+#[test]
+fn test_subscribe_ctl_in_batches() {
+    let event = Event::<u32>::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    event.subscribe_ctl(|n| {
+        if n == 2 {
+            HandlerControl::StopPropagation
+        } else {
+            HandlerControl::Continue
+        }
+    });
+    let log_clone = Arc::clone(&log);
+    event.subscribe_ctl(move |n| {
+        log_clone.lock().unwrap().push(("until 3", n));
+        if n == 3 {
+            HandlerControl::Unsubscribe
+        } else {
+            HandlerControl::Continue
+        }
+    });
+    record(&event, &log, "last");
+
+    event.invoke_batch(&[1, 2, 3, 4]);
+
+    assert_eq!(
+        *log.lock().unwrap(),
+        vec![("until 3", 1), ("until 3", 3), ("last", 1), ("last", 3), ("last", 4)]
+    );
+    assert_eq!(event.subscriber_count(), 2);
+}
+
+#[test]
+fn test_subscribe_ctl_borrowed() {
+    let event: Event<str, Borrowed> = Event::builder().borrowed().build();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe_ctl(move |text: &str| {
+        seen_clone.lock().unwrap().push(text.to_string());
+        HandlerControl::Unsubscribe
+    });
+
+    event.invoke("first");
+    event.invoke("second");
+    assert_eq!(*seen.lock().unwrap(), vec!["first"]);
+}
@@ -0,0 +1,40 @@
+use std::borrow::Cow;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use blob_event::Event;
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+// A payload counting its clones.
+#[derive(Debug, PartialEq)]
+struct Counted(u32);
+
+impl Clone for Counted {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, Ordering::SeqCst);
+        Counted(self.0)
+    }
+}
+
+#[test]
+fn test_subscribe_cow_clones_only_on_ownership() {
+    let event = Event::<Counted>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let kept = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe_cow(move |payload: Cow<Counted>| seen_clone.lock().unwrap().push(payload.0));
+    let kept_clone = Arc::clone(&kept);
+    event.subscribe_cow(move |payload: Cow<Counted>| {
+        if payload.0 > 1 {
+            kept_clone.lock().unwrap().push(payload.into_owned());
+        }
+    });
+
+    event.invoke(Counted(1));
+    event.invoke(Counted(2));
+
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+    assert_eq!(*kept.lock().unwrap(), vec![Counted(2)]);
+    assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+}
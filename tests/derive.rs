@@ -0,0 +1,120 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Borrowed, Event, EventEmitter, EventSet};
+
+#[derive(EventSet)]
+enum InputEvent {
+    Clicked(i32, i32),
+    KeyPressed { key: char },
+    Scrolled(f32),
+    HTTPRequest { path: String, status: u16 },
+    Move,
+}
+
+#[test]
+fn test_event_set_dispatches_each_variant_to_its_event() {
+    let events = InputEventEvents::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+
+    let seen_clone = Arc::clone(&seen);
+    events
+        .clicked
+        .subscribe(move |(x, y)| seen_clone.lock().unwrap().push(format!("clicked {} {}", x, y)));
+    let seen_clone = Arc::clone(&seen);
+    events
+        .key_pressed
+        .subscribe(move |key| seen_clone.lock().unwrap().push(format!("key {}", key)));
+    let seen_clone = Arc::clone(&seen);
+    events
+        .scrolled
+        .subscribe(move |delta| seen_clone.lock().unwrap().push(format!("scrolled {}", delta)));
+    let seen_clone = Arc::clone(&seen);
+    events
+        .http_request
+        .subscribe(move |(path, status)| seen_clone.lock().unwrap().push(format!("{} {}", path, status)));
+    let seen_clone = Arc::clone(&seen);
+    events
+        .r#move
+        .subscribe(move |()| seen_clone.lock().unwrap().push("move".to_string()));
+
+    events.dispatch(InputEvent::Clicked(1, 2));
+    events.dispatch(InputEvent::KeyPressed { key: 'q' });
+    events.dispatch(InputEvent::Scrolled(0.5));
+    events.dispatch(InputEvent::HTTPRequest {
+        path: "/".to_string(),
+        status: 200,
+    });
+    events.dispatch(InputEvent::Move);
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        ["clicked 1 2", "key q", "scrolled 0.5", "/ 200", "move"]
+    );
+}
+
+#[test]
+fn test_event_set_clones_share_events() {
+    let events = InputEventEvents::default();
+    let copy = events.clone();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    copy.scrolled
+        .subscribe(move |delta| seen_clone.lock().unwrap().push(delta));
+
+    events.dispatch(InputEvent::Scrolled(1.0));
+    assert_eq!(*seen.lock().unwrap(), [1.0]);
+    assert_eq!(events.clicked.subscriber_count(), 0);
+}
+
+#[derive(EventEmitter)]
+struct Player {
+    name: String,
+    damaged: Event<u32>,
+    said: Event<str, Borrowed>,
+    r#type: Event<()>,
+}
+
+impl Player {
+    fn new(name: &str) -> Self {
+        Player {
+            name: name.to_string(),
+            damaged: Event::new(),
+            said: Event::builder().borrowed().build(),
+            r#type: Event::new(),
+        }
+    }
+}
+
+#[test]
+fn test_event_emitter_wraps_each_event() {
+    let player = Player::new("ada");
+    let seen = Arc::new(Mutex::new(Vec::new()));
+
+    let seen_clone = Arc::clone(&seen);
+    let damaged =
+        player.subscribe_damaged(move |amount| seen_clone.lock().unwrap().push(format!("damaged {}", amount)));
+    let seen_clone = Arc::clone(&seen);
+    player.subscribe_said(move |line: &str| seen_clone.lock().unwrap().push(format!("said {}", line)));
+    let seen_clone = Arc::clone(&seen);
+    player.subscribe_type(move |()| seen_clone.lock().unwrap().push("type".to_string()));
+
+    player.emit_damaged(3);
+    player.emit_said("hi");
+    player.emit_type(());
+    assert!(player.unsubscribe_damaged(damaged));
+    assert!(!player.unsubscribe_damaged(damaged));
+    player.emit_damaged(4);
+
+    assert_eq!(*seen.lock().unwrap(), ["damaged 3", "said hi", "type"]);
+    assert_eq!(player.name, "ada");
+}
+
+#[test]
+fn test_event_emitter_unsubscribes_all_events() {
+    let player = Player::new("bo");
+    player.subscribe_damaged(|_| {});
+    player.subscribe_said(|_: &str| {});
+    player.unsubscribe_all_events();
+    assert_eq!(player.damaged.subscriber_count(), 0);
+    assert_eq!(player.said.subscriber_count(), 0);
+}
@@ -0,0 +1,75 @@
+use blob_event::Event;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[test]
+fn test_invoke_detached_returns_before_handlers_finish() {
+    let event = Event::<i32>::new();
+    let (release, released) = mpsc::channel::<()>();
+    let released = Mutex::new(released);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    event.subscribe(move |_| {
+        released.lock().unwrap().recv().unwrap();
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+    });
+
+    // The handler is blocked, but the caller isn't
+    event.invoke_detached(1);
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+
+    release.send(()).unwrap();
+    event.flush();
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_invoke_detached_keeps_order() {
+    let event = Event::<i32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |x| {
+        if x % 10 == 0 {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        seen_clone.lock().unwrap().push(x);
+    });
+
+    for i in 0..50 {
+        event.invoke_detached(i);
+    }
+    event.flush();
+
+    assert_eq!(*seen.lock().unwrap(), (0..50).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_invoke_detached_survives_handler_panic() {
+    let event = Event::<i32>::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    event.subscribe(move |x| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+        if x == 1 {
+            panic!("detached handler failed");
+        }
+    });
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    event.invoke_detached(1);
+    event.invoke_detached(2);
+    event.flush();
+    panic::set_hook(hook);
+
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_flush_without_detached_invokes_returns() {
+    let event = Event::<i32>::new();
+    event.flush();
+}
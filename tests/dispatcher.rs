@@ -0,0 +1,217 @@
+use blob_event::{Backpressure, DeliveryMode, Event};
+use std::panic;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+fn queued_event(capacity: usize, backpressure: Backpressure) -> Event<i32> {
+    Event::builder()
+        .delivery_mode(DeliveryMode::Queued { capacity, backpressure })
+        .build()
+}
+
+// Subscribes a handler that reports each value as it starts and then waits to be released.
+fn gated_handler(event: &Event<i32>) -> (Receiver<i32>, mpsc::Sender<()>) {
+    let (started, started_rx) = mpsc::channel();
+    let (release, released) = mpsc::channel::<()>();
+    let released = Mutex::new(released);
+    event.subscribe(move |x| {
+        started.send(x).unwrap();
+        released.lock().unwrap().recv().unwrap();
+    });
+    (started_rx, release)
+}
+
+#[test]
+fn test_queued_invoke_runs_handlers_on_dispatcher_thread() {
+    let event = queued_event(16, Backpressure::Block);
+    let (sender, receiver) = mpsc::channel();
+    event.subscribe(move |x| sender.send((x, thread::current().name().map(String::from))).unwrap());
+
+    event.invoke(1);
+    event.invoke(2);
+
+    let first = receiver.recv().unwrap();
+    let second = receiver.recv().unwrap();
+    assert_eq!((first.0, second.0), (1, 2));
+    assert_eq!(first.1.as_deref(), Some("blob-event-dispatcher"));
+}
+
+#[test]
+fn test_queued_invoke_does_not_wait_for_handlers() {
+    let event = queued_event(4, Backpressure::Block);
+    let (started, release) = gated_handler(&event);
+
+    event.invoke(1);
+    event.invoke(2);
+    assert_eq!(started.recv().unwrap(), 1);
+
+    release.send(()).unwrap();
+    assert_eq!(started.recv().unwrap(), 2);
+    release.send(()).unwrap();
+}
+
+#[test]
+fn test_drop_backpressure_discards_when_full() {
+    let event = queued_event(1, Backpressure::Drop);
+    let (started, release) = gated_handler(&event);
+
+    event.invoke(1);
+    assert_eq!(started.recv().unwrap(), 1);
+    // The handler is busy with 1, so 2 fills the queue and 3 is discarded
+    event.invoke(2);
+    event.invoke(3);
+
+    release.send(()).unwrap();
+    assert_eq!(started.recv().unwrap(), 2);
+    release.send(()).unwrap();
+    assert!(started.recv_timeout(Duration::from_millis(50)).is_err());
+}
+
+#[test]
+fn test_block_backpressure_waits_for_room() {
+    let event = queued_event(1, Backpressure::Block);
+    let (started, release) = gated_handler(&event);
+
+    event.invoke(1);
+    assert_eq!(started.recv().unwrap(), 1);
+    event.invoke(2);
+
+    let done = Arc::new(AtomicBool::new(false));
+    let producer = {
+        let event = event.clone();
+        let done = Arc::clone(&done);
+        thread::spawn(move || {
+            event.invoke(3);
+            done.store(true, Ordering::SeqCst);
+        })
+    };
+    thread::sleep(Duration::from_millis(50));
+    assert!(!done.load(Ordering::SeqCst));
+
+    for expected in [2, 3] {
+        release.send(()).unwrap();
+        assert_eq!(started.recv().unwrap(), expected);
+    }
+    release.send(()).unwrap();
+    producer.join().unwrap();
+    assert!(done.load(Ordering::SeqCst));
+}
+
+#[test]
+fn test_block_backpressure_doesnt_hold_up_invokes_from_handlers() {
+    // A capacity of zero leaves no room for an invocation made on the dispatcher thread.
+    let event = queued_event(0, Backpressure::Block);
+    let (sender, receiver) = mpsc::channel();
+    let invoking = event.clone();
+    event.subscribe(move |x| {
+        sender.send(x).unwrap();
+        if x > 1 {
+            invoking.invoke(x - 1);
+        }
+    });
+
+    event.invoke(3);
+
+    let received: Vec<i32> = (0..3)
+        .map(|_| receiver.recv_timeout(Duration::from_secs(5)).unwrap())
+        .collect();
+    assert_eq!(received, [3, 2, 1]);
+}
+
+#[test]
+fn test_dispatcher_survives_handler_panic() {
+    let event = queued_event(4, Backpressure::Block);
+    let (sender, receiver) = mpsc::channel();
+    event.subscribe(move |x| {
+        if x == 1 {
+            panic!("queued handler failed");
+        }
+        sender.send(x).unwrap();
+    });
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    event.invoke(1);
+    event.invoke(2);
+    let received = receiver.recv();
+    panic::set_hook(hook);
+
+    assert_eq!(received.unwrap(), 2);
+}
+
+#[test]
+fn test_coalesced_delivery_keeps_only_latest_args() {
+    let event: Event<i32> = Event::builder().delivery_mode(DeliveryMode::Coalesced).build();
+    let (started, release) = gated_handler(&event);
+
+    event.invoke(1);
+    assert_eq!(started.recv().unwrap(), 1);
+    // Arrive while the handler is busy, so only the newest survives
+    event.invoke(2);
+    event.invoke(3);
+    event.invoke(4);
+
+    release.send(()).unwrap();
+    assert_eq!(started.recv().unwrap(), 4);
+    release.send(()).unwrap();
+    assert!(started.recv_timeout(Duration::from_millis(50)).is_err());
+}
+
+#[test]
+fn test_pumped_event_runs_handlers_only_when_pumped() {
+    let event: Event<i32> = Event::builder().delivery_mode(DeliveryMode::Pumped).build();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |x| seen_clone.lock().unwrap().push((x, thread::current().id())));
+
+    let worker = {
+        let event = event.clone();
+        thread::spawn(move || {
+            event.invoke(1);
+            event.invoke(2);
+        })
+    };
+    worker.join().unwrap();
+    assert!(seen.lock().unwrap().is_empty());
+
+    assert_eq!(event.pump(), 2);
+    let here = thread::current().id();
+    assert_eq!(*seen.lock().unwrap(), vec![(1, here), (2, here)]);
+    assert_eq!(event.pump(), 0);
+}
+
+#[test]
+fn test_pump_leaves_invokes_from_handlers_for_next_pump() {
+    let event: Event<i32> = Event::builder().delivery_mode(DeliveryMode::Pumped).build();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    let event_clone = event.clone();
+    event.subscribe(move |x| {
+        seen_clone.lock().unwrap().push(x);
+        if x < 3 {
+            event_clone.invoke(x + 1);
+        }
+    });
+
+    event.invoke(1);
+    assert_eq!(event.pump(), 1);
+    assert_eq!(event.pump(), 1);
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn test_pump_from_other_thread_panics() {
+    let event: Event<i32> = Event::builder().delivery_mode(DeliveryMode::Pumped).build();
+    event.pump();
+
+    let other = event.clone();
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = thread::spawn(move || other.pump()).join();
+    panic::set_hook(hook);
+
+    assert!(result.is_err());
+}
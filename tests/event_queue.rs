@@ -0,0 +1,67 @@
+use blob_event::{Event, EventQueue};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+#[test]
+fn test_drain_dispatches_pushed_args_in_order() {
+    let mut queue = EventQueue::<i32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    queue.event().subscribe(move |x| seen_clone.lock().unwrap().push(x));
+
+    let producers: Vec<_> = (0..2)
+        .map(|p| {
+            let queue = queue.clone();
+            thread::spawn(move || {
+                for i in 0..3 {
+                    queue.push(p * 10 + i);
+                }
+            })
+        })
+        .collect();
+    for producer in producers {
+        producer.join().unwrap();
+    }
+    assert_eq!(queue.pending(), 6);
+    assert!(seen.lock().unwrap().is_empty());
+
+    assert_eq!(queue.drain(), 6);
+    let mut seen = seen.lock().unwrap().clone();
+    // Each producer's pushes keep their order
+    let first: Vec<_> = seen.iter().copied().filter(|x| *x < 10).collect();
+    assert_eq!(first, vec![0, 1, 2]);
+    seen.sort();
+    assert_eq!(seen, vec![0, 1, 2, 10, 11, 12]);
+    assert_eq!(queue.pending(), 0);
+}
+
+#[test]
+fn test_pushes_during_drain_wait_for_next_frame() {
+    let mut queue = EventQueue::<i32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    let pusher = queue.clone();
+    queue.event().subscribe(move |x| {
+        seen_clone.lock().unwrap().push(x);
+        pusher.push(x + 1);
+    });
+
+    queue.push(1);
+    assert_eq!(queue.drain(), 1);
+    assert_eq!(queue.pending(), 1);
+    assert_eq!(queue.drain(), 1);
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn test_with_event_delivers_to_existing_subscribers() {
+    let event = Event::<i32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |x| seen_clone.lock().unwrap().push(x));
+
+    let mut queue = EventQueue::with_event(event);
+    queue.push(7);
+    queue.drain();
+    assert_eq!(*seen.lock().unwrap(), vec![7]);
+}
@@ -0,0 +1,71 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use blob_event::testing::ManualScheduler;
+use blob_event::{Borrowed, Event, Scheduler, Task, with_scheduler};
+
+// A clock that is moved by hand and never runs its tasks, standing in for a late timer.
+#[derive(Clone)]
+struct StalledClock(Arc<Mutex<Instant>>);
+
+impl Scheduler for StalledClock {
+    fn now(&self) -> Instant {
+        *self.0.lock().unwrap()
+    }
+
+    fn schedule(&self, _deadline: Instant, _task: Task) {}
+}
+
+#[test]
+fn test_subscribe_for_unsubscribes_at_deadline() {
+    let clock = ManualScheduler::new();
+    let event = Event::<u32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    with_scheduler(clock.clone(), || {
+        event.subscribe_for(Duration::from_secs(5), move |n| seen_clone.lock().unwrap().push(n))
+    });
+
+    event.invoke(1);
+    clock.advance(Duration::from_millis(4999));
+    event.invoke(2);
+    assert_eq!(event.subscriber_count(), 1);
+    clock.advance(Duration::from_millis(1));
+    assert_eq!(event.subscriber_count(), 0);
+    event.invoke(3);
+
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn test_subscribe_for_skips_handler_when_timer_is_late() {
+    let clock = StalledClock(Arc::new(Mutex::new(Instant::now())));
+    let event: Event<str, Borrowed> = Event::builder().borrowed().build();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    with_scheduler(clock.clone(), || {
+        event.subscribe_for(Duration::from_secs(1), move |text: &str| {
+            seen_clone.lock().unwrap().push(text.to_string())
+        })
+    });
+
+    event.invoke("early");
+    *clock.0.lock().unwrap() += Duration::from_secs(1);
+    event.invoke("late");
+
+    assert_eq!(*seen.lock().unwrap(), vec!["early".to_string()]);
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_subscribe_for_can_be_unsubscribed_early() {
+    let clock = ManualScheduler::new();
+    let event = Event::<u32>::new();
+    let id = with_scheduler(clock.clone(), || event.subscribe_for(Duration::from_secs(5), |_| {}));
+    assert!(event.unsubscribe(id));
+    let other = event.subscribe(|_| {});
+
+    clock.advance(Duration::from_secs(5));
+
+    assert!(event.contains(other));
+}
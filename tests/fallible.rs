@@ -0,0 +1,47 @@
+use blob_event::FallibleEvent;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn test_fallible_invoke_runs_every_handler_and_collects_errors() {
+    let event = FallibleEvent::<i32, String>::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let calls_clone = Arc::clone(&calls);
+    let first = event.subscribe(move |x| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+        Err(format!("first rejected {}", x))
+    });
+    let calls_clone = Arc::clone(&calls);
+    event.subscribe(move |_| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+        Ok(())
+    });
+    let third = event.subscribe(|x| if x > 0 { Err("too big".to_string()) } else { Ok(()) });
+
+    let failures = event.invoke(5);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert_eq!(
+        failures,
+        vec![(first, "first rejected 5".to_string()), (third, "too big".to_string())]
+    );
+}
+
+#[test]
+fn test_fallible_invoke_without_failures_returns_empty() {
+    let event = FallibleEvent::<i32, ()>::new();
+    assert!(event.invoke(1).is_empty());
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    let sub = event.subscribe(move |x| {
+        seen_clone.lock().unwrap().push(x);
+        Ok(())
+    });
+    assert!(event.invoke(2).is_empty());
+    assert_eq!(event.subscriber_count(), 1);
+
+    assert!(event.unsubscribe(sub));
+    event.invoke(3);
+    assert_eq!(*seen.lock().unwrap(), vec![2]);
+}
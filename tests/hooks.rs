@@ -0,0 +1,104 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{DispatchError, DispatchReport, Event, EventHooks, InterceptDecision, Subscription};
+
+#[derive(Default)]
+struct Recorder(Mutex<Vec<String>>);
+
+impl EventHooks<i32> for Recorder {
+    fn on_subscribe(&self, _: Subscription) {
+        self.0.lock().unwrap().push("subscribe".to_string());
+    }
+
+    fn on_unsubscribe(&self, _: Subscription) {
+        self.0.lock().unwrap().push("unsubscribe".to_string());
+    }
+
+    fn on_invoke_start(&self, args: &i32) {
+        self.0.lock().unwrap().push(format!("start {}", args));
+    }
+
+    fn on_invoke_end(&self, outcome: Result<&DispatchReport, &DispatchError>) {
+        let entry = match outcome {
+            Ok(report) if report.vetoed => "vetoed".to_string(),
+            Ok(report) => format!("end {}", report.handlers_called),
+            Err(_) => "panicked".to_string(),
+        };
+        self.0.lock().unwrap().push(entry);
+    }
+}
+
+#[test]
+fn test_hooks_see_subscriptions_and_dispatches() {
+    let event = Event::<i32>::new();
+    let recorder = Arc::new(Recorder::default());
+    event.set_hooks(Arc::clone(&recorder));
+
+    let first = event.subscribe(|_| {});
+    event.subscribe(|_| {});
+    event.invoke(1);
+    event.unsubscribe(first);
+    event.unsubscribe(first);
+    event.invoke_batch(&[2, 3]);
+    event.unsubscribe_all();
+
+    let expected = [
+        "subscribe",
+        "subscribe",
+        "start 1",
+        "end 2",
+        "unsubscribe",
+        "start 2",
+        "end 1",
+        "start 3",
+        "end 1",
+        "unsubscribe",
+    ];
+    assert_eq!(*recorder.0.lock().unwrap(), expected);
+
+    event.clear_hooks();
+    event.invoke(4);
+    assert_eq!(recorder.0.lock().unwrap().len(), expected.len());
+}
+
+#[test]
+fn test_hooks_see_vetoes_and_caught_panics() {
+    let event = Event::<i32>::new();
+    let recorder = Arc::new(Recorder::default());
+    event.set_hooks(Arc::clone(&recorder));
+    event.add_interceptor(|n| {
+        if *n == 0 {
+            InterceptDecision::Veto
+        } else {
+            InterceptDecision::Continue
+        }
+    });
+    event.subscribe(|n| assert!(n > 1));
+
+    event.invoke(0);
+    let _ = event.try_invoke(1);
+
+    assert_eq!(
+        *recorder.0.lock().unwrap(),
+        ["subscribe", "start 0", "vetoed", "start 1", "panicked"]
+    );
+}
+
+#[test]
+fn test_hooks_may_use_the_event() {
+    struct Resubscribe(Mutex<Option<Event<i32>>>);
+
+    impl EventHooks<i32> for Resubscribe {
+        fn on_unsubscribe(&self, _: Subscription) {
+            if let Some(event) = self.0.lock().unwrap().take() {
+                event.subscribe(|_| {});
+            }
+        }
+    }
+
+    let event = Event::<i32>::new();
+    event.set_hooks(Resubscribe(Mutex::new(Some(event.clone()))));
+    let sub = event.subscribe(|_| {});
+    event.unsubscribe(sub);
+    assert_eq!(event.subscriber_count(), 1);
+}
@@ -0,0 +1,75 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Borrowed, DispatchError, Event, InterceptDecision};
+
+#[test]
+fn test_interceptors_observe_transform_and_veto() {
+    let event = Event::<i32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let received = Arc::new(Mutex::new(Vec::new()));
+
+    let log = Arc::clone(&seen);
+    event.add_interceptor(move |n| {
+        log.lock().unwrap().push(*n);
+        InterceptDecision::Continue
+    });
+    event.add_interceptor(|n| {
+        if *n < 0 {
+            InterceptDecision::Veto
+        } else {
+            InterceptDecision::Continue
+        }
+    });
+    event.add_interceptor(|n| InterceptDecision::Replace(Box::new(n * 10)));
+    let received_clone = Arc::clone(&received);
+    event.subscribe(move |n| received_clone.lock().unwrap().push(n));
+
+    event.invoke(1);
+    event.invoke(-1);
+    event.invoke_batch(&[2, -2, 3]);
+
+    assert_eq!(*seen.lock().unwrap(), vec![1, -1, 2, -2, 3]);
+    assert_eq!(*received.lock().unwrap(), vec![10, 20, 30]);
+    assert_eq!(event.subscriber_count(), 1);
+}
+
+#[test]
+fn test_vetoed_dispatch_is_reported_and_skips_mirrors() {
+    let event: Event<str, Borrowed> = Event::builder().borrowed().build();
+    let mirror = event.mirror();
+    let mirrored = Arc::new(Mutex::new(Vec::new()));
+    let mirrored_clone = Arc::clone(&mirrored);
+    mirror.subscribe(move |s: &str| mirrored_clone.lock().unwrap().push(s.to_string()));
+    event.subscribe(|_| {});
+
+    let interceptor = event.add_interceptor(|s: &str| {
+        if s.is_empty() {
+            InterceptDecision::Veto
+        } else {
+            InterceptDecision::Replace(s.to_uppercase().into_boxed_str())
+        }
+    });
+
+    let report = event.try_invoke("").unwrap();
+    assert!(report.vetoed);
+    assert_eq!(report.handlers_called, 0);
+    assert_eq!(event.invoke_counted("hi"), 1);
+    assert_eq!(*mirrored.lock().unwrap(), vec!["HI".to_string()]);
+
+    assert!(event.remove_interceptor(interceptor));
+    assert!(!event.remove_interceptor(interceptor));
+    assert!(!event.try_invoke("").unwrap().vetoed);
+}
+
+#[test]
+fn test_panicking_interceptor_is_reported_by_try_invoke() {
+    let event = Event::<i32>::new();
+    let calls = Arc::new(Mutex::new(0));
+    let calls_clone = Arc::clone(&calls);
+    event.subscribe(move |_| *calls_clone.lock().unwrap() += 1);
+    let interceptor = event.add_interceptor(|_| panic!("rejected"));
+
+    let error = event.try_invoke(1).unwrap_err();
+    assert!(matches!(error, DispatchError::HandlerPanicked { subscription, .. } if subscription == interceptor));
+    assert_eq!(*calls.lock().unwrap(), 0);
+}
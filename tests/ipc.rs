@@ -0,0 +1,126 @@
+#![cfg(all(feature = "ipc", unix))]
+
+use std::path::PathBuf;
+use std::sync::mpsc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use blob_event::ipc;
+
+fn socket_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("blob-event-{}-{}.sock", name, std::process::id()))
+}
+
+// Waits for the host to accept the given number of connections.
+fn wait_for_peers<Args: serde::Serialize + serde::de::DeserializeOwned + Clone + Send + 'static>(
+    host: &ipc::IpcEvent<Args>,
+    count: usize,
+) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while host.peer_count() < count {
+        assert!(Instant::now() < deadline, "peers never connected");
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn test_invokes_cross_the_socket_both_ways() {
+    let path = socket_path("both-ways");
+    let host = ipc::host::<(u32, String)>(&path).unwrap();
+    let (to_host, at_host) = mpsc::channel();
+    host.event().subscribe(move |args| to_host.send(args).unwrap());
+
+    let worker = ipc::connect::<(u32, String)>(&path).unwrap();
+    let (to_worker, at_worker) = mpsc::channel();
+    worker.event().subscribe(move |args| to_worker.send(args).unwrap());
+    wait_for_peers(&host, 1);
+
+    assert_eq!(worker.invoke((1, "from worker".to_string())).unwrap(), 1);
+    // Local handlers see the invocation too
+    assert_eq!(at_worker.recv().unwrap(), (1, "from worker".to_string()));
+    assert_eq!(
+        at_host.recv_timeout(Duration::from_secs(5)).unwrap(),
+        (1, "from worker".to_string())
+    );
+
+    assert_eq!(host.invoke((2, "from host".to_string())).unwrap(), 1);
+    assert_eq!(at_host.recv().unwrap(), (2, "from host".to_string()));
+    assert_eq!(
+        at_worker.recv_timeout(Duration::from_secs(5)).unwrap(),
+        (2, "from host".to_string())
+    );
+}
+
+#[test]
+fn test_host_forwards_to_every_connection() {
+    let path = socket_path("broadcast");
+    let host = ipc::host::<u32>(&path).unwrap();
+    let (sender, received) = mpsc::channel();
+    let workers: Vec<_> = (0..3)
+        .map(|i| {
+            let worker = ipc::connect::<u32>(&path).unwrap();
+            let sender = sender.clone();
+            worker.event().subscribe(move |args| sender.send((i, args)).unwrap());
+            worker
+        })
+        .collect();
+    wait_for_peers(&host, 3);
+
+    assert_eq!(host.invoke(7).unwrap(), 3);
+    let mut seen: Vec<_> = (0..3)
+        .map(|_| received.recv_timeout(Duration::from_secs(5)).unwrap())
+        .collect();
+    seen.sort();
+    assert_eq!(seen, vec![(0, 7), (1, 7), (2, 7)]);
+    drop(workers);
+}
+
+#[test]
+fn test_dropping_host_removes_socket() {
+    let path = socket_path("drop");
+    let host = ipc::host::<u32>(&path).unwrap();
+    let worker = ipc::connect::<u32>(&path).unwrap();
+    wait_for_peers(&host, 1);
+    assert!(path.exists());
+
+    drop(host);
+    assert!(!path.exists());
+    assert!(ipc::connect::<u32>(&path).is_err());
+    // The worker notices on its next invoke
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while worker.invoke(1).unwrap() == 1 {
+        assert!(Instant::now() < deadline, "the host's connection stayed open");
+        thread::sleep(Duration::from_millis(5));
+    }
+    assert_eq!(worker.peer_count(), 0);
+}
+
+#[test]
+fn test_host_replaces_stale_socket() {
+    let path = socket_path("stale");
+    let listener = std::os::unix::net::UnixListener::bind(&path).unwrap();
+    drop(listener);
+    assert!(path.exists());
+
+    let host = ipc::host::<u32>(&path).unwrap();
+    assert!(ipc::host::<u32>(&path).is_err());
+    ipc::connect::<u32>(&path).unwrap();
+    drop(host);
+}
+
+#[test]
+fn test_stalled_peer_is_dropped() {
+    let path = socket_path("stalled");
+    let host = ipc::host::<String>(&path).unwrap();
+    // Connected but never reading, so its socket buffer fills up.
+    let _stalled = std::os::unix::net::UnixStream::connect(&path).unwrap();
+    wait_for_peers(&host, 1);
+
+    // Once the buffer is full, a write blocks until it times out and the peer is dropped.
+    let line = "x".repeat(64 * 1024);
+    let deadline = Instant::now() + Duration::from_secs(30);
+    while host.invoke(line.clone()).unwrap() > 0 {
+        assert!(Instant::now() < deadline, "stalled peer was never dropped");
+    }
+    assert_eq!(host.peer_count(), 0);
+}
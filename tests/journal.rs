@@ -0,0 +1,209 @@
+#![cfg(feature = "journal")]
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Event, ReplayReport, Replayer};
+
+// A journal path unique to the test, removed when the guard drops.
+struct TempJournal(PathBuf);
+
+impl TempJournal {
+    fn new(name: &str) -> Self {
+        let path = std::env::temp_dir().join(format!("blob-event-{}-{}.jsonl", name, std::process::id()));
+        let _ = fs::remove_file(&path);
+        TempJournal(path)
+    }
+}
+
+impl Drop for TempJournal {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.0);
+    }
+}
+
+fn collecting<Args: Clone + Send + 'static>(event: &Event<Args>) -> Arc<Mutex<Vec<Args>>> {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |args| seen_clone.lock().unwrap().push(args));
+    seen
+}
+
+#[test]
+fn test_invoke_writes_then_dispatches() {
+    let temp = TempJournal::new("invoke");
+    let event = Event::<(u32, String)>::new();
+    let seen = collecting(&event);
+    let journal = event.journaled(&temp.0).unwrap();
+
+    journal.invoke((1, "a".to_string())).unwrap();
+    journal.invoke((2, "b".to_string())).unwrap();
+
+    assert_eq!(*seen.lock().unwrap(), vec![(1, "a".to_string()), (2, "b".to_string())]);
+    assert_eq!(
+        fs::read_to_string(&temp.0).unwrap(),
+        "{\"version\":0,\"args\":[1,\"a\"]}\n{\"version\":0,\"args\":[2,\"b\"]}\n"
+    );
+}
+
+#[test]
+fn test_replay_after_restart() {
+    let temp = TempJournal::new("replay");
+    {
+        let journal = Event::<u32>::new().journaled(&temp.0).unwrap();
+        journal.invoke(1).unwrap();
+        journal.invoke(2).unwrap();
+    }
+
+    let event = Event::<u32>::new();
+    let seen = collecting(&event);
+    let journal = event.journaled(&temp.0).unwrap();
+    assert_eq!(journal.replay().unwrap(), 2);
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+    // Replaying doesn't write the entries again
+    assert_eq!(journal.replay().unwrap(), 2);
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2, 1, 2]);
+}
+
+#[test]
+fn test_clear_empties_journal() {
+    let temp = TempJournal::new("clear");
+    let journal = Event::<u32>::new().journaled(&temp.0).unwrap();
+    journal.invoke(1).unwrap();
+    journal.clear().unwrap();
+    journal.invoke(2).unwrap();
+
+    let seen = collecting(&journal.event());
+    assert_eq!(journal.replay().unwrap(), 1);
+    assert_eq!(*seen.lock().unwrap(), vec![2]);
+}
+
+#[test]
+fn test_torn_entry_is_discarded() {
+    let temp = TempJournal::new("torn");
+    fs::write(
+        &temp.0,
+        "{\"version\":0,\"args\":1}\n{\"version\":0,\"args\":2}\n{\"vers",
+    )
+    .unwrap();
+
+    let event = Event::<u32>::new();
+    let seen = collecting(&event);
+    let journal = event.journaled(&temp.0).unwrap();
+    journal.invoke(4).unwrap();
+    seen.lock().unwrap().clear();
+
+    assert_eq!(journal.replay().unwrap(), 3);
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2, 4]);
+}
+
+#[test]
+fn test_invalid_entry_fails_replay() {
+    let temp = TempJournal::new("invalid");
+    fs::write(
+        &temp.0,
+        "{\"version\":0,\"args\":1}\n{\"version\":0,\"args\":\"two\"}\n",
+    )
+    .unwrap();
+
+    let event = Event::<u32>::new();
+    let seen = collecting(&event);
+    let journal = event.journaled(&temp.0).unwrap();
+    assert_eq!(journal.replay().unwrap_err().kind(), ErrorKind::InvalidData);
+    assert!(seen.lock().unwrap().is_empty());
+}
+
+// Writes a journal with one entry of each of three schema versions of the arguments.
+fn versioned_journal(temp: &TempJournal) {
+    let journal = Event::<u32>::new().journaled(&temp.0).unwrap();
+    journal.invoke(7).unwrap();
+    let journal = Event::<(u32, String)>::new()
+        .journaled(&temp.0)
+        .unwrap()
+        .schema_version(1);
+    journal.invoke((8, "eight".to_string())).unwrap();
+    let journal = Event::<(u32, String, bool)>::new()
+        .journaled(&temp.0)
+        .unwrap()
+        .schema_version(2);
+    journal.invoke((9, "nine".to_string(), true)).unwrap();
+}
+
+#[test]
+fn test_replayer_migrates_old_versions() {
+    let temp = TempJournal::new("migrate");
+    versioned_journal(&temp);
+
+    let event = Event::<(u32, String, bool)>::new();
+    let seen = collecting(&event);
+    let report = Replayer::new(2)
+        .migrate(0, |id: u32| (id, String::new(), false))
+        .migrate(1, |(id, name): (u32, String)| (id, name, false))
+        .replay(&temp.0, &event)
+        .unwrap();
+
+    assert_eq!(
+        report,
+        ReplayReport {
+            dispatched: 3,
+            skipped: 0
+        }
+    );
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            (7, String::new(), false),
+            (8, "eight".to_string(), false),
+            (9, "nine".to_string(), true)
+        ]
+    );
+}
+
+#[test]
+fn test_replayer_skips_versions() {
+    let temp = TempJournal::new("skip");
+    versioned_journal(&temp);
+
+    let event = Event::<(u32, String, bool)>::new();
+    let seen = collecting(&event);
+    let report = Replayer::new(2)
+        .skip(0)
+        .migrate(1, |(id, name): (u32, String)| (id, name, false))
+        .replay(&temp.0, &event)
+        .unwrap();
+    assert_eq!(
+        report,
+        ReplayReport {
+            dispatched: 2,
+            skipped: 1
+        }
+    );
+
+    let report = Replayer::new(2).skip_unknown().replay(&temp.0, &event).unwrap();
+    assert_eq!(
+        report,
+        ReplayReport {
+            dispatched: 1,
+            skipped: 2
+        }
+    );
+    assert_eq!(seen.lock().unwrap().len(), 3);
+}
+
+#[test]
+fn test_unknown_version_fails_replay() {
+    let temp = TempJournal::new("unknown");
+    versioned_journal(&temp);
+
+    let event = Event::<(u32, String, bool)>::new();
+    let seen = collecting(&event);
+    let error = Replayer::new(2).skip(0).replay(&temp.0, &event).unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::InvalidData);
+    assert!(seen.lock().unwrap().is_empty());
+
+    // The journal's own replay only reads its own version
+    let journal = event.journaled(&temp.0).unwrap().schema_version(2);
+    assert_eq!(journal.replay().unwrap_err().kind(), ErrorKind::InvalidData);
+}
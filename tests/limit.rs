@@ -0,0 +1,61 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Event, EventError, EventHooks, SubscriberLimit, Subscription};
+
+#[derive(Default)]
+struct LimitWatch {
+    reached: AtomicUsize,
+    unsubscribed: Mutex<Vec<Subscription>>,
+}
+
+impl<Args: ?Sized> EventHooks<Args> for LimitWatch {
+    fn on_unsubscribe(&self, subscription: Subscription) {
+        self.unsubscribed.lock().unwrap().push(subscription);
+    }
+
+    fn on_subscriber_limit(&self) {
+        self.reached.fetch_add(1, Ordering::Relaxed);
+    }
+}
+
+#[test]
+fn test_max_subscribers_rejects_beyond_limit() {
+    let event = Event::<i32>::with_max_subscribers(2);
+    let watch = Arc::new(LimitWatch::default());
+    event.set_hooks(Arc::clone(&watch));
+
+    let first = event.subscribe(|_| {});
+    event.subscribe(|_| {});
+    assert_eq!(event.try_subscribe(|_| {}), Err(EventError::TooManySubscribers));
+    let rejected = event.subscribe(|_| panic!("rejected handler called"));
+    assert!(!event.unsubscribe(rejected));
+    assert_eq!(event.subscriber_count(), 2);
+    assert_eq!(watch.reached.load(Ordering::Relaxed), 2);
+
+    event.invoke(1);
+    event.unsubscribe(first);
+    assert!(event.try_subscribe(|_| {}).is_ok());
+}
+
+#[test]
+fn test_max_subscribers_evicts_oldest() {
+    let event: Event<i32> = Event::builder()
+        .max_subscribers(2, SubscriberLimit::EvictOldest)
+        .build();
+    let watch = Arc::new(LimitWatch::default());
+    event.set_hooks(Arc::clone(&watch));
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let mut subs = Vec::new();
+    for name in ["a", "b", "c"] {
+        let calls_clone = Arc::clone(&calls);
+        subs.push(event.subscribe(move |_| calls_clone.lock().unwrap().push(name)));
+    }
+    event.invoke(1);
+
+    assert_eq!(*calls.lock().unwrap(), ["b", "c"]);
+    assert_eq!(*watch.unsubscribed.lock().unwrap(), [subs[0]]);
+    assert_eq!(watch.reached.load(Ordering::Relaxed), 1);
+    assert!(!event.unsubscribe(subs[0]));
+}
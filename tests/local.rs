@@ -0,0 +1,69 @@
+use blob_event::{Event, LocalEvent};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+#[test]
+fn test_local_event_accepts_non_send_handlers() {
+    let event = LocalEvent::<i32>::new();
+    let seen = Rc::new(RefCell::new(Vec::new()));
+
+    let seen_clone = Rc::clone(&seen);
+    let sub = event.subscribe(move |x| seen_clone.borrow_mut().push(x));
+    assert_eq!(event.subscriber_count(), 1);
+
+    event.invoke(1);
+    event.invoke(2);
+    assert!(event.unsubscribe(sub));
+    event.invoke(3);
+
+    assert_eq!(*seen.borrow(), vec![1, 2]);
+    assert!(!event.unsubscribe(sub));
+}
+
+#[test]
+fn test_local_event_handler_unsubscribes_itself() {
+    let event = LocalEvent::<()>::new();
+    let calls = Rc::new(RefCell::new(0));
+    let own_sub = Rc::new(RefCell::new(None));
+
+    let calls_clone = Rc::clone(&calls);
+    let own_sub_clone = Rc::clone(&own_sub);
+    let event_clone = event.clone();
+    let sub = event.subscribe(move |()| {
+        *calls_clone.borrow_mut() += 1;
+        if let Some(sub) = own_sub_clone.borrow_mut().take() {
+            event_clone.unsubscribe(sub);
+        }
+    });
+    *own_sub.borrow_mut() = Some(sub);
+
+    event.invoke(());
+    event.invoke(());
+    assert_eq!(*calls.borrow(), 1);
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_local_event_skips_handlers_removed_mid_dispatch() {
+    let event = LocalEvent::<()>::new();
+    let calls = Rc::new(RefCell::new(0));
+
+    let event_clone = event.clone();
+    event.subscribe(move |()| event_clone.unsubscribe_all());
+    let calls_clone = Rc::clone(&calls);
+    event.subscribe(move |()| *calls_clone.borrow_mut() += 1);
+
+    event.invoke(());
+    assert_eq!(*calls.borrow(), 0);
+}
+
+#[test]
+fn test_local_subscription_rejected_by_other_event() {
+    let local = LocalEvent::<i32>::new();
+    let shared = Event::<i32>::new();
+    shared.subscribe(|_| {});
+    let sub = local.subscribe(|_| {});
+
+    assert!(!shared.unsubscribe(sub));
+    assert_eq!(shared.subscriber_count(), 1);
+}
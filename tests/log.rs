@@ -0,0 +1,51 @@
+#![cfg(feature = "log")]
+
+use blob_event::Event;
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::panic;
+use std::sync::Mutex;
+
+// Collects the messages of every debug record.
+struct Collector {
+    messages: Mutex<Vec<String>>,
+}
+
+impl Log for Collector {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= Level::Debug
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        self.messages.lock().unwrap().push(record.args().to_string());
+    }
+
+    fn flush(&self) {}
+}
+
+static COLLECTOR: Collector = Collector {
+    messages: Mutex::new(Vec::new()),
+};
+
+#[test]
+fn test_lifecycle_is_logged() {
+    log::set_logger(&COLLECTOR).unwrap();
+    log::set_max_level(LevelFilter::Debug);
+
+    let event = Event::<i32>::new();
+    event.invoke(1);
+    let sub = event.subscribe(|x| assert!(x < 10, "too big"));
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let _ = event.try_invoke(10);
+    panic::set_hook(hook);
+    event.unsubscribe(sub);
+    let named = event.subscribe_named("audit", |_| {});
+
+    let messages = COLLECTOR.messages.lock().unwrap();
+    assert_eq!(messages.len(), 5);
+    assert!(messages[0].ends_with("with no subscribers"));
+    assert_eq!(messages[1], format!("subscribed {:?} with priority 0", sub));
+    assert_eq!(messages[2], format!("handler for {:?} panicked: too big", sub));
+    assert_eq!(messages[3], format!("unsubscribed {:?}", sub));
+    assert_eq!(messages[4], format!("subscribed {:?} (audit) with priority 0", named));
+}
@@ -0,0 +1,71 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use blob_event::Mailbox;
+
+#[test]
+fn test_mailbox_delivers_in_order_on_its_thread() {
+    let mailbox = Mailbox::<u32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    mailbox.subscribe(move |n| {
+        seen_clone
+            .lock()
+            .unwrap()
+            .push((n, thread::current().name().map(str::to_string)))
+    });
+
+    let address = mailbox.address();
+    let sender = thread::spawn(move || {
+        for n in 0..100 {
+            address.send(n);
+        }
+    });
+    sender.join().unwrap();
+    mailbox.join();
+
+    let seen = seen.lock().unwrap();
+    assert_eq!(
+        seen.iter().map(|(n, _)| *n).collect::<Vec<_>>(),
+        (0..100).collect::<Vec<_>>()
+    );
+    assert!(
+        seen.iter()
+            .all(|(_, name)| name.as_deref() == Some("blob-event-mailbox"))
+    );
+}
+
+#[test]
+fn test_mailbox_survives_panicking_handler() {
+    let mailbox = Mailbox::<u32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    mailbox.subscribe(move |n| {
+        if n == 1 {
+            panic!("bad message");
+        }
+        seen_clone.lock().unwrap().push(n);
+    });
+
+    let address = mailbox.address();
+    for n in 0..3 {
+        address.send(n);
+    }
+    drop(address);
+    mailbox.join();
+    assert_eq!(*seen.lock().unwrap(), [0, 2]);
+}
+
+#[test]
+fn test_address_keeps_mailbox_running() {
+    let mailbox = Mailbox::<u32>::new();
+    let (done, received) = std::sync::mpsc::channel();
+    mailbox.subscribe(move |n| done.send(n).unwrap());
+    let unused = mailbox.subscribe(|_| {});
+    assert!(mailbox.unsubscribe(unused));
+
+    let address = mailbox.address();
+    drop(mailbox);
+    address.clone().send(1);
+    assert_eq!(received.recv().unwrap(), 1);
+}
@@ -0,0 +1,189 @@
+#![cfg(feature = "mqtt")]
+
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+use blob_event::Event;
+use blob_event::mqtt::{self, MqttOptions};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+// What the fake broker received from the bridge.
+#[derive(Debug, PartialEq)]
+enum Received {
+    Subscribe(String),
+    Unsubscribe(String),
+    Publish(String, Vec<u8>),
+}
+
+// Just enough of an MQTT 3.1.1 broker to talk to one client.
+struct FakeBroker {
+    port: u16,
+    received: Receiver<Received>,
+    client: Arc<Mutex<Option<TcpStream>>>,
+}
+
+impl FakeBroker {
+    fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let (sender, received) = mpsc::channel();
+        let client = Arc::new(Mutex::new(None));
+        let writer = Arc::clone(&client);
+        thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            *writer.lock().unwrap() = Some(stream.try_clone().unwrap());
+            let reply = |bytes: &[u8]| writer.lock().unwrap().as_mut().unwrap().write_all(bytes).unwrap();
+            while let Some((header, body)) = read_packet(&mut stream) {
+                match header >> 4 {
+                    1 => reply(&[0x20, 2, 0, 0]),
+                    3 => {
+                        let (topic, rest) = read_string(&body);
+                        let qos = (header >> 1) & 3;
+                        let payload = if qos > 0 {
+                            reply(&[0x40, 2, rest[0], rest[1]]);
+                            &rest[2..]
+                        } else {
+                            rest
+                        };
+                        let _ = sender.send(Received::Publish(topic, payload.to_vec()));
+                    }
+                    8 => {
+                        reply(&[0x90, 3, body[0], body[1], 1]);
+                        let _ = sender.send(Received::Subscribe(read_string(&body[2..]).0));
+                    }
+                    10 => {
+                        reply(&[0xB0, 2, body[0], body[1]]);
+                        let _ = sender.send(Received::Unsubscribe(read_string(&body[2..]).0));
+                    }
+                    12 => reply(&[0xD0, 0]),
+                    14 => return,
+                    _ => {}
+                }
+            }
+        });
+        FakeBroker { port, received, client }
+    }
+
+    fn options(&self) -> MqttOptions {
+        MqttOptions::new("blob-event-test", "127.0.0.1", self.port)
+    }
+
+    // Sends a QoS 0 message to the client.
+    fn publish(&self, topic: &str, payload: &[u8]) {
+        let mut body = (topic.len() as u16).to_be_bytes().to_vec();
+        body.extend_from_slice(topic.as_bytes());
+        body.extend_from_slice(payload);
+        let mut packet = vec![0x30, body.len() as u8];
+        packet.extend(body);
+        self.client
+            .lock()
+            .unwrap()
+            .as_mut()
+            .unwrap()
+            .write_all(&packet)
+            .unwrap();
+    }
+
+    fn next(&self) -> Received {
+        self.received.recv_timeout(TIMEOUT).unwrap()
+    }
+}
+
+fn read_packet(stream: &mut TcpStream) -> Option<(u8, Vec<u8>)> {
+    let mut byte = [0];
+    stream.read_exact(&mut byte).ok()?;
+    let header = byte[0];
+    let (mut length, mut shift) = (0usize, 0);
+    loop {
+        stream.read_exact(&mut byte).ok()?;
+        length |= ((byte[0] & 0x7F) as usize) << shift;
+        shift += 7;
+        if byte[0] & 0x80 == 0 {
+            break;
+        }
+    }
+    let mut body = vec![0; length];
+    stream.read_exact(&mut body).ok()?;
+    Some((header, body))
+}
+
+fn read_string(bytes: &[u8]) -> (String, &[u8]) {
+    let length = u16::from_be_bytes([bytes[0], bytes[1]]) as usize;
+    let string = String::from_utf8(bytes[2..2 + length].to_vec()).unwrap();
+    (string, &bytes[2 + length..])
+}
+
+#[test]
+fn test_published_event_reaches_broker() {
+    let broker = FakeBroker::start();
+    let bridge = mqtt::connect(broker.options());
+    let setpoint = Event::<f32>::new();
+    bridge.publish(&setpoint, "home/setpoint");
+    assert_eq!(setpoint.subscriber_count(), 0);
+
+    setpoint.invoke(21.5);
+    assert_eq!(
+        broker.next(),
+        Received::Publish("home/setpoint".to_string(), b"21.5".to_vec())
+    );
+
+    assert!(bridge.unpublish("home/setpoint"));
+    assert!(!bridge.unpublish("home/setpoint"));
+}
+
+#[test]
+fn test_subscribed_filter_receives_matching_messages() {
+    let broker = FakeBroker::start();
+    let bridge = mqtt::connect(broker.options());
+    let temperatures = bridge.subscribe::<f32>("home/+/temperature").unwrap();
+    let (sender, received) = mpsc::channel();
+    temperatures.subscribe(move |celsius| sender.send(celsius).unwrap());
+    // Wait for the broker to know about the subscription
+    assert_eq!(broker.next(), Received::Subscribe("home/+/temperature".to_string()));
+
+    broker.publish("home/attic/humidity", b"40");
+    broker.publish("home/kitchen/temperature", b"\"not a number\"");
+    broker.publish("home/kitchen/temperature", b"19.5");
+    assert_eq!(received.recv_timeout(TIMEOUT).unwrap(), 19.5);
+    assert!(received.recv_timeout(Duration::from_millis(50)).is_err());
+
+    // Asking again returns the same event
+    assert_eq!(
+        bridge
+            .subscribe::<f32>("home/+/temperature")
+            .unwrap()
+            .subscriber_count(),
+        1
+    );
+}
+
+#[test]
+fn test_unsubscribe_stops_delivery() {
+    let broker = FakeBroker::start();
+    let bridge = mqtt::connect(broker.options());
+    let alerts = bridge.subscribe::<String>("alerts").unwrap();
+    let (sender, received) = mpsc::channel();
+    alerts.subscribe(move |alert| sender.send(alert).unwrap());
+    loop {
+        if let Received::Subscribe(filter) = broker.next() {
+            assert_eq!(filter, "alerts");
+            break;
+        }
+    }
+
+    assert!(bridge.unsubscribe("alerts"));
+    assert!(!bridge.unsubscribe("alerts"));
+    loop {
+        if let Received::Unsubscribe(filter) = broker.next() {
+            assert_eq!(filter, "alerts");
+            break;
+        }
+    }
+    broker.publish("alerts", b"\"fire\"");
+    assert!(received.recv_timeout(Duration::from_millis(50)).is_err());
+}
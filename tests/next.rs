@@ -0,0 +1,76 @@
+use std::future::Future;
+use std::pin::pin;
+use std::sync::Arc;
+use std::task::{Context, Poll, Wake, Waker};
+use std::thread::{self, Thread};
+
+use blob_event::{Borrowed, Event};
+
+struct Unpark(Thread);
+
+impl Wake for Unpark {
+    fn wake(self: Arc<Self>) {
+        self.0.unpark();
+    }
+}
+
+// Drives `future` to completion on the current thread.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(Unpark(thread::current())));
+    let mut cx = Context::from_waker(&waker);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        thread::park();
+    }
+}
+
+#[test]
+fn test_next_resolves_with_the_next_invocation() {
+    let event = Event::<i32>::new();
+    let next = event.next();
+    assert_eq!(event.subscriber_count(), 1);
+
+    let producer = event.clone();
+    let handle = thread::spawn(move || {
+        producer.invoke(1);
+        producer.invoke(2);
+    });
+    assert_eq!(block_on(next), 1);
+    handle.join().unwrap();
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_next_wakes_a_pending_task() {
+    let event: Event<String, Borrowed> = Event::builder().borrowed().build();
+    let waiter = event.clone();
+    let handle = thread::spawn(move || block_on(waiter.next()));
+
+    while event.subscriber_count() == 0 {
+        thread::yield_now();
+    }
+    event.invoke(&"ready".to_string());
+    assert_eq!(handle.join().unwrap(), "ready");
+}
+
+#[test]
+fn test_dropping_next_unsubscribes() {
+    let event = Event::<i32>::new();
+    let mut next = Box::pin(event.next());
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(next.as_mut().poll(&mut cx), Poll::Pending);
+    drop(next);
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+#[should_panic(expected = "subscriber limit")]
+fn test_next_on_full_event_panics_when_polled() {
+    let event = Event::<i32>::with_max_subscribers(1);
+    event.subscribe(|_| {});
+    let mut next = Box::pin(event.next());
+    let _ = next.as_mut().poll(&mut Context::from_waker(Waker::noop()));
+}
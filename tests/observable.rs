@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use blob_event::{MapChange, ObservableMap, ObservableVec, VecChange};
+
+fn record<T: Clone + Send + 'static>(vec: &ObservableVec<T>) -> Arc<Mutex<Vec<VecChange<T>>>> {
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = Arc::clone(&changes);
+    vec.changes()
+        .subscribe(move |change| changes_clone.lock().unwrap().push(change));
+    changes
+}
+
+#[test]
+fn test_observable_vec_dispatches_every_change() {
+    let mut vec = ObservableVec::new();
+    let changes = record(&vec);
+
+    vec.push('a');
+    vec.push('c');
+    vec.insert(1, 'b');
+    assert_eq!(vec.set(2, 'd'), 'c');
+    assert_eq!(vec.remove(0), 'a');
+    assert_eq!(vec.pop(), Some('d'));
+    vec.clear();
+
+    assert!(vec.is_empty());
+    assert_eq!(
+        *changes.lock().unwrap(),
+        vec![
+            VecChange::Pushed { index: 0, value: 'a' },
+            VecChange::Pushed { index: 1, value: 'c' },
+            VecChange::Inserted { index: 1, value: 'b' },
+            VecChange::Replaced {
+                index: 2,
+                old: 'c',
+                new: 'd'
+            },
+            VecChange::Removed { index: 0, value: 'a' },
+            VecChange::Removed { index: 1, value: 'd' },
+            VecChange::Cleared(vec!['b']),
+        ]
+    );
+}
+
+#[test]
+fn test_observable_vec_no_op_changes_dispatch_nothing() {
+    let mut vec = ObservableVec::<u32>::from_vec(vec![1, 2]);
+    let changes = record(&vec);
+
+    assert_eq!(vec.pop(), Some(2));
+    assert_eq!(vec.pop(), Some(1));
+    assert_eq!(vec.pop(), None);
+    vec.clear();
+
+    assert_eq!(changes.lock().unwrap().len(), 2);
+}
+
+#[test]
+fn test_observable_vec_reads_through_slice() {
+    let vec = ObservableVec::from_vec(vec![3, 1, 2]);
+    assert_eq!(vec.len(), 3);
+    assert_eq!(vec[1], 1);
+    assert_eq!(vec.iter().max(), Some(&3));
+    assert_eq!(format!("{:?}", vec), "[3, 1, 2]");
+    assert_eq!(vec.into_vec(), vec![3, 1, 2]);
+}
+
+#[test]
+fn test_observable_map_dispatches_every_change() {
+    let mut map = ObservableMap::new();
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = Arc::clone(&changes);
+    map.changes()
+        .subscribe(move |change| changes_clone.lock().unwrap().push(change));
+
+    assert_eq!(map.insert("a", 1), None);
+    assert_eq!(map.insert("a", 2), Some(1));
+    assert_eq!(map.remove("a"), Some(2));
+    assert_eq!(map.remove("a"), None);
+
+    assert!(map.is_empty());
+    assert_eq!(
+        *changes.lock().unwrap(),
+        vec![
+            MapChange::Inserted("a", 1),
+            MapChange::Updated {
+                key: "a",
+                old: 1,
+                new: 2
+            },
+            MapChange::Removed("a", 2),
+        ]
+    );
+}
+
+#[test]
+fn test_observable_map_retain_and_clear_remove_each_entry() {
+    let mut map = ObservableMap::from_map((0..6).map(|n| (n, n * 10)).collect::<HashMap<_, _>>());
+    let removed = Arc::new(Mutex::new(Vec::new()));
+    let removed_clone = Arc::clone(&removed);
+    map.changes().subscribe(move |change| {
+        if let MapChange::Removed(key, value) = change {
+            removed_clone.lock().unwrap().push((key, value));
+        }
+    });
+
+    map.retain(|key, _| key % 2 == 0);
+    assert_eq!(map.len(), 3);
+    removed.lock().unwrap().sort();
+    assert_eq!(*removed.lock().unwrap(), vec![(1, 10), (3, 30), (5, 50)]);
+
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(removed.lock().unwrap().len(), 6);
+}
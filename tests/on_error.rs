@@ -0,0 +1,75 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Borrowed, Event};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Disconnected(u32);
+
+#[test]
+fn test_emit_error_reaches_error_handlers_only() {
+    let event = Event::<i32>::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+    let (on_next, on_error) = (Arc::clone(&log), Arc::clone(&log));
+    event.subscribe_with_error(
+        move |n| on_next.lock().unwrap().push(format!("next {}", n)),
+        move |error: Disconnected| on_error.lock().unwrap().push(format!("error {:?}", error)),
+    );
+    let plain = Arc::clone(&log);
+    event.subscribe(move |n| plain.lock().unwrap().push(format!("plain {}", n)));
+
+    event.invoke(1);
+    assert_eq!(event.emit_error(Disconnected(7)), 1);
+
+    assert_eq!(*log.lock().unwrap(), ["next 1", "plain 1", "error Disconnected(7)"]);
+}
+
+#[test]
+fn test_emit_error_skips_handlers_of_other_error_types() {
+    let event: Event<str, Borrowed> = Event::builder().borrowed().build();
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let errors_clone = Arc::clone(&errors);
+    event.subscribe_with_error(
+        |_| {},
+        move |error: &String| errors_clone.lock().unwrap().push(error.clone()),
+    );
+    event.subscribe_with_error(|_| {}, |_: &Disconnected| panic!("wrong error type"));
+
+    assert_eq!(event.emit_error("timed out".to_string()), 1);
+    assert_eq!(event.emit_error(42u8), 0);
+    assert_eq!(*errors.lock().unwrap(), ["timed out"]);
+}
+
+#[test]
+fn test_emit_error_after_unsubscribe_or_close() {
+    let event = Event::<i32>::new();
+    let sub = event.subscribe_with_error(|_| {}, |_: Disconnected| panic!("unsubscribed"));
+    event.unsubscribe(sub);
+    assert_eq!(event.emit_error(Disconnected(1)), 0);
+
+    event.subscribe_with_error(|_| {}, |_: Disconnected| panic!("closed"));
+    event.close();
+    assert_eq!(event.emit_error(Disconnected(2)), 0);
+}
+
+#[test]
+fn test_emit_error_from_inside_a_handler() {
+    let event = Event::<i32>::new();
+    let errors = Arc::new(Mutex::new(Vec::new()));
+    let (inner_errors, outer_errors) = (Arc::clone(&errors), Arc::clone(&errors));
+    let emitter = event.clone();
+    let received = Arc::new(Mutex::new(None));
+    let report = Arc::clone(&received);
+    event.subscribe_with_error(
+        move |n| *report.lock().unwrap() = Some(emitter.emit_error(Disconnected(n as u32))),
+        move |error: Disconnected| inner_errors.lock().unwrap().push(("emitter", error)),
+    );
+    event.subscribe_with_error(
+        |_| {},
+        move |error: Disconnected| outer_errors.lock().unwrap().push(("other", error)),
+    );
+
+    event.invoke(3);
+
+    assert_eq!(*received.lock().unwrap(), Some(1));
+    assert_eq!(*errors.lock().unwrap(), [("other", Disconnected(3))]);
+}
@@ -0,0 +1,337 @@
+use blob_event::Event;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn test_merge_forwards_from_every_source() {
+    let a = Event::<i32>::new();
+    let b = Event::<i32>::new();
+    let c = Event::<i32>::new();
+    let merged = Event::merge(&[&a, &b, &c]);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    let sub = merged.subscribe(move |x| {
+        received_clone.lock().unwrap().push(x);
+    });
+
+    a.invoke(1);
+    b.invoke(2);
+    c.invoke(3);
+    a.invoke(4);
+
+    assert_eq!(*received.lock().unwrap(), vec![1, 2, 3, 4]);
+
+    merged.unsubscribe(sub);
+}
+
+#[test]
+fn test_merge_with_no_sources() {
+    let merged = Event::<i32>::merge(&[]);
+    assert_eq!(merged.subscriber_count(), 0);
+
+    // Nothing to forward from, but the merged event is still usable on its own
+    merged.invoke(1);
+}
+
+#[test]
+fn test_merge_stops_when_source_is_cleared() {
+    let a = Event::<i32>::new();
+    let b = Event::<i32>::new();
+    let merged = Event::merge(&[&a, &b]);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    merged.subscribe(move |x| {
+        received_clone.lock().unwrap().push(x);
+    });
+
+    a.unsubscribe_all();
+    a.invoke(1);
+    b.invoke(2);
+
+    assert_eq!(*received.lock().unwrap(), vec![2]);
+}
+
+#[test]
+fn test_dropped_merge_unsubscribes_from_sources() {
+    let a = Event::<i32>::new();
+    let b = Event::<i32>::new();
+    let merged = Event::merge(&[&a, &b]);
+    assert_eq!(a.subscriber_count(), 1);
+
+    drop(merged);
+    // The forwarding handlers notice on their next invocation
+    a.invoke(1);
+    b.invoke(2);
+    assert_eq!(a.subscriber_count(), 0);
+    assert_eq!(b.subscriber_count(), 0);
+}
+
+#[test]
+fn test_dropped_operators_unsubscribe_from_sources() {
+    let a = Event::<i32>::new();
+    let b = Event::<i32>::new();
+    drop(a.distinct());
+    drop(a.scan(0, |total, x| total + x));
+    drop(a.take(3));
+    drop(a.skip(1));
+    drop(a.buffered(2));
+    drop(Event::combine_latest(&a, &b));
+    drop(Event::zip(&a, &b));
+    assert_eq!(a.subscriber_count(), 7);
+
+    // Twice, for the buffer to emit a batch
+    a.invoke(1);
+    a.invoke(2);
+    b.invoke(1);
+    assert_eq!(a.subscriber_count(), 0);
+    assert_eq!(b.subscriber_count(), 0);
+}
+
+#[test]
+fn test_combine_latest_waits_for_both_sources() {
+    let a = Event::<i32>::new();
+    let b = Event::<String>::new();
+    let combined = Event::combine_latest(&a, &b);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    let sub = combined.subscribe(move |pair| {
+        received_clone.lock().unwrap().push(pair);
+    });
+
+    a.invoke(1);
+    a.invoke(2);
+    assert!(received.lock().unwrap().is_empty());
+
+    b.invoke("x".to_string());
+    a.invoke(3);
+    b.invoke("y".to_string());
+
+    assert_eq!(
+        *received.lock().unwrap(),
+        vec![(2, "x".to_string()), (3, "x".to_string()), (3, "y".to_string())]
+    );
+
+    combined.unsubscribe(sub);
+}
+
+#[test]
+fn test_zip_pairs_nth_emissions() {
+    let a = Event::<i32>::new();
+    let b = Event::<char>::new();
+    let zipped = Event::zip(&a, &b);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    let sub = zipped.subscribe(move |pair| {
+        received_clone.lock().unwrap().push(pair);
+    });
+
+    a.invoke(1);
+    a.invoke(2);
+    a.invoke(3);
+    b.invoke('a');
+    b.invoke('b');
+
+    assert_eq!(*received.lock().unwrap(), vec![(1, 'a'), (2, 'b')]);
+
+    // The third value of `a` is still queued for the next value of `b`
+    b.invoke('c');
+    b.invoke('d');
+    a.invoke(4);
+
+    assert_eq!(*received.lock().unwrap(), vec![(1, 'a'), (2, 'b'), (3, 'c'), (4, 'd')]);
+
+    zipped.unsubscribe(sub);
+}
+
+#[test]
+fn test_distinct_suppresses_consecutive_duplicates() {
+    let event = Event::<i32>::new();
+    let distinct = event.distinct();
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    let sub = distinct.subscribe(move |x| {
+        received_clone.lock().unwrap().push(x);
+    });
+
+    for x in [1, 1, 2, 2, 2, 1, 3, 3] {
+        event.invoke(x);
+    }
+
+    // Only consecutive repeats are dropped, so 1 comes through again after 2
+    assert_eq!(*received.lock().unwrap(), vec![1, 2, 1, 3]);
+
+    distinct.unsubscribe(sub);
+}
+
+#[test]
+fn test_scan_emits_running_total() {
+    let event = Event::<i32>::new();
+    let totals = event.scan(0, |total, x| total + x);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    let sub = totals.subscribe(move |total| {
+        received_clone.lock().unwrap().push(total);
+    });
+
+    for x in [1, 2, 3, 4] {
+        event.invoke(x);
+    }
+
+    assert_eq!(*received.lock().unwrap(), vec![1, 3, 6, 10]);
+
+    totals.unsubscribe(sub);
+}
+
+#[test]
+fn test_scan_running_average_across_threads() {
+    let event = Event::<f64>::new();
+    let averages = event.scan((0.0, 0u32), |(sum, count), x| (sum + x, count + 1));
+
+    let last = Arc::new(Mutex::new((0.0, 0)));
+    let last_clone = Arc::clone(&last);
+    averages.subscribe(move |state| {
+        *last_clone.lock().unwrap() = state;
+    });
+
+    let handles: Vec<_> = (0..4)
+        .map(|_| {
+            let event = event.clone();
+            std::thread::spawn(move || {
+                for _ in 0..25 {
+                    event.invoke(2.0);
+                }
+            })
+        })
+        .collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+
+    let (sum, count) = *last.lock().unwrap();
+    assert_eq!(count, 100);
+    assert_eq!(sum / count as f64, 2.0);
+}
+
+#[test]
+fn test_take_forwards_first_n_then_unsubscribes() {
+    let event = Event::<i32>::new();
+    let first_three = event.take(3);
+    assert_eq!(event.subscriber_count(), 1);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    first_three.subscribe(move |x| {
+        received_clone.lock().unwrap().push(x);
+    });
+
+    for x in 1..=5 {
+        event.invoke(x);
+    }
+
+    assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_take_zero_never_subscribes() {
+    let event = Event::<i32>::new();
+    let none = event.take(0);
+
+    assert_eq!(event.subscriber_count(), 0);
+    assert_eq!(none.subscriber_count(), 0);
+}
+
+#[test]
+fn test_skip_drops_first_n() {
+    let event = Event::<i32>::new();
+    let after_two = event.skip(2);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    after_two.subscribe(move |x| {
+        received_clone.lock().unwrap().push(x);
+    });
+
+    for x in 1..=5 {
+        event.invoke(x);
+    }
+
+    assert_eq!(*received.lock().unwrap(), vec![3, 4, 5]);
+}
+
+#[test]
+fn test_take_and_skip_compose_into_a_window() {
+    let event = Event::<i32>::new();
+    let window = event.skip(2).take(2);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    window.subscribe(move |x| {
+        received_clone.lock().unwrap().push(x);
+    });
+
+    for x in 1..=6 {
+        event.invoke(x);
+    }
+
+    assert_eq!(*received.lock().unwrap(), vec![3, 4]);
+}
+
+#[test]
+fn test_buffered_emits_fixed_size_batches() {
+    let event = Event::<i32>::new();
+    let batches = event.buffered(2);
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    batches.subscribe(move |batch| {
+        received_clone.lock().unwrap().push(batch);
+    });
+
+    for x in 1..=5 {
+        event.invoke(x);
+    }
+
+    assert_eq!(*received.lock().unwrap(), vec![vec![1, 2], vec![3, 4]]);
+}
+
+#[test]
+fn test_buffered_on_closed_source_never_emits() {
+    let event = Event::<i32>::new();
+    event.close();
+    let batches = event.buffered(2);
+    assert_eq!(batches.subscriber_count(), 0);
+    event.invoke(1);
+    event.invoke(2);
+}
+
+#[test]
+fn test_buffered_by_time_emits_window_contents() {
+    let event = Event::<i32>::new();
+    let batches = event.buffered_by_time(Duration::from_millis(30));
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    batches.subscribe(move |batch| {
+        received_clone.lock().unwrap().push(batch);
+    });
+
+    event.invoke(1);
+    event.invoke(2);
+    event.invoke(3);
+    assert!(received.lock().unwrap().is_empty());
+
+    thread::sleep(Duration::from_millis(250));
+    assert_eq!(*received.lock().unwrap(), vec![vec![1, 2, 3]]);
+
+    // Idle windows don't produce empty batches
+    thread::sleep(Duration::from_millis(100));
+    assert_eq!(received.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,51 @@
+#![cfg(all(feature = "signals", unix))]
+
+use std::sync::mpsc;
+use std::time::Duration;
+
+use blob_event::os_signals;
+use signal_hook::consts::signal::{SIGHUP, SIGKILL, SIGUSR1, SIGUSR2};
+use signal_hook::low_level::raise;
+
+#[test]
+fn test_signal_invokes_its_event() {
+    let (sender, received) = mpsc::channel();
+    os_signals::sigusr1().subscribe(move |()| sender.send(()).unwrap());
+
+    raise(SIGUSR1).unwrap();
+    received.recv_timeout(Duration::from_secs(5)).unwrap();
+    raise(SIGUSR1).unwrap();
+    received.recv_timeout(Duration::from_secs(5)).unwrap();
+}
+
+#[test]
+fn test_panicking_handler_doesnt_stop_later_signals() {
+    let (sender, received) = mpsc::channel();
+    let mut delivered = 0;
+    os_signals::sighup().subscribe(move |()| {
+        delivered += 1;
+        sender.send(delivered).unwrap();
+        if delivered == 1 {
+            panic!("handler failed");
+        }
+    });
+
+    // Raised one at a time, since pending signals of the same kind are merged
+    raise(SIGHUP).unwrap();
+    assert_eq!(received.recv_timeout(Duration::from_secs(5)).unwrap(), 1);
+    raise(SIGHUP).unwrap();
+    assert_eq!(received.recv_timeout(Duration::from_secs(5)).unwrap(), 2);
+}
+
+#[test]
+fn test_signal_event_is_shared() {
+    let first = os_signals::signal(SIGUSR2).unwrap();
+    let sub = first.subscribe(|()| {});
+    assert_eq!(os_signals::sigusr2().subscriber_count(), 1);
+    assert!(os_signals::sigusr2().unsubscribe(sub));
+}
+
+#[test]
+fn test_uncatchable_signal_is_refused() {
+    assert!(os_signals::signal(SIGKILL).is_err());
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "rayon")]
+
+use blob_event::{Borrowed, Event, HandlerControl};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Barrier};
+use std::time::Duration;
+
+#[test]
+fn test_invoke_parallel_calls_every_handler() {
+    let event = Event::<usize>::new();
+    let total = Arc::new(AtomicUsize::new(0));
+    for _ in 0..16 {
+        let total = Arc::clone(&total);
+        event.subscribe(move |x| {
+            total.fetch_add(x, Ordering::SeqCst);
+        });
+    }
+
+    event.invoke_parallel(2);
+    assert_eq!(total.load(Ordering::SeqCst), 32);
+}
+
+#[test]
+fn test_invoke_parallel_runs_handlers_concurrently() {
+    let threads = 4;
+    let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+
+    // Every handler waits for all the others, which only completes if they run at once
+    let event: Event<[u8], Borrowed> = Event::builder().borrowed().build();
+    let barrier = Arc::new(Barrier::new(threads));
+    for _ in 0..threads {
+        let barrier = Arc::clone(&barrier);
+        event.subscribe(move |_| {
+            barrier.wait();
+        });
+    }
+
+    pool.install(|| event.invoke_parallel(&[1, 2, 3]));
+}
+
+#[test]
+fn test_invoke_parallel_reaches_mirrors() {
+    let event = Event::<u32>::new();
+    let mirror = event.mirror();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    mirror.subscribe(move |_| {
+        std::thread::sleep(Duration::from_millis(1));
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+    });
+
+    event.invoke_parallel(1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_invoke_parallel_ignores_stop_propagation() {
+    let event = Event::<u32>::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    event.subscribe_ctl(|_| HandlerControl::StopPropagation);
+    let calls_clone = Arc::clone(&calls);
+    event.subscribe(move |_| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+    });
+    event.subscribe_ctl(|_| HandlerControl::Unsubscribe);
+
+    event.invoke_parallel(1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(event.subscriber_count(), 2);
+}
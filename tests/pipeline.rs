@@ -0,0 +1,63 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Borrowed, Event, HandlerControl, InterceptDecision};
+
+#[test]
+fn test_invoke_mut_passes_changes_along() {
+    let event = Event::<Vec<u32>>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    event.subscribe_mut(|list| list.push(1));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |list| seen_clone.lock().unwrap().push(list));
+    event.subscribe_mut(|list| list.retain(|n| *n != 0));
+    let mirror = event.mirror();
+    let seen_clone = Arc::clone(&seen);
+    mirror.subscribe(move |list| seen_clone.lock().unwrap().push(list));
+
+    let mut list = vec![0, 2];
+    event.invoke_mut(&mut list);
+
+    assert_eq!(list, vec![2, 1]);
+    assert_eq!(*seen.lock().unwrap(), vec![vec![0, 2, 1], vec![2, 1]]);
+}
+
+#[test]
+fn test_mut_handler_changes_a_copy_on_plain_invoke() {
+    let event: Event<String, Borrowed> = Event::builder().borrowed().build();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    event.subscribe_mut(|text: &mut String| text.push('!'));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |text: &String| seen_clone.lock().unwrap().push(text.clone()));
+
+    let text = "hi".to_string();
+    event.invoke(&text);
+    let mut shared = "hey".to_string();
+    event.invoke_mut(&mut shared);
+
+    assert_eq!(text, "hi");
+    assert_eq!(shared, "hey!");
+    assert_eq!(*seen.lock().unwrap(), vec!["hi".to_string(), "hey!".to_string()]);
+}
+
+#[test]
+fn test_invoke_mut_applies_interceptors_and_controls() {
+    let event = Event::<u32>::new();
+    event.add_interceptor(|n| {
+        if *n == 0 {
+            InterceptDecision::Veto
+        } else {
+            InterceptDecision::Replace(Box::new(n * 10))
+        }
+    });
+    event.subscribe_mut(|n| *n += 1);
+    event.subscribe_ctl(|_| HandlerControl::StopPropagation);
+    event.subscribe_mut(|n| *n = 0);
+
+    let mut n = 0;
+    event.invoke_mut(&mut n);
+    assert_eq!(n, 0);
+
+    n = 4;
+    event.invoke_mut(&mut n);
+    assert_eq!(n, 41);
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "prometheus")]
+
+use std::sync::{Arc, Barrier};
+use std::thread;
+
+use blob_event::Event;
+use blob_event::prometheus::MetricNames;
+use prometheus::Registry;
+use prometheus::proto::MetricFamily;
+
+fn family<'a>(families: &'a [MetricFamily], name: &str) -> &'a MetricFamily {
+    families.iter().find(|family| family.name() == name).unwrap()
+}
+
+#[test]
+fn test_exported_metrics_follow_the_event() {
+    let registry = Registry::new();
+    let event = Event::<i32>::new();
+    event.subscribe(|_| {});
+    event
+        .export_metrics(&registry, &MetricNames::with_prefix("app"))
+        .unwrap();
+
+    let sub = event.subscribe(|_| {});
+    event.invoke(1);
+    event.invoke_batch(&[2, 3]);
+    event.unsubscribe(sub);
+
+    let families = registry.gather();
+    let invocations = &family(&families, "app_invocations_total").get_metric()[0];
+    assert_eq!(invocations.get_counter().get_value(), 3.0);
+    let subscribers = &family(&families, "app_subscribers").get_metric()[0];
+    assert_eq!(subscribers.get_gauge().get_value(), 1.0);
+    let duration = &family(&families, "app_handler_duration_seconds").get_metric()[0];
+    // Two handlers for the invoke, and two for each item of the batch
+    assert_eq!(duration.get_histogram().get_sample_count(), 6);
+}
+
+#[test]
+fn test_export_twice_is_rejected() {
+    let registry = Registry::new();
+    let event = Event::<i32>::new();
+    event.export_metrics(&registry, &MetricNames::default()).unwrap();
+
+    let other = Registry::new();
+    assert!(event.export_metrics(&other, &MetricNames::default()).is_err());
+    assert!(
+        Event::<i32>::new()
+            .export_metrics(&registry, &MetricNames::default())
+            .is_err()
+    );
+}
+
+#[test]
+fn test_failed_export_leaves_the_registry_untouched() {
+    let registry = Registry::new();
+    let taken = prometheus::IntGauge::new("app_subscribers", "Taken by someone else").unwrap();
+    registry.register(Box::new(taken)).unwrap();
+
+    let event = Event::<i32>::new();
+    assert!(
+        event
+            .export_metrics(&registry, &MetricNames::with_prefix("app"))
+            .is_err()
+    );
+
+    let families = registry.gather();
+    assert_eq!(families.len(), 1);
+    assert_eq!(family(&families, "app_subscribers").help(), "Taken by someone else");
+}
+
+#[test]
+fn test_concurrent_exports_register_only_the_winner() {
+    let event = Event::<i32>::new();
+    let barrier = Arc::new(Barrier::new(4));
+    let exports: Vec<_> = (0..4)
+        .map(|_| {
+            let event = event.clone();
+            let barrier = Arc::clone(&barrier);
+            thread::spawn(move || {
+                let registry = Registry::new();
+                barrier.wait();
+                let exported = event.export_metrics(&registry, &MetricNames::default()).is_ok();
+                (exported, registry.gather().len())
+            })
+        })
+        .collect();
+
+    let results: Vec<_> = exports.into_iter().map(|export| export.join().unwrap()).collect();
+    assert_eq!(results.iter().filter(|(exported, _)| *exported).count(), 1);
+    for (exported, families) in results {
+        assert_eq!(families, if exported { 3 } else { 0 });
+    }
+}
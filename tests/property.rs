@@ -0,0 +1,210 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Computed, Property};
+
+#[test]
+fn test_property_dispatches_only_real_changes() {
+    let property = Property::new(1);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = Arc::clone(&changes);
+    property
+        .changed()
+        .subscribe(move |change| changes_clone.lock().unwrap().push(change));
+
+    assert!(property.set(2));
+    assert!(!property.set(2));
+    assert!(property.update(|value| *value *= 10));
+    assert!(!property.update(|_| {}));
+
+    assert_eq!(property.get(), 20);
+    assert_eq!(*changes.lock().unwrap(), vec![(1, 2), (2, 20)]);
+}
+
+#[test]
+fn test_concurrent_updates_are_not_lost() {
+    let counter = Property::new(0);
+    let changes = Arc::new(Mutex::new(0));
+    let changes_clone = Arc::clone(&changes);
+    counter
+        .changed()
+        .subscribe(move |_| *changes_clone.lock().unwrap() += 1);
+
+    std::thread::scope(|scope| {
+        for _ in 0..8 {
+            scope.spawn(|| {
+                for _ in 0..1000 {
+                    counter.update(|value| *value += 1);
+                }
+            });
+        }
+    });
+
+    assert_eq!(counter.get(), 8000);
+    assert_eq!(*changes.lock().unwrap(), 8000);
+}
+
+#[test]
+fn test_property_clones_share_value() {
+    let property = Property::new("a".to_string());
+    let other = property.clone();
+    other.set("b".to_string());
+    assert_eq!(property.get(), "b");
+    assert_eq!(format!("{:?}", property), "Property(\"b\")");
+}
+
+#[test]
+fn test_computed_recomputes_on_any_dependency_change() {
+    let first = Property::new("Ada".to_string());
+    let last = Property::new("Lovelace".to_string());
+    let full = Computed::new((&first, &last), |first, last| format!("{} {}", first, last));
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = Arc::clone(&changes);
+    full.changed()
+        .subscribe(move |(_, new)| changes_clone.lock().unwrap().push(new));
+
+    assert_eq!(full.get(), "Ada Lovelace");
+    first.set("Augusta".to_string());
+    last.set("King".to_string());
+
+    assert_eq!(full.get(), "Augusta King");
+    assert_eq!(*changes.lock().unwrap(), vec!["Augusta Lovelace", "Augusta King"]);
+}
+
+#[test]
+fn test_computed_unchanged_result_dispatches_nothing() {
+    let n = Property::new(3);
+    let parity = Computed::new((n.clone(),), |n| n % 2);
+    let count = Arc::new(Mutex::new(0));
+    let count_clone = Arc::clone(&count);
+    parity.changed().subscribe(move |_| *count_clone.lock().unwrap() += 1);
+
+    n.set(5);
+    n.set(6);
+    assert_eq!(parity.get(), 0);
+    assert_eq!(*count.lock().unwrap(), 1);
+}
+
+#[test]
+fn test_computed_chains_and_stops_when_dropped() {
+    let width = Property::new(2);
+    let height = Property::new(3);
+    let area = Computed::new((&width, &height), |w, h| w * h);
+    let doubled = Computed::new((&area,), |area| area * 2);
+
+    width.set(4);
+    assert_eq!(doubled.get(), 24);
+
+    drop(doubled);
+    drop(area);
+    width.set(5);
+    assert_eq!(width.changed().subscriber_count(), 0);
+}
+
+#[test]
+fn test_computed_cycle_panics() {
+    let input = Property::new(0);
+    let next = Computed::new((&input,), |n| n + 1);
+    let feedback = input.clone();
+    next.changed().subscribe(move |(_, n)| {
+        feedback.set(n);
+    });
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| input.set(1)));
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_bind_bidirectional_syncs_both_ways() {
+    let cents = Property::new(250);
+    let text = Property::new(String::new());
+    cents.bind_bidirectional(
+        &text,
+        |cents| format!("{}.{:02}", cents / 100, cents % 100),
+        |text: String| text.replace('.', "").parse().unwrap_or(0),
+    );
+    assert_eq!(text.get(), "2.50");
+
+    cents.set(1999);
+    assert_eq!(text.get(), "19.99");
+    text.set("4.20".to_string());
+    assert_eq!(cents.get(), 420);
+}
+
+#[test]
+fn test_bind_bidirectional_does_not_echo_lossy_conversions() {
+    let exact = Property::new(1.0_f64);
+    let rounded = Property::new(0_i64);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = Arc::clone(&changes);
+    exact
+        .changed()
+        .subscribe(move |(_, new)| changes_clone.lock().unwrap().push(new));
+    exact.bind_bidirectional(&rounded, |x| x.round() as i64, |n| n as f64);
+
+    exact.set(2.4);
+    assert_eq!(rounded.get(), 2);
+    assert_eq!(exact.get(), 2.4);
+    assert_eq!(*changes.lock().unwrap(), vec![2.4]);
+}
+
+#[test]
+fn test_property_binding_unbind() {
+    let a = Property::new(1);
+    let b = Property::new(0);
+    let binding = a.bind_bidirectional(&b, |n| n, |n| n);
+    binding.unbind();
+
+    a.set(2);
+    b.set(3);
+    assert_eq!((a.get(), b.get()), (2, 3));
+}
+
+#[test]
+fn test_bound_properties_are_freed_when_dropped() {
+    let value = Arc::new(1);
+    let tracked = Arc::downgrade(&value);
+    let a = Property::new(value);
+    let b = Property::new(Arc::new(0));
+    let binding = a.bind_bidirectional(&b, |n| n, |n| n);
+
+    drop((a, b, binding));
+
+    assert!(tracked.upgrade().is_none());
+}
+
+#[test]
+fn test_property_custom_equality_suppresses_small_changes() {
+    let reading = Property::with_equality(10.0_f64, |current, new| (current - new).abs() < 1.0);
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let changes_clone = Arc::clone(&changes);
+    reading
+        .changed()
+        .subscribe(move |change| changes_clone.lock().unwrap().push(change));
+
+    assert!(!reading.set(10.4));
+    assert!(!reading.set(10.9));
+    assert_eq!(reading.get(), 10.0);
+    assert!(reading.set(11.2));
+    assert!(!reading.update(|value| *value -= 0.5));
+
+    assert_eq!(*changes.lock().unwrap(), vec![(10.0, 11.2)]);
+}
+
+#[test]
+fn test_property_custom_equality_without_partial_eq() {
+    #[derive(Clone)]
+    struct Reading {
+        value: u32,
+    }
+
+    let reading = Property::with_equality(Reading { value: 1 }, |a, b| a.value / 10 == b.value / 10);
+    let count = Arc::new(Mutex::new(0));
+    let count_clone = Arc::clone(&count);
+    reading.changed().subscribe(move |_| *count_clone.lock().unwrap() += 1);
+
+    reading.set(Reading { value: 9 });
+    reading.set(Reading { value: 10 });
+    assert_eq!(reading.get().value, 10);
+    assert_eq!(*count.lock().unwrap(), 1);
+}
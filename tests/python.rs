@@ -0,0 +1,58 @@
+#![cfg(feature = "python")]
+
+use blob_event::Event;
+use blob_event::python::{self, PyEvent};
+use pyo3::prelude::*;
+use pyo3::types::PyModule;
+use std::ffi::CString;
+
+fn run(code: &str, event: PyEvent) -> Py<PyAny> {
+    Python::initialize();
+    Python::attach(|py| {
+        let module = PyModule::new(py, "blob_event").unwrap();
+        python::register(&module).unwrap();
+        let globals = pyo3::types::PyDict::new(py);
+        globals.set_item("blob_event", module).unwrap();
+        globals.set_item("event", Py::new(py, event).unwrap()).unwrap();
+        py.run(&CString::new(code).unwrap(), Some(&globals), None).unwrap();
+        globals.get_item("result").unwrap().unwrap().unbind()
+    })
+}
+
+#[test]
+fn test_python_subscribes_and_invokes() {
+    let result = run(
+        r#"
+seen = []
+sub = event.subscribe(seen.append)
+event.invoke(1)
+event.invoke("two")
+assert event.unsubscribe(sub)
+event.invoke(3)
+result = tuple(seen)
+"#,
+        PyEvent::from(Event::builder().borrowed().build()),
+    );
+
+    Python::attach(|py| {
+        let seen: (i32, String) = result.bind(py).extract().unwrap();
+        assert_eq!(seen, (1, "two".to_string()));
+    });
+}
+
+#[test]
+fn test_rust_invokes_python_handler() {
+    let event = Event::<i32>::new();
+    Python::initialize();
+    let seen = Python::attach(|py| pyo3::types::PyList::empty(py).unbind());
+    let append = Python::attach(|py| seen.bind(py).getattr("append").unwrap().unbind());
+    event.subscribe_py(append);
+
+    // Invoked from a thread that doesn't hold the GIL
+    std::thread::spawn(move || event.invoke(5)).join().unwrap();
+
+    Python::attach(|py| {
+        let seen: Vec<i32> = seen.bind(py).extract().unwrap();
+        assert_eq!(seen, vec![5]);
+    });
+}
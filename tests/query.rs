@@ -0,0 +1,67 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::QueryEvent;
+
+#[test]
+fn test_invoke_until_returns_first_answer_and_stops() {
+    let query = QueryEvent::<u32, Option<&'static str>>::new();
+    let asked = Arc::new(Mutex::new(Vec::new()));
+    for (name, answers) in [("small", 0..10), ("medium", 10..100), ("large", 0..1000)] {
+        let asked_clone = Arc::clone(&asked);
+        query.subscribe(move |n| {
+            asked_clone.lock().unwrap().push(name);
+            answers.contains(n).then_some(name)
+        });
+    }
+
+    assert_eq!(query.invoke_until(5), Some("small"));
+    assert_eq!(*asked.lock().unwrap(), vec!["small"]);
+    asked.lock().unwrap().clear();
+
+    assert_eq!(query.invoke_until(50), Some("medium"));
+    assert_eq!(query.invoke_until(5000), None);
+    assert_eq!(
+        *asked.lock().unwrap(),
+        vec!["small", "medium", "small", "medium", "large"]
+    );
+}
+
+#[test]
+fn test_invoke_until_without_handlers() {
+    let query = QueryEvent::<(), Option<u32>>::new();
+    assert_eq!(query.invoke_until(()), None);
+
+    let id = query.clone().subscribe(|_| Some(1));
+    assert_eq!(query.subscriber_count(), 1);
+    assert_eq!(query.invoke_until(()), Some(1));
+    assert!(query.unsubscribe(id));
+    assert_eq!(query.invoke_until(()), None);
+}
+
+#[test]
+fn test_invoke_fold_combines_answers_in_order() {
+    let query = QueryEvent::<u32, String>::new();
+    query.subscribe(|n| format!("a{}", n));
+    query.subscribe(|n| format!("b{}", n + 1));
+    query.subscribe(|n| format!("c{}", n + 2));
+
+    let joined = query.invoke_fold(1, String::new(), |mut acc, answer| {
+        acc.push_str(&answer);
+        acc
+    });
+    assert_eq!(joined, "a1b2c3");
+    assert_eq!(query.invoke_fold(1, 0, |count, _| count + 1), 3);
+}
+
+#[test]
+fn test_invoke_fold_aggregates() {
+    let query = QueryEvent::<(), u32>::new();
+    assert_eq!(query.invoke_fold((), 7, |_, answer| answer), 7);
+
+    for n in [4, 9, 2] {
+        query.subscribe(move |_| n);
+    }
+    assert_eq!(query.invoke_fold((), 0, u32::max), 9);
+    assert!(query.invoke_fold((), false, |any, n| any || n > 8));
+    assert!(!query.invoke_fold((), true, |all, n| all && n > 2));
+}
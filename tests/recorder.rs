@@ -0,0 +1,77 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use blob_event::{Event, EventRecorder, RecordedInvocation};
+
+#[test]
+fn test_recorder_captures_several_events_and_replays_them() {
+    let clicks = Event::<u32>::new();
+    let keys = Event::<u32>::new();
+    let mut recorder = EventRecorder::new();
+    recorder.record(&clicks);
+    recorder.record(&keys);
+
+    clicks.invoke(1);
+    keys.invoke(2);
+    clicks.invoke(3);
+    assert_eq!(clicks.subscriber_count(), 0);
+    recorder.stop();
+    clicks.invoke(4);
+
+    let records = recorder.records();
+    assert_eq!(
+        records.iter().map(|record| record.args).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert!(records.windows(2).all(|pair| pair[0].at <= pair[1].at));
+
+    let target = Event::<u32>::new();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    target.subscribe(move |n| received_clone.lock().unwrap().push(n));
+    recorder.replay(&target);
+    assert_eq!(*received.lock().unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn test_replaying_into_a_recorded_event_records_it_once() {
+    let event = Event::<u32>::new();
+    let mut recorder = EventRecorder::new();
+    recorder.record(&event);
+    event.invoke(7);
+
+    recorder.replay(&event);
+    assert_eq!(recorder.len(), 2);
+    recorder.clear();
+    assert!(recorder.is_empty());
+}
+
+#[test]
+fn test_timed_replay_keeps_the_gaps() {
+    let recorder = EventRecorder::from_records(vec![
+        RecordedInvocation {
+            at: Duration::from_millis(500),
+            args: 'a',
+        },
+        RecordedInvocation {
+            at: Duration::from_millis(550),
+            args: 'b',
+        },
+        RecordedInvocation {
+            at: Duration::from_millis(600),
+            args: 'c',
+        },
+    ]);
+    let event = Event::<char>::new();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    event.subscribe(move |c| received_clone.lock().unwrap().push(c));
+
+    let started = Instant::now();
+    recorder.replay_timed(&event);
+    let elapsed = started.elapsed();
+
+    assert_eq!(*received.lock().unwrap(), vec!['a', 'b', 'c']);
+    assert!(elapsed >= Duration::from_millis(100));
+    assert!(elapsed < Duration::from_millis(500));
+}
@@ -0,0 +1,99 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use blob_event::{DispatchError, Event, EventHooks, RecursionPolicy};
+
+// Subscribes a handler that invokes its own event again with the next number, counting calls.
+fn recursive(policy: RecursionPolicy) -> (Event<u32>, Arc<AtomicUsize>) {
+    let event: Event<u32> = Event::builder().max_recursion_depth(1, policy).build();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let (inner, calls_clone) = (event.clone(), Arc::clone(&calls));
+    event.subscribe(move |n| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+        inner.invoke(n + 1);
+    });
+    (event, calls)
+}
+
+#[test]
+fn test_recursion_beyond_limit_is_dropped() {
+    let (event, calls) = recursive(RecursionPolicy::Drop);
+    event.invoke(0);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // The depth unwinds with the dispatches, so the next invoke goes through again
+    event.invoke(0);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_recursion_beyond_limit_panics() {
+    let (event, calls) = recursive(RecursionPolicy::Panic);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| event.invoke(0)));
+    assert!(result.is_err());
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_recursion_overflow_goes_to_hook() {
+    struct Overflow(Mutex<Vec<u32>>);
+
+    impl EventHooks<u32> for Overflow {
+        fn on_recursion_overflow(&self, args: &u32) {
+            self.0.lock().unwrap().push(*args);
+        }
+    }
+
+    let (event, _) = recursive(RecursionPolicy::Hook);
+    let overflow = Arc::new(Overflow(Mutex::new(Vec::new())));
+    event.set_hooks(Arc::clone(&overflow));
+    event.invoke(0);
+    assert_eq!(*overflow.0.lock().unwrap(), [1]);
+}
+
+#[test]
+fn test_recursion_limit_covers_cycles_between_events() {
+    let ping: Event<u32> = Event::builder().max_recursion_depth(1, RecursionPolicy::Drop).build();
+    let pong = Event::<u32>::new();
+    let results = Arc::new(Mutex::new(Vec::new()));
+
+    let to_pong = pong.clone();
+    ping.subscribe(move |n| to_pong.invoke(n + 1));
+    let (to_ping, results_clone) = (ping.clone(), Arc::clone(&results));
+    pong.subscribe(move |n| {
+        results_clone.lock().unwrap().push(n);
+        assert_eq!(to_ping.try_invoke(n + 1), Err(DispatchError::RecursionLimit));
+    });
+
+    ping.invoke(0);
+    assert_eq!(*results.lock().unwrap(), [1]);
+}
+
+#[test]
+fn test_deeper_limit_skips_running_handlers() {
+    let event: Event<u32> = Event::builder().max_recursion_depth(3, RecursionPolicy::Drop).build();
+    let inner = event.clone();
+    event.subscribe(move |n| inner.invoke(n + 1));
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |n| seen_clone.lock().unwrap().push(n));
+
+    // The nested dispatch skips the handler that started it and reaches the other one
+    event.invoke(0);
+    assert_eq!(*seen.lock().unwrap(), [1, 0]);
+}
+
+#[test]
+fn test_deeper_limit_doesnt_hang_on_self_invoking_handler() {
+    let event: Event<u32> = Event::builder().max_recursion_depth(3, RecursionPolicy::Drop).build();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let (inner, calls_clone) = (event.clone(), Arc::clone(&calls));
+    event.subscribe(move |n| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+        assert_eq!(inner.try_invoke(n + 1).unwrap().handlers_skipped, 1);
+    });
+
+    event.invoke(0);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,202 @@
+#![cfg(feature = "redis")]
+
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use blob_event::Event;
+use blob_event::redis_bridge::{self, Client};
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+type Subscribers = Arc<Mutex<Vec<(String, TcpStream)>>>;
+
+// Just enough of a Redis server for pub/sub between its connections.
+struct FakeRedis {
+    port: u16,
+    subscribers: Subscribers,
+}
+
+impl FakeRedis {
+    fn start() -> Self {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let subscribers: Subscribers = Arc::default();
+        let shared = Arc::clone(&subscribers);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let stream = stream.unwrap();
+                let subscribers = Arc::clone(&shared);
+                thread::spawn(move || serve(stream, subscribers));
+            }
+        });
+        FakeRedis { port, subscribers }
+    }
+
+    fn client(&self) -> Client {
+        Client::open(format!("redis://127.0.0.1:{}/", self.port)).unwrap()
+    }
+
+    fn subscriber_count(&self) -> usize {
+        self.subscribers.lock().unwrap().len()
+    }
+}
+
+fn serve(stream: TcpStream, subscribers: Subscribers) {
+    let mut writer = stream.try_clone().unwrap();
+    let connection = writer.peer_addr().unwrap();
+    let mut reader = BufReader::new(stream);
+    while let Some(command) = read_command(&mut reader) {
+        let reply = match command[0].to_ascii_uppercase().as_slice() {
+            b"PUBLISH" => {
+                let channel = String::from_utf8(command[1].clone()).unwrap();
+                let mut subscribers = subscribers.lock().unwrap();
+                let mut delivered = 0;
+                for (subscribed, stream) in subscribers.iter_mut() {
+                    if *subscribed == channel {
+                        let message = encode(&[b"message", &command[1], &command[2]], None);
+                        delivered += stream.write_all(&message).is_ok() as usize;
+                    }
+                }
+                format!(":{}\r\n", delivered).into_bytes()
+            }
+            b"SUBSCRIBE" => {
+                let channel = String::from_utf8(command[1].clone()).unwrap();
+                let stream = writer.try_clone().unwrap();
+                subscribers.lock().unwrap().push((channel, stream));
+                encode(&[b"subscribe", &command[1]], Some(1))
+            }
+            b"UNSUBSCRIBE" => {
+                subscribers
+                    .lock()
+                    .unwrap()
+                    .retain(|(_, stream)| stream.peer_addr().ok() != Some(connection));
+                encode(&[b"unsubscribe", &command.get(1).cloned().unwrap_or_default()], Some(0))
+            }
+            b"PING" => b"+PONG\r\n".to_vec(),
+            _ => b"+OK\r\n".to_vec(),
+        };
+        if writer.write_all(&reply).is_err() {
+            return;
+        }
+    }
+}
+
+// Encodes an array of bulk strings, optionally followed by an integer.
+fn encode(parts: &[&[u8]], count: Option<i64>) -> Vec<u8> {
+    let mut bytes = format!("*{}\r\n", parts.len() + count.is_some() as usize).into_bytes();
+    for part in parts {
+        bytes.extend_from_slice(format!("${}\r\n", part.len()).as_bytes());
+        bytes.extend_from_slice(part);
+        bytes.extend_from_slice(b"\r\n");
+    }
+    if let Some(count) = count {
+        bytes.extend_from_slice(format!(":{}\r\n", count).as_bytes());
+    }
+    bytes
+}
+
+fn read_line(reader: &mut BufReader<TcpStream>) -> Option<String> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).ok()? == 0 {
+        return None;
+    }
+    Some(line.trim_end().to_string())
+}
+
+fn read_command(reader: &mut BufReader<TcpStream>) -> Option<Vec<Vec<u8>>> {
+    let count: usize = read_line(reader)?.strip_prefix('*')?.parse().ok()?;
+    let mut parts = Vec::new();
+    for _ in 0..count {
+        let length: usize = read_line(reader)?.strip_prefix('$')?.parse().ok()?;
+        let mut part = vec![0; length + 2];
+        reader.read_exact(&mut part).ok()?;
+        part.truncate(length);
+        parts.push(part);
+    }
+    Some(parts)
+}
+
+#[test]
+fn test_published_event_reaches_subscribed_event() {
+    let server = FakeRedis::start();
+    let client = server.client();
+    let invalidations = redis_bridge::subscribe::<String>(&client, "invalidate").unwrap();
+    let (sender, received) = mpsc::channel();
+    invalidations.subscribe(move |key| sender.send(key).unwrap());
+
+    let writes = Event::<String>::new();
+    let _publisher = redis_bridge::publish(&client, &writes, "invalidate").unwrap();
+    writes.invoke("user:42".to_string());
+    writes.invoke("user:7".to_string());
+
+    assert_eq!(received.recv_timeout(TIMEOUT).unwrap(), "user:42");
+    assert_eq!(received.recv_timeout(TIMEOUT).unwrap(), "user:7");
+}
+
+#[test]
+fn test_channels_are_kept_apart() {
+    let server = FakeRedis::start();
+    let client = server.client();
+    let prices = redis_bridge::subscribe::<f64>(&client, "prices").unwrap();
+    let (sender, received) = mpsc::channel();
+    prices.subscribe(move |price| sender.send(price).unwrap());
+
+    let orders = Event::<u32>::new();
+    let _orders_publisher = redis_bridge::publish(&client, &orders, "orders").unwrap();
+    let quotes = Event::<f64>::new();
+    let _quotes_publisher = redis_bridge::publish(&client, &quotes, "prices").unwrap();
+    orders.invoke(1);
+    quotes.invoke(12.5);
+
+    assert_eq!(received.recv_timeout(TIMEOUT).unwrap(), 12.5);
+    assert!(received.recv_timeout(Duration::from_millis(50)).is_err());
+}
+
+#[test]
+fn test_dropping_publisher_stops_publishing() {
+    let server = FakeRedis::start();
+    let client = server.client();
+    let ticks = redis_bridge::subscribe::<u32>(&client, "ticks").unwrap();
+    let (sender, received) = mpsc::channel();
+    ticks.subscribe(move |tick| sender.send(tick).unwrap());
+
+    let local = Event::<u32>::new();
+    let publisher = redis_bridge::publish(&client, &local, "ticks").unwrap();
+    // Publishing taps the event without subscribing to it
+    assert_eq!(local.subscriber_count(), 0);
+    local.invoke(1);
+    assert_eq!(received.recv_timeout(TIMEOUT).unwrap(), 1);
+    drop(publisher);
+    local.invoke(2);
+    assert!(received.recv_timeout(Duration::from_millis(50)).is_err());
+}
+
+#[test]
+fn test_dropped_event_ends_subscription() {
+    let server = FakeRedis::start();
+    let client = server.client();
+    let ticks = redis_bridge::subscribe::<u32>(&client, "ticks").unwrap();
+    assert_eq!(server.subscriber_count(), 1);
+    drop(ticks);
+
+    // The subscription notices within its polling interval and leaves the channel
+    let deadline = Instant::now() + TIMEOUT;
+    while server.subscriber_count() > 0 {
+        assert!(Instant::now() < deadline, "the subscription was never ended");
+        thread::sleep(Duration::from_millis(20));
+    }
+}
+
+#[test]
+fn test_unreachable_server_fails() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let port = listener.local_addr().unwrap().port();
+    drop(listener);
+    let client = Client::open(format!("redis://127.0.0.1:{}/", port)).unwrap();
+    assert!(redis_bridge::subscribe::<u32>(&client, "ticks").is_err());
+    assert!(redis_bridge::publish(&client, &Event::<u32>::new(), "ticks").is_err());
+}
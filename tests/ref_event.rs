@@ -0,0 +1,73 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{BorrowedArgs, RefEvent};
+
+struct Line;
+
+impl BorrowedArgs for Line {
+    type Args<'a> = (usize, &'a str);
+}
+
+#[test]
+fn test_ref_event_dispatches_short_lived_borrows() {
+    let event = RefEvent::<Line>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |(number, text)| seen_clone.lock().unwrap().push(format!("{}: {}", number, text)));
+    let total = Arc::new(Mutex::new(0));
+    let total_clone = Arc::clone(&total);
+    event.subscribe(move |(_, text): (usize, &str)| *total_clone.lock().unwrap() += text.len());
+
+    let file = String::from("first\nsecond");
+    for (number, text) in file.lines().enumerate() {
+        event.invoke((number + 1, text));
+    }
+    {
+        let temporary = format!("line {}", 3);
+        event.invoke((3, &temporary));
+    }
+
+    assert_eq!(*seen.lock().unwrap(), vec!["1: first", "2: second", "3: line 3"]);
+    assert_eq!(*total.lock().unwrap(), 17);
+}
+
+#[test]
+fn test_ref_event_unsubscribe() {
+    let event = RefEvent::<Line>::default();
+    let calls = Arc::new(Mutex::new(0));
+    let calls_clone = Arc::clone(&calls);
+    let id = event.subscribe(move |_| *calls_clone.lock().unwrap() += 1);
+    let clone = event.clone();
+
+    clone.invoke((1, "a"));
+    assert!(event.unsubscribe(id));
+    clone.invoke((2, "b"));
+
+    assert_eq!(*calls.lock().unwrap(), 1);
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_ref_event_handler_invoking_its_own_event() {
+    let event = RefEvent::<Line>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let reinvoking = event.clone();
+    let first = Arc::clone(&seen);
+    event.subscribe(move |(number, text): (usize, &str)| {
+        first.lock().unwrap().push(format!("first {}", number));
+        if number == 1 {
+            let echo = format!("echo of {}", text);
+            reinvoking.invoke((2, &echo));
+        }
+    });
+    let second = Arc::clone(&seen);
+    event.subscribe(move |(number, text)| second.lock().unwrap().push(format!("second {}: {}", number, text)));
+
+    event.invoke((1, "ping"));
+
+    // The nested invoke skips the first handler, which is still running.
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec!["first 1", "second 2: echo of ping", "second 1: ping"]
+    );
+}
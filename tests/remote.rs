@@ -0,0 +1,168 @@
+#![cfg(feature = "remote")]
+
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use blob_event::{Event, remote};
+
+// Waits for the server to accept the given number of clients.
+fn wait_for_clients(server: &remote::Server, count: usize) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while server.client_count() < count {
+        assert!(Instant::now() < deadline, "clients never connected");
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn test_published_events_reach_clients() {
+    let prices = Event::<(String, f64)>::new();
+    let server = remote::serve("127.0.0.1:0").unwrap();
+    server.publish("prices", &prices);
+    assert_eq!(prices.subscriber_count(), 0);
+
+    let client = remote::connect(server.local_addr()).unwrap();
+    let (sender, received) = mpsc::channel();
+    client
+        .event::<(String, f64)>("prices")
+        .subscribe(move |args| sender.send(args).unwrap());
+    wait_for_clients(&server, 1);
+
+    prices.invoke(("ACME".to_string(), 12.5));
+    prices.invoke(("INIT".to_string(), 3.0));
+    assert_eq!(
+        received.recv_timeout(Duration::from_secs(5)).unwrap(),
+        ("ACME".to_string(), 12.5)
+    );
+    assert_eq!(
+        received.recv_timeout(Duration::from_secs(5)).unwrap(),
+        ("INIT".to_string(), 3.0)
+    );
+}
+
+#[test]
+fn test_events_are_routed_by_name() {
+    let temperature = Event::<f32>::new();
+    let alerts = Event::<String>::new();
+    let server = remote::serve("127.0.0.1:0").unwrap();
+    server.publish("temperature", &temperature);
+    server.publish("alerts", &alerts);
+
+    let client = remote::connect(server.local_addr()).unwrap();
+    let (sender, received) = mpsc::channel();
+    client
+        .event::<String>("alerts")
+        .subscribe(move |alert| sender.send(alert).unwrap());
+    wait_for_clients(&server, 1);
+
+    temperature.invoke(21.5);
+    alerts.invoke("overheating".to_string());
+    assert_eq!(received.recv_timeout(Duration::from_secs(5)).unwrap(), "overheating");
+    assert!(received.recv_timeout(Duration::from_millis(50)).is_err());
+    // Asking again returns the same event
+    assert_eq!(client.event::<String>("alerts").subscriber_count(), 1);
+}
+
+#[test]
+fn test_unpublish_stops_delivery() {
+    let ticks = Event::<u32>::new();
+    let server = remote::serve("127.0.0.1:0").unwrap();
+    server.publish("ticks", &ticks);
+
+    let client = remote::connect(server.local_addr()).unwrap();
+    let (sender, received) = mpsc::channel();
+    client
+        .event::<u32>("ticks")
+        .subscribe(move |tick| sender.send(tick).unwrap());
+    wait_for_clients(&server, 1);
+
+    ticks.invoke(1);
+    assert_eq!(received.recv_timeout(Duration::from_secs(5)).unwrap(), 1);
+    assert!(server.unpublish("ticks"));
+    assert!(!server.unpublish("ticks"));
+    ticks.invoke(2);
+    assert!(received.recv_timeout(Duration::from_millis(50)).is_err());
+}
+
+#[test]
+fn test_dropped_client_is_forgotten() {
+    let ticks = Event::<u32>::new();
+    let server = remote::serve("127.0.0.1:0").unwrap();
+    server.publish("ticks", &ticks);
+    let client = remote::connect(server.local_addr()).unwrap();
+    wait_for_clients(&server, 1);
+
+    drop(client);
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while server.client_count() > 0 {
+        assert!(Instant::now() < deadline, "the client's connection stayed open");
+        ticks.invoke(1);
+        thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+#[should_panic(expected = "another type")]
+fn test_event_with_another_type_panics() {
+    let server = remote::serve("127.0.0.1:0").unwrap();
+    let client = remote::connect(server.local_addr()).unwrap();
+    client.event::<u32>("ticks");
+    client.event::<String>("ticks");
+}
+
+#[test]
+fn test_stalled_client_doesnt_block_invoke() {
+    let frames = Event::<String>::new();
+    let server = remote::serve("127.0.0.1:0").unwrap();
+    server.publish("frames", &frames);
+    // Connected but never reading, so its socket buffers fill up.
+    let _stalled = std::net::TcpStream::connect(server.local_addr()).unwrap();
+    wait_for_clients(&server, 1);
+
+    // More than the client may fall behind by, even after its socket buffers took their
+    // share. Writing to the client directly would block here for good.
+    let frame = "x".repeat(8 * 1024);
+    for _ in 0..3000 {
+        frames.invoke(frame.clone());
+    }
+
+    assert_eq!(server.client_count(), 0);
+}
+
+// Blocks whoever serializes it until the paired sender is dropped.
+#[derive(Clone)]
+struct Stalling(Arc<Mutex<mpsc::Receiver<()>>>);
+
+impl serde::Serialize for Stalling {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let _ = self.0.lock().unwrap().recv();
+        serializer.serialize_unit()
+    }
+}
+
+#[test]
+fn test_dropped_server_releases_its_port() {
+    let event = Event::<Stalling>::new();
+    let server = remote::serve("127.0.0.1:0").unwrap();
+    let address = server.local_addr();
+    server.publish("stalling", &event);
+
+    // Keeps the published event's mirror, and with it the server's clients, alive while the
+    // server is dropped.
+    let (release, stalled) = mpsc::channel();
+    let stalled = Stalling(Arc::new(Mutex::new(stalled)));
+    let invoking = thread::spawn(move || event.invoke(stalled));
+    thread::sleep(Duration::from_millis(50));
+    drop(server);
+    thread::sleep(Duration::from_millis(50));
+    drop(release);
+    invoking.join().unwrap();
+
+    // The listener closes once the thread accepting clients notices, which may take a moment.
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while remote::serve(address).is_err() {
+        assert!(Instant::now() < deadline, "the server kept its port bound");
+        thread::sleep(Duration::from_millis(5));
+    }
+}
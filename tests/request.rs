@@ -0,0 +1,73 @@
+use std::thread;
+use std::time::Duration;
+
+use blob_event::RequestEvent;
+
+#[test]
+fn test_ask_returns_first_response() {
+    let ping = RequestEvent::<u32, u32>::new();
+    ping.subscribe(|_, _| {});
+    ping.subscribe(|n, responder| {
+        responder.respond(n + 1);
+    });
+    ping.subscribe(|n, responder| {
+        responder.respond(n + 2);
+    });
+
+    assert_eq!(ping.ask(1), Some(2));
+}
+
+#[test]
+fn test_ask_all_collects_responses() {
+    let votes = RequestEvent::<&'static str, bool>::new();
+    assert!(votes.ask_all("quit").is_empty());
+
+    votes.subscribe(|_, responder| {
+        responder.respond(true);
+    });
+    let sub = votes.subscribe(|_, responder| {
+        responder.respond(false);
+    });
+    // Drops its responder without answering
+    votes.subscribe(|_, _| {});
+
+    assert_eq!(votes.ask_all("quit"), [true, false]);
+    assert!(votes.unsubscribe(sub));
+    assert_eq!(votes.ask_all("quit"), [true]);
+}
+
+#[test]
+fn test_responders_can_answer_from_other_threads() {
+    let work = RequestEvent::<u64, u64>::new();
+    work.subscribe(|n, responder| {
+        let n = *n;
+        thread::spawn(move || {
+            thread::sleep(Duration::from_millis(10));
+            responder.respond(n * 2);
+        });
+    });
+
+    assert_eq!(work.ask(21), Some(42));
+    assert_eq!(work.ask_all(1), [2]);
+}
+
+#[test]
+fn test_ask_timeout_gives_up_on_kept_responders() {
+    let slow = RequestEvent::<(), u8>::new();
+    let (keep, kept) = std::sync::mpsc::channel();
+    slow.subscribe(move |_, responder| {
+        // Kept alive without ever answering
+        keep.send(responder).unwrap();
+    });
+    slow.subscribe(|_, responder| {
+        responder.respond(1);
+    });
+
+    assert_eq!(slow.ask_all_timeout((), Duration::from_millis(20)), [1]);
+    assert_eq!(slow.ask_timeout((), Duration::from_millis(20)), Some(1));
+
+    let first = kept.recv().unwrap();
+    drop(slow);
+    // Nobody is waiting anymore
+    assert!(!first.respond(0));
+}
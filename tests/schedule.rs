@@ -0,0 +1,135 @@
+use blob_event::schedule::cron;
+use blob_event::testing::ManualScheduler;
+use blob_event::with_scheduler;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+fn counting(job: &blob_event::schedule::CronJob) -> Arc<AtomicUsize> {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    job.event().subscribe(move |_| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+    });
+    calls
+}
+
+#[test]
+fn test_cron_fires_every_matching_minute() {
+    let clock = ManualScheduler::new();
+    let job = with_scheduler(clock.clone(), || cron("* * * * *").unwrap());
+    let calls = counting(&job);
+
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    clock.advance(Duration::from_secs(120));
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_cron_hourly_over_a_day() {
+    let clock = ManualScheduler::new();
+    let job = with_scheduler(clock.clone(), || cron("0 * * * *").unwrap());
+    let calls = counting(&job);
+
+    clock.advance(Duration::from_secs(24 * 60 * 60));
+    assert_eq!(calls.load(Ordering::SeqCst), 24);
+}
+
+#[test]
+fn test_cron_passes_due_time() {
+    let clock = ManualScheduler::new();
+    let job = with_scheduler(clock.clone(), || cron("*/5 * * * *").unwrap());
+    let due = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let due_clone = Arc::clone(&due);
+    job.event().subscribe(move |at| due_clone.lock().unwrap().push(at));
+
+    clock.advance(Duration::from_secs(15 * 60));
+    let due = due.lock().unwrap();
+    assert_eq!(due.len(), 3);
+    assert_eq!(due[1] - due[0], Duration::from_secs(5 * 60));
+    assert_eq!(due[2] - due[1], Duration::from_secs(5 * 60));
+}
+
+#[test]
+fn test_cancel_stops_job() {
+    let clock = ManualScheduler::new();
+    let job = with_scheduler(clock.clone(), || cron("* * * * *").unwrap());
+    let calls = counting(&job);
+
+    clock.advance(Duration::from_secs(60));
+    job.cancel();
+    assert!(job.is_cancelled());
+    clock.advance(Duration::from_secs(600));
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(clock.pending(), 0);
+}
+
+#[test]
+fn test_dropped_job_stops() {
+    let clock = ManualScheduler::new();
+    let job = with_scheduler(clock.clone(), || cron("* * * * *").unwrap());
+    drop(job);
+
+    clock.advance(Duration::from_secs(60));
+    assert_eq!(clock.pending(), 0);
+}
+
+#[test]
+fn test_panicking_handler_keeps_job_running() {
+    let clock = ManualScheduler::new();
+    let job = with_scheduler(clock.clone(), || cron("* * * * *").unwrap());
+    let calls = counting(&job);
+    job.event().subscribe(|_| panic!("handler failed"));
+
+    clock.advance(Duration::from_secs(180));
+    assert_eq!(calls.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_impossible_date_never_fires() {
+    let clock = ManualScheduler::new();
+    let job = with_scheduler(clock.clone(), || cron("0 0 30 2 *").unwrap());
+    let calls = counting(&job);
+
+    assert_eq!(clock.pending(), 0);
+    clock.advance(Duration::from_secs(400 * 24 * 60 * 60));
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+}
+
+#[test]
+fn test_cron_parse_errors() {
+    assert!(cron("* * * *").is_err());
+    assert!(cron("* * * * * *").is_err());
+    assert!(cron("60 * * * *").is_err());
+    assert!(cron("* 24 * * *").is_err());
+    assert!(cron("* * 0 * *").is_err());
+    assert!(cron("* * * 13 *").is_err());
+    assert!(cron("* * * * 8").is_err());
+    assert!(cron("*/0 * * * *").is_err());
+    assert!(cron("5-1 * * * *").is_err());
+    assert!(cron("a * * * *").is_err());
+
+    let error = cron("* 24 * * *").err().unwrap();
+    assert_eq!(error.to_string(), "invalid cron hour field `24`");
+    let error = cron("* *").err().unwrap();
+    assert_eq!(error.to_string(), "cron expression is missing its day of month field");
+}
+
+#[test]
+fn test_cron_accepts_lists_ranges_and_steps() {
+    assert!(cron("0,15,30-45/5 9-17 1-7,15 */2 1-5").is_ok());
+    assert!(cron("5/15 * * * 7").is_ok());
+}
+
+#[test]
+fn test_stepped_wildcard_day_narrows_weekday() {
+    let clock = ManualScheduler::new();
+    // Mondays falling on an odd day of the month, not every odd day and every Monday
+    let job = with_scheduler(clock.clone(), || cron("0 0 */2 * 1").unwrap());
+    let calls = counting(&job);
+
+    // Four Mondays, of which one to three are on odd days
+    clock.advance(Duration::from_secs(28 * 24 * 60 * 60));
+    assert!((1..=3).contains(&calls.load(Ordering::SeqCst)));
+}
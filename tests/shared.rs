@@ -0,0 +1,62 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{DeliveryMode, Event, InterceptDecision};
+
+#[test]
+fn test_invoke_shared_hands_out_the_callers_arc() {
+    let event = Event::<Vec<u8>>::new();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    event.subscribe_shared(move |payload| received_clone.lock().unwrap().push(payload));
+    let received_clone = Arc::clone(&received);
+    event.subscribe_shared(move |payload| received_clone.lock().unwrap().push(payload));
+    let cloned = Arc::new(Mutex::new(Vec::new()));
+    let cloned_clone = Arc::clone(&cloned);
+    event.subscribe(move |payload| cloned_clone.lock().unwrap().push(payload));
+
+    let payload = Arc::new(vec![1, 2, 3]);
+    event.invoke_shared(Arc::clone(&payload));
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 2);
+    assert!(received.iter().all(|shared| Arc::ptr_eq(shared, &payload)));
+    assert_eq!(*cloned.lock().unwrap(), vec![vec![1, 2, 3]]);
+}
+
+#[test]
+fn test_shared_handler_gets_a_new_arc_otherwise() {
+    let event = Event::<u32>::new();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    event.subscribe_shared(move |payload| received_clone.lock().unwrap().push(payload));
+    event.add_interceptor(|n| {
+        if *n == 2 {
+            InterceptDecision::Replace(Box::new(20))
+        } else {
+            InterceptDecision::Continue
+        }
+    });
+
+    event.invoke(1);
+    let payload = Arc::new(2);
+    event.invoke_shared(Arc::clone(&payload));
+
+    let received = received.lock().unwrap();
+    assert_eq!(*received[0], 1);
+    assert_eq!(*received[1], 20);
+    assert!(!Arc::ptr_eq(&received[1], &payload));
+}
+
+#[test]
+fn test_invoke_shared_queues_on_pumped_event() {
+    let event = Event::<String>::builder().delivery_mode(DeliveryMode::Pumped).build();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    event.subscribe_shared(move |payload| received_clone.lock().unwrap().push(payload));
+
+    event.invoke_shared(Arc::new("queued".to_string()));
+    assert!(received.lock().unwrap().is_empty());
+    assert_eq!(event.pump(), 1);
+
+    assert_eq!(*received.lock().unwrap()[0], "queued");
+}
@@ -0,0 +1,53 @@
+use std::sync::mpsc;
+use std::time::Duration;
+
+use blob_event::shutdown::{self, ShutdownReason};
+
+// Tests share the process-wide event, so each one only looks for the reason it caused.
+fn expect(reason: ShutdownReason, cause: impl FnOnce()) {
+    let (sender, received) = mpsc::channel();
+    let event = shutdown::requested();
+    let sub = event.subscribe(move |reason| {
+        let _ = sender.send(reason);
+    });
+    cause();
+    let deadline = std::time::Instant::now() + Duration::from_secs(5);
+    loop {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        if received.recv_timeout(remaining).unwrap() == reason {
+            break;
+        }
+    }
+    event.unsubscribe(sub);
+    assert!(shutdown::is_requested());
+}
+
+#[test]
+fn test_request_shutdown_invokes_shutdown_event() {
+    expect(ShutdownReason::Requested, shutdown::request_shutdown);
+}
+
+#[test]
+fn test_request_count_includes_the_request_being_dispatched() {
+    let (sender, received) = mpsc::channel();
+    let event = shutdown::requested();
+    let sub = event.subscribe(move |_| {
+        let _ = sender.send(shutdown::request_count());
+    });
+    let before = shutdown::request_count();
+    shutdown::request_shutdown();
+    event.unsubscribe(sub);
+
+    // Other tests may request shutdowns concurrently, so the count may have grown further.
+    let seen = received.recv_timeout(Duration::from_secs(5)).unwrap();
+    assert!(seen > before);
+    assert!(shutdown::request_count() >= seen);
+}
+
+#[cfg(all(feature = "signals", unix))]
+#[test]
+fn test_sigterm_requests_shutdown() {
+    expect(ShutdownReason::Terminate, || {
+        signal_hook::low_level::raise(signal_hook::consts::SIGTERM).unwrap();
+    });
+}
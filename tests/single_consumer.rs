@@ -0,0 +1,41 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{EventError, SingleConsumerEvent};
+
+// A payload that can't be cloned.
+#[derive(Debug, PartialEq)]
+struct Token(u32);
+
+#[test]
+fn test_single_consumer_moves_args_into_handler() {
+    let event = SingleConsumerEvent::<Token>::new();
+    let taken = Arc::new(Mutex::new(Vec::new()));
+    let taken_clone = Arc::clone(&taken);
+    event.subscribe(move |token| taken_clone.lock().unwrap().push(token));
+
+    assert_eq!(event.invoke(Token(1)), Ok(()));
+    assert_eq!(event.clone().invoke(Token(2)), Ok(()));
+
+    assert_eq!(*taken.lock().unwrap(), vec![Token(1), Token(2)]);
+}
+
+#[test]
+fn test_single_consumer_refuses_second_subscriber() {
+    let event = SingleConsumerEvent::<Token>::new();
+    let first = event.subscribe(|_| {});
+
+    assert_eq!(event.try_subscribe(|_| {}), Err(EventError::TooManySubscribers));
+    let refused = event.subscribe(|_| panic!("refused handler called"));
+    assert!(!event.unsubscribe(refused));
+    event.invoke(Token(1)).unwrap();
+
+    assert!(event.unsubscribe(first));
+    assert!(event.try_subscribe(|_| {}).is_ok());
+}
+
+#[test]
+fn test_single_consumer_returns_args_without_subscriber() {
+    let event = SingleConsumerEvent::<Token>::default();
+    assert!(!event.has_subscriber());
+    assert_eq!(event.invoke(Token(7)), Err(Token(7)));
+}
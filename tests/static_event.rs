@@ -0,0 +1,82 @@
+use blob_event::{EventError, StaticEvent};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[test]
+fn test_static_event_in_static() {
+    static EVENT: StaticEvent<i32, 2> = StaticEvent::new();
+    static TOTAL: AtomicUsize = AtomicUsize::new(0);
+
+    fn add(x: &i32) {
+        TOTAL.fetch_add(*x as usize, Ordering::SeqCst);
+    }
+
+    let sub = EVENT.subscribe(&add).unwrap();
+    EVENT.invoke(&3);
+    EVENT.invoke(&4);
+    assert_eq!(TOTAL.load(Ordering::SeqCst), 7);
+
+    assert!(EVENT.unsubscribe(sub));
+    EVENT.invoke(&5);
+    assert_eq!(TOTAL.load(Ordering::SeqCst), 7);
+}
+
+#[test]
+fn test_static_event_rejects_beyond_capacity() {
+    let event = StaticEvent::<str, 2>::new();
+    assert_eq!(event.capacity(), 2);
+
+    let first = event.subscribe(&|_| {}).unwrap();
+    event.subscribe(&|_| {}).unwrap();
+    assert_eq!(event.subscribe(&|_| {}), Err(EventError::TooManySubscribers));
+    assert_eq!(event.subscriber_count(), 2);
+
+    // Removing a handler frees its slot
+    event.unsubscribe(first);
+    assert!(event.subscribe(&|_| {}).is_ok());
+}
+
+#[test]
+fn test_static_event_stale_subscription() {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    let event = StaticEvent::<(), 1>::new();
+    let stale = event.subscribe(&|_| {}).unwrap();
+    event.unsubscribe(stale);
+    let fresh = event
+        .subscribe(&|_| {
+            CALLS.fetch_add(1, Ordering::SeqCst);
+        })
+        .unwrap();
+
+    // Same slot, but the old handle no longer matches it
+    assert_ne!(stale, fresh);
+    assert!(!event.unsubscribe(stale));
+    event.invoke(&());
+    assert_eq!(CALLS.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_static_event_handler_unsubscribes_other() {
+    static EVENT: StaticEvent<(), 2> = StaticEvent::new();
+    static SECOND: Mutex<Option<blob_event::Subscription>> = Mutex::new(None);
+    static SECOND_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+    EVENT
+        .subscribe(&|_| {
+            if let Some(second) = SECOND.lock().unwrap().take() {
+                EVENT.unsubscribe(second);
+            }
+        })
+        .unwrap();
+    let second = EVENT
+        .subscribe(&|_| {
+            SECOND_CALLS.fetch_add(1, Ordering::SeqCst);
+        })
+        .unwrap();
+    *SECOND.lock().unwrap() = Some(second);
+
+    EVENT.invoke(&());
+    assert_eq!(SECOND_CALLS.load(Ordering::SeqCst), 0);
+    assert_eq!(EVENT.subscriber_count(), 1);
+}
@@ -0,0 +1,51 @@
+use blob_event::Event;
+use std::thread;
+use std::time::Duration;
+
+#[test]
+fn test_stats_count_invocations_and_calls() {
+    let event: Event<u64> = Event::builder().collect_stats().build();
+    let fast = event.subscribe(|_| {});
+    let slow = event.subscribe(|millis| thread::sleep(Duration::from_millis(millis)));
+
+    event.invoke(5);
+    event.invoke_batch(&[0, 5]);
+    let removed = event.subscribe(|_| {});
+    event.unsubscribe(removed);
+
+    let stats = event.stats().unwrap();
+    assert_eq!(stats.invocations, 3);
+    let subscriptions: Vec<_> = stats.handlers.iter().map(|handler| handler.subscription).collect();
+    assert_eq!(subscriptions, vec![fast, slow]);
+    // A batch counts one call per item
+    assert_eq!(stats.handlers[0].calls, 3);
+    assert_eq!(stats.handlers[1].calls, 3);
+    assert!(stats.handlers[1].total_duration >= Duration::from_millis(10));
+    assert!(stats.handlers[1].average_duration().unwrap() >= Duration::from_millis(3));
+    assert!(stats.total_handler_duration() >= stats.handlers[1].total_duration);
+}
+
+#[test]
+fn test_stats_unavailable_unless_enabled() {
+    let event = Event::<u64>::new();
+    event.subscribe(|_| {});
+    event.invoke(1);
+    assert!(event.stats().is_none());
+
+    let event: Event<u64> = Event::builder().collect_stats().build();
+    let stats = event.stats().unwrap();
+    assert_eq!(stats.invocations, 0);
+    assert!(stats.average_handler_duration().is_none());
+}
+
+#[test]
+fn test_stats_agree_with_subscription_info_on_batches() {
+    let event: Event<u64> = Event::builder().collect_stats().build();
+    let handler = event.subscribe(|_| {});
+
+    event.invoke_batch(&[1, 2, 3, 4, 5]);
+    let stats = event.stats().unwrap();
+    assert_eq!(stats.invocations, 5);
+    assert_eq!(stats.handlers[0].calls, 5);
+    assert_eq!(event.subscription_info(handler).unwrap().calls, 5);
+}
@@ -0,0 +1,211 @@
+use blob_event::testing::{ManualScheduler, Schedule, YieldPoint};
+use blob_event::{BatchConfig, Event, Scheduler, TimeoutAction, with_scheduler};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Duration;
+
+fn counting_event() -> (Event<i32>, Arc<AtomicUsize>, blob_event::Subscription) {
+    let event = Event::<i32>::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    let sub = event.subscribe(move |_| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+    });
+    (event, calls, sub)
+}
+
+#[test]
+fn test_unsubscribe_between_snapshot_and_handler() {
+    let (event, calls, sub) = counting_event();
+
+    let mut schedule = Schedule::new(["invoke", "unsubscribe", "invoke"]);
+    let invoking = event.clone();
+    schedule.spawn("invoke", move || invoking.invoke(1));
+    let unsubscribing = event.clone();
+    schedule.spawn("unsubscribe", move || {
+        assert!(unsubscribing.unsubscribe(sub));
+    });
+    let trace = schedule.join();
+
+    // The handler was removed after the snapshot but before it ran, so it is skipped
+    assert_eq!(calls.load(Ordering::SeqCst), 0);
+    assert_eq!(
+        trace,
+        vec![
+            ("invoke".to_string(), YieldPoint::BeforeSnapshot),
+            ("unsubscribe".to_string(), YieldPoint::BeforeUnsubscribe),
+            ("invoke".to_string(), YieldPoint::BeforeHandler),
+        ]
+    );
+}
+
+#[test]
+fn test_unsubscribe_after_handler() {
+    let (event, calls, sub) = counting_event();
+
+    let mut schedule = Schedule::new(["invoke", "invoke", "unsubscribe"]);
+    let invoking = event.clone();
+    schedule.spawn("invoke", move || invoking.invoke(1));
+    let unsubscribing = event.clone();
+    schedule.spawn("unsubscribe", move || {
+        unsubscribing.unsubscribe(sub);
+    });
+    schedule.join();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_subscribe_before_snapshot_is_included() {
+    let (event, calls, _) = counting_event();
+
+    let mut schedule = Schedule::new(["subscribe", "invoke"]);
+    let subscribing = event.clone();
+    let calls_clone = Arc::clone(&calls);
+    schedule.spawn("subscribe", move || {
+        subscribing.subscribe(move |_| {
+            calls_clone.fetch_add(10, Ordering::SeqCst);
+        });
+    });
+    let invoking = event.clone();
+    schedule.spawn("invoke", move || invoking.invoke(1));
+    schedule.join();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 11);
+}
+
+#[test]
+fn test_steps_of_finished_threads_are_skipped() {
+    let (event, calls, _) = counting_event();
+
+    // "idle" never reaches a yield point, so its turns must not block "invoke"
+    let mut schedule = Schedule::new(["idle", "invoke", "idle", "invoke"]);
+    schedule.spawn("idle", || {});
+    let invoking = event.clone();
+    schedule.spawn("invoke", move || invoking.invoke(1));
+    schedule.join();
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+#[should_panic(expected = "boom")]
+fn test_join_propagates_panics() {
+    let mut schedule = Schedule::new(Vec::<String>::new());
+    schedule.spawn("panics", || panic!("boom"));
+    schedule.join();
+}
+
+#[test]
+fn test_spy_records_dispatches_without_subscribing() {
+    let event = Event::<i32>::new();
+    let spy = event.spy();
+    spy.assert_not_invoked();
+
+    event.invoke(1);
+    event.invoke_batch(&[2, 3]);
+
+    assert_eq!(spy.received(), vec![1, 2, 3]);
+    spy.assert_invoked_times(3);
+    spy.assert_last_args(&3);
+    assert_eq!(event.subscriber_count(), 0);
+
+    spy.clear();
+    assert_eq!(spy.last(), None);
+    drop(spy);
+    event.invoke(4);
+}
+
+#[test]
+fn test_spy_on_borrowed_event() {
+    let event: Event<Vec<u8>, blob_event::Borrowed> = Event::builder().borrowed().build();
+    let spy = event.spy();
+    event.invoke(&vec![1, 2]);
+    spy.assert_last_args(&vec![1, 2]);
+}
+
+#[test]
+#[should_panic(expected = "expected the event to be invoked 2 times, but it was invoked 1 times")]
+fn test_spy_assertion_failure_message() {
+    let event = Event::<i32>::new();
+    let spy = event.spy();
+    event.invoke(1);
+    spy.assert_invoked_times(2);
+}
+
+#[test]
+fn test_manual_scheduler_drives_batch_latency() {
+    let clock = ManualScheduler::new();
+    let event = Event::<i32>::new();
+    let batches = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let batches_clone = Arc::clone(&batches);
+    let config = BatchConfig::new(10).max_latency(Duration::from_secs(60));
+    with_scheduler(clock.clone(), || {
+        event
+            .subscribe_batched(config, move |batch| batches_clone.lock().unwrap().push(batch))
+            .unwrap()
+    });
+
+    event.invoke(1);
+    event.invoke(2);
+    assert_eq!(clock.pending(), 1);
+    clock.advance(Duration::from_secs(59));
+    assert!(batches.lock().unwrap().is_empty());
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2]]);
+    assert_eq!(clock.pending(), 0);
+}
+
+#[test]
+fn test_manual_scheduler_drives_handler_timeouts() {
+    let clock = ManualScheduler::new();
+    let event: Event<i32> = with_scheduler(clock.clone(), || {
+        Event::builder()
+            .handler_timeout(Duration::from_secs(5), TimeoutAction::Report)
+            .build()
+    });
+    let spy = event.handler_timeouts().spy();
+    let pacer = clock.clone();
+    let slow = event.subscribe(move |_| pacer.advance(Duration::from_secs(10)));
+    event.invoke_detached(1);
+    event.flush();
+
+    spy.assert_last_args(&slow);
+}
+
+#[test]
+fn test_manual_scheduler_runs_timers_in_deadline_order() {
+    let clock = ManualScheduler::new();
+    let order = Arc::new(std::sync::Mutex::new(Vec::new()));
+    for (delay, name) in [(3, "c"), (1, "a"), (2, "b")] {
+        let order = Arc::clone(&order);
+        let deadline = clock.now() + Duration::from_secs(delay);
+        clock.schedule(deadline, Box::new(move || order.lock().unwrap().push(name)));
+    }
+    let before = clock.now();
+    clock.advance(Duration::from_secs(2));
+    assert_eq!(*order.lock().unwrap(), vec!["a", "b"]);
+    assert_eq!(clock.now() - before, Duration::from_secs(2));
+}
+
+#[test]
+fn test_finished_watched_calls_leave_one_pending_check() {
+    let clock = ManualScheduler::new();
+    let event: Event<i32> = with_scheduler(clock.clone(), || {
+        Event::builder()
+            .handler_timeout(Duration::from_secs(5), TimeoutAction::Report)
+            .build()
+    });
+    let spy = event.handler_timeouts().spy();
+    event.subscribe(|_| {});
+    for i in 0..100 {
+        event.invoke_detached(i);
+    }
+    event.flush();
+    assert_eq!(clock.pending(), 1);
+
+    clock.advance(Duration::from_secs(10));
+    assert_eq!(clock.pending(), 0);
+    spy.assert_not_invoked();
+}
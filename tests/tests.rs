@@ -1,4 +1,5 @@
-use blob_event::{Event, Subscription};
+use blob_event::{Borrowed, DispatchError, DispatchOrder, Event, SharedEvent, Subscription};
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -126,11 +127,26 @@ fn test_unsubscribe_returns_false_for_invalid_id() {
     // Trying to unsubscribe again should return false
     let removed = event.unsubscribe(sub);
     assert!(!removed);
+}
 
-    // Unsubscribing a made-up ID should return false
-    let fake_id = Subscription::new(9999);
-    let removed = event.unsubscribe(fake_id);
-    assert!(!removed);
+#[test]
+fn test_stale_subscription_does_not_remove_reused_slot() {
+    let event = Event::<i32>::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let stale = event.subscribe(|_| {});
+    event.unsubscribe(stale);
+
+    // The new subscription may reuse the slot, but with a new generation
+    let calls_clone = Arc::clone(&calls);
+    let fresh = event.subscribe(move |_| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+    });
+    assert_ne!(stale, fresh);
+
+    assert!(!event.unsubscribe(stale));
+    event.invoke(1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+    assert!(event.unsubscribe(fresh));
 }
 
 #[test]
@@ -465,3 +481,777 @@ fn test_subscription_id_can_be_stored() {
 
     assert_eq!(event.subscriber_count(), 0);
 }
+
+#[test]
+fn test_handler_unsubscribes_itself() {
+    let event = Event::<i32>::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    let count_clone = Arc::clone(&count);
+    let own_sub = Arc::new(Mutex::new(None::<Subscription>));
+    let own_sub_clone = Arc::clone(&own_sub);
+    let event_clone = event.clone();
+
+    let sub = event.subscribe(move |_| {
+        count_clone.fetch_add(1, Ordering::SeqCst);
+        if let Some(sub) = own_sub_clone.lock().unwrap().take() {
+            assert!(event_clone.unsubscribe(sub));
+        }
+    });
+    *own_sub.lock().unwrap() = Some(sub);
+
+    event.invoke(0);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(event.subscriber_count(), 0);
+
+    // The handler removed itself, so it isn't called again
+    event.invoke(0);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_handler_unsubscribes_other_handler_during_invoke() {
+    let event = Event::<i32>::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    let target_of_a = Arc::new(Mutex::new(None::<Subscription>));
+    let target_of_b = Arc::new(Mutex::new(None::<Subscription>));
+
+    let count_a = Arc::clone(&count);
+    let target_a = Arc::clone(&target_of_a);
+    let event_a = event.clone();
+    let sub_a = event.subscribe(move |_| {
+        count_a.fetch_add(1, Ordering::SeqCst);
+        if let Some(sub) = target_a.lock().unwrap().take() {
+            event_a.unsubscribe(sub);
+        }
+    });
+
+    let count_b = Arc::clone(&count);
+    let target_b = Arc::clone(&target_of_b);
+    let event_b = event.clone();
+    let sub_b = event.subscribe(move |_| {
+        count_b.fetch_add(1, Ordering::SeqCst);
+        if let Some(sub) = target_b.lock().unwrap().take() {
+            event_b.unsubscribe(sub);
+        }
+    });
+
+    // Each handler removes the other; whichever runs first wins and the second never runs
+    *target_of_a.lock().unwrap() = Some(sub_b);
+    *target_of_b.lock().unwrap() = Some(sub_a);
+
+    event.invoke(0);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+    assert_eq!(event.subscriber_count(), 1);
+
+    event.unsubscribe_all();
+}
+
+#[test]
+fn test_handler_subscribes_during_invoke() {
+    let event = Event::<i32>::new();
+    let count = Arc::new(AtomicUsize::new(0));
+    let count_clone = Arc::clone(&count);
+    let event_clone = event.clone();
+
+    let sub = event.subscribe(move |_| {
+        let count_inner = Arc::clone(&count_clone);
+        event_clone.subscribe(move |_| {
+            count_inner.fetch_add(1, Ordering::SeqCst);
+        });
+    });
+
+    // The new handler is not part of the dispatch that created it
+    event.invoke(0);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+    assert_eq!(event.subscriber_count(), 2);
+
+    event.unsubscribe(sub);
+    event.invoke(0);
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+
+    event.unsubscribe_all();
+}
+
+#[test]
+fn test_handler_closes_own_event_during_invoke() {
+    let event = Event::<i32>::new();
+    let closed = Arc::new(AtomicUsize::new(0));
+    let closed_clone = Arc::clone(&closed);
+    let event_clone = event.clone();
+
+    event.subscribe_with_close(
+        move |_| {
+            event_clone.close();
+            // The close notification waits for this call to return
+            assert_eq!(closed_clone.load(Ordering::SeqCst), 0);
+        },
+        {
+            let closed = Arc::clone(&closed);
+            move || {
+                closed.fetch_add(1, Ordering::SeqCst);
+            }
+        },
+    );
+
+    event.invoke(0);
+    assert!(event.is_closed());
+    assert_eq!(closed.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_dropped_handler_can_use_its_event() {
+    // Subscribes a new handler to the event when dropped
+    struct Resubscribe(Event<i32>);
+
+    impl Drop for Resubscribe {
+        fn drop(&mut self) {
+            self.0.subscribe(|_| {});
+        }
+    }
+
+    let event = Event::<i32>::new();
+    let guard = Resubscribe(event.clone());
+    let sub = event.subscribe(move |_| {
+        let _ = &guard;
+    });
+
+    // The handler is dropped once the subscription list is unlocked again
+    event.unsubscribe(sub);
+    assert_eq!(event.subscriber_count(), 1);
+
+    event.unsubscribe_all();
+}
+
+#[test]
+fn test_replace_keeps_subscription_and_order() {
+    let event: Event<i32> = Event::builder().dispatch_order(DispatchOrder::Priority).build();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+
+    let c = Arc::clone(&calls);
+    let first = event.subscribe_with_priority(10, move |x| c.lock().unwrap().push(("first", x)));
+    let c = Arc::clone(&calls);
+    event.subscribe_with_priority(5, move |x| c.lock().unwrap().push(("second", x)));
+
+    let c = Arc::clone(&calls);
+    assert!(event.replace(first, move |x| c.lock().unwrap().push(("replaced", x))));
+    event.invoke(1);
+    assert_eq!(*calls.lock().unwrap(), [("replaced", 1), ("second", 1)]);
+    assert_eq!(event.subscriptions()[0], first);
+
+    event.unsubscribe(first);
+    assert!(!event.replace(first, |_| {}));
+
+    event.unsubscribe_all();
+}
+
+#[test]
+fn test_handler_replaces_itself_during_invoke() {
+    let event = Event::<i32>::new();
+    let own_sub = Arc::new(Mutex::new(None::<Subscription>));
+    let count = Arc::new(AtomicUsize::new(0));
+
+    let (own, event_clone, c) = (Arc::clone(&own_sub), event.clone(), Arc::clone(&count));
+    let sub = event.subscribe(move |_| {
+        let c = Arc::clone(&c);
+        let own = own.lock().unwrap().unwrap();
+        event_clone.replace(own, move |x| {
+            c.fetch_add(x as usize, Ordering::SeqCst);
+        });
+    });
+    *own_sub.lock().unwrap() = Some(sub);
+
+    event.invoke(1);
+    assert_eq!(count.load(Ordering::SeqCst), 0);
+    event.invoke(2);
+    assert_eq!(count.load(Ordering::SeqCst), 2);
+
+    event.unsubscribe_all();
+}
+
+#[test]
+fn test_take_handler_moves_it_with_its_state() {
+    let source = Event::<i32>::new();
+    let target = Event::<i32>::new();
+    let total = Arc::new(AtomicUsize::new(0));
+
+    let t = Arc::clone(&total);
+    let mut sum = 0;
+    let sub = source.subscribe(move |x| {
+        sum += x;
+        t.store(sum as usize, Ordering::SeqCst);
+    });
+    source.invoke(1);
+
+    let mut handler = source.take_handler(sub).unwrap();
+    assert_eq!(source.subscriber_count(), 0);
+    assert!(source.take_handler(sub).is_none());
+
+    // The running sum came along
+    handler(2);
+    assert_eq!(total.load(Ordering::SeqCst), 3);
+    target.subscribe(handler);
+    target.invoke(3);
+    source.invoke(100);
+    assert_eq!(total.load(Ordering::SeqCst), 6);
+
+    target.unsubscribe_all();
+}
+
+#[test]
+fn test_handler_takes_itself_during_invoke() {
+    let event = Event::<i32>::new();
+    let own_sub = Arc::new(Mutex::new(None::<Subscription>));
+    let taken = Arc::new(Mutex::new(None));
+
+    let (own, event_clone, t) = (Arc::clone(&own_sub), event.clone(), Arc::clone(&taken));
+    let sub = event.subscribe(move |_| {
+        if let Some(own) = own.lock().unwrap().take() {
+            *t.lock().unwrap() = event_clone.take_handler(own);
+        }
+    });
+    *own_sub.lock().unwrap() = Some(sub);
+
+    event.invoke(0);
+    assert!(taken.lock().unwrap().is_some());
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_borrowed_event_does_not_require_clone() {
+    // Not Clone, so this payload can only be delivered by reference
+    struct Frame {
+        pixels: Vec<u8>,
+    }
+
+    let event: Event<Frame, Borrowed> = Event::builder().borrowed().build();
+    let total = Arc::new(AtomicUsize::new(0));
+
+    let total_clone1 = Arc::clone(&total);
+    let sub1 = event.subscribe(move |frame: &Frame| {
+        total_clone1.fetch_add(frame.pixels.len(), Ordering::SeqCst);
+    });
+    let total_clone2 = Arc::clone(&total);
+    let sub2 = event.subscribe(move |frame: &Frame| {
+        total_clone2.fetch_add(frame.pixels.len(), Ordering::SeqCst);
+    });
+
+    event.invoke(&Frame { pixels: vec![0; 16] });
+    assert_eq!(total.load(Ordering::SeqCst), 32);
+
+    event.unsubscribe(sub1);
+    event.unsubscribe(sub2);
+}
+
+#[test]
+fn test_borrowed_event_with_unsized_args() {
+    let event: Event<str, Borrowed> = Event::builder().borrowed().build();
+    let received = Arc::new(Mutex::new(String::new()));
+    let received_clone = Arc::clone(&received);
+
+    let sub = event.subscribe(move |text: &str| {
+        received_clone.lock().unwrap().push_str(text);
+    });
+
+    let owned = String::from("hello");
+    event.invoke(&owned);
+    event.invoke(" world");
+
+    assert_eq!(*received.lock().unwrap(), "hello world");
+
+    event.unsubscribe(sub);
+}
+
+#[test]
+fn test_shared_event_shares_one_allocation() {
+    let event: SharedEvent<Vec<u8>> = Event::builder().shared().build();
+    let received = Arc::new(Mutex::new(Vec::new()));
+
+    let received_clone1 = Arc::clone(&received);
+    let sub1 = event.subscribe(move |data: Arc<Vec<u8>>| {
+        received_clone1.lock().unwrap().push(data);
+    });
+    let received_clone2 = Arc::clone(&received);
+    let sub2 = event.subscribe(move |data: Arc<Vec<u8>>| {
+        received_clone2.lock().unwrap().push(data);
+    });
+
+    let payload = Arc::new(vec![1, 2, 3]);
+    event.invoke(Arc::clone(&payload));
+
+    let received = received.lock().unwrap();
+    assert_eq!(received.len(), 2);
+    assert!(received.iter().all(|data| Arc::ptr_eq(data, &payload)));
+
+    drop(received);
+    event.unsubscribe(sub1);
+    event.unsubscribe(sub2);
+}
+
+#[test]
+fn test_builder_default_mode_clones() {
+    let event: Event<String> = Event::builder().build();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+
+    let sub = event.subscribe(move |text| {
+        received_clone.lock().unwrap().push(text);
+    });
+
+    event.invoke("owned".to_string());
+    assert_eq!(*received.lock().unwrap(), vec!["owned".to_string()]);
+
+    event.unsubscribe(sub);
+}
+
+#[test]
+fn test_mirror_receives_every_dispatch() {
+    let event = Event::<i32>::new();
+    let mirror = event.mirror();
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    mirror.subscribe(move |x| {
+        received_clone.lock().unwrap().push(x);
+    });
+
+    // The mirror fires even when the event itself has no subscribers
+    event.invoke(1);
+    let sub = event.subscribe(|_| {});
+    event.invoke(2);
+
+    assert_eq!(*received.lock().unwrap(), vec![1, 2]);
+    // Attaching a mirror doesn't add a subscriber to the original event
+    assert_eq!(event.subscriber_count(), 1);
+
+    event.unsubscribe(sub);
+}
+
+#[test]
+fn test_mirror_detaches_when_dropped() {
+    let event = Event::<i32>::new();
+    let count = Arc::new(AtomicUsize::new(0));
+
+    let mirror = event.mirror();
+    let count_clone = Arc::clone(&count);
+    mirror.subscribe(move |_| {
+        count_clone.fetch_add(1, Ordering::SeqCst);
+    });
+
+    event.invoke(0);
+    drop(mirror);
+    event.invoke(0);
+
+    assert_eq!(count.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_mirror_of_borrowed_event() {
+    let event: Event<str, Borrowed> = Event::builder().borrowed().build();
+    let mirror = event.mirror();
+
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    mirror.subscribe(move |text: &str| {
+        received_clone.lock().unwrap().push(text.to_string());
+    });
+
+    event.invoke("tapped");
+    assert_eq!(*received.lock().unwrap(), vec!["tapped".to_string()]);
+}
+
+#[test]
+fn test_try_invoke_reports_handlers_called() {
+    let event = Event::<i32>::new();
+    let sub1 = event.subscribe(|_| {});
+    let sub2 = event.subscribe(|_| {});
+
+    let report = event.try_invoke(1).unwrap();
+    assert_eq!(report.handlers_called, 2);
+    assert_eq!(report.handlers_skipped, 0);
+
+    event.unsubscribe(sub1);
+    event.unsubscribe(sub2);
+}
+
+#[test]
+fn test_try_invoke_reports_handlers_skipped_mid_dispatch() {
+    let event = Event::<i32>::new();
+    let event_clone = event.clone();
+
+    // Whichever handler runs first removes every subscription, so the other is skipped
+    event.subscribe(move |_| event_clone.unsubscribe_all());
+    let event_clone = event.clone();
+    event.subscribe(move |_| event_clone.unsubscribe_all());
+
+    let report = event.try_invoke(1).unwrap();
+    assert_eq!(report.handlers_called, 1);
+    assert_eq!(report.handlers_skipped, 1);
+}
+
+#[test]
+fn test_try_invoke_catches_handler_panic() {
+    let event = Event::<i32>::new();
+    let sub = event.subscribe(|x| {
+        if x < 0 {
+            panic!("negative value");
+        }
+    });
+
+    let error = event.try_invoke(-1).unwrap_err();
+    assert_eq!(
+        error,
+        DispatchError::HandlerPanicked {
+            subscription: sub,
+            message: Some("negative value".to_string()),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        format!("handler for {:?} panicked: negative value", sub)
+    );
+}
+
+#[test]
+fn test_subscription_info() {
+    let event = Event::<i32>::new();
+    let sub = event.subscribe(|_| {});
+
+    let info = event.subscription_info(sub).unwrap();
+    assert_eq!(info.subscription, sub);
+    assert!(!info.running);
+
+    event.unsubscribe(sub);
+    assert!(event.subscription_info(sub).is_none());
+}
+
+#[test]
+fn test_subscriptions_and_contains() {
+    let event = Event::<i32>::new();
+    let before = std::time::Instant::now();
+    let first = event.subscribe(|_| {});
+    let second = event.subscribe(|_| {});
+    assert_eq!(event.subscriptions(), [first, second]);
+    assert!(event.contains(first));
+
+    event.invoke(1);
+    event.invoke_batch(&[2, 3]);
+    let info = event.subscription_info(second).unwrap();
+    assert_eq!(info.calls, 3);
+    assert!(info.subscribed_at >= before);
+
+    event.unsubscribe(first);
+    assert!(!event.contains(first));
+    assert_eq!(event.subscriptions(), [second]);
+}
+
+#[test]
+fn test_named_subscriptions_are_listed() {
+    let event = Event::<i32>::new();
+    let physics = event.subscribe_named("physics_step", |_| {});
+    let anonymous = event.subscribe(|_| {});
+    assert_eq!(
+        event.debug_subscribers(),
+        [(physics, Some("physics_step")), (anonymous, None)]
+    );
+    assert_eq!(event.subscription_info(physics).unwrap().name, Some("physics_step"));
+
+    let borrowed: Event<str, Borrowed> = Event::builder().borrowed().build();
+    let logger = borrowed.subscribe_named("logger", |_| {});
+    assert_eq!(borrowed.debug_subscribers(), [(logger, Some("logger"))]);
+}
+
+#[test]
+fn test_subscription_info_while_running() {
+    let event = Event::<i32>::new();
+    let running = Arc::new(Mutex::new(None));
+    let running_clone = Arc::clone(&running);
+    let own_sub = Arc::new(Mutex::new(None::<Subscription>));
+    let own_sub_clone = Arc::clone(&own_sub);
+    let event_clone = event.clone();
+
+    let sub = event.subscribe(move |_| {
+        let sub = own_sub_clone.lock().unwrap().unwrap();
+        *running_clone.lock().unwrap() = Some(event_clone.subscription_info(sub).unwrap().running);
+    });
+    *own_sub.lock().unwrap() = Some(sub);
+
+    event.invoke(0);
+    assert_eq!(*running.lock().unwrap(), Some(true));
+
+    event.unsubscribe(sub);
+}
+
+fn recording_event(order: DispatchOrder) -> (Event<i32>, Arc<Mutex<Vec<&'static str>>>) {
+    let event: Event<i32> = Event::builder().dispatch_order(order).build();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    (event, calls)
+}
+
+fn record(calls: &Arc<Mutex<Vec<&'static str>>>, name: &'static str) -> impl FnMut(i32) + Send + 'static {
+    let calls = Arc::clone(calls);
+    move |_| calls.lock().unwrap().push(name)
+}
+
+#[test]
+fn test_dispatch_order_insertion() {
+    let (event, calls) = recording_event(DispatchOrder::Insertion);
+    event.subscribe(record(&calls, "a"));
+    let sub = event.subscribe(record(&calls, "b"));
+    event.subscribe(record(&calls, "c"));
+    event.unsubscribe(sub);
+    event.subscribe(record(&calls, "d"));
+
+    event.invoke(0);
+    assert_eq!(*calls.lock().unwrap(), vec!["a", "c", "d"]);
+}
+
+#[test]
+fn test_dispatch_order_reverse() {
+    let (event, calls) = recording_event(DispatchOrder::Reverse);
+    event.subscribe(record(&calls, "a"));
+    event.subscribe(record(&calls, "b"));
+    event.subscribe(record(&calls, "c"));
+
+    event.invoke(0);
+    assert_eq!(*calls.lock().unwrap(), vec!["c", "b", "a"]);
+}
+
+#[test]
+fn test_dispatch_order_priority() {
+    let (event, calls) = recording_event(DispatchOrder::Priority);
+    event.subscribe(record(&calls, "default"));
+    event.subscribe_with_priority(-5, record(&calls, "low"));
+    event.subscribe_with_priority(5, record(&calls, "high"));
+    event.subscribe_with_priority(5, record(&calls, "high2"));
+    event.subscribe(record(&calls, "default2"));
+
+    event.invoke(0);
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec!["high", "high2", "default", "default2", "low"]
+    );
+}
+
+#[test]
+fn test_priority_ignored_without_priority_order() {
+    let (event, calls) = recording_event(DispatchOrder::Insertion);
+    event.subscribe_with_priority(-5, record(&calls, "low"));
+    event.subscribe_with_priority(5, record(&calls, "high"));
+
+    event.invoke(0);
+    assert_eq!(*calls.lock().unwrap(), vec!["low", "high"]);
+}
+
+#[test]
+fn test_handler_panic_does_not_break_later_invokes() {
+    let event = Event::<i32>::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    event.subscribe(move |_| {
+        if calls_clone.fetch_add(1, Ordering::SeqCst) == 0 {
+            panic!("first call");
+        }
+    });
+
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| event.invoke(1))).is_err());
+
+    // The handler's lock was released by a panic, but the event keeps working
+    event.invoke(2);
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+    assert!(event.try_subscribe(|_| {}).is_ok());
+}
+
+#[test]
+fn test_panicking_handler_drop_leaves_list_usable() {
+    struct PanicOnDrop;
+
+    impl Drop for PanicOnDrop {
+        fn drop(&mut self) {
+            panic!("dropped");
+        }
+    }
+
+    let event = Event::<i32>::new();
+    let guard = PanicOnDrop;
+    let sub = event.subscribe(move |_| {
+        let _ = &guard;
+    });
+
+    // The handler is dropped after the subscription list is unlocked, so the panic reaches
+    // the caller without poisoning the list
+    assert!(panic::catch_unwind(AssertUnwindSafe(|| event.unsubscribe(sub))).is_err());
+
+    assert_eq!(event.try_unsubscribe(sub), Ok(false));
+    assert!(event.try_subscribe(|_| {}).is_ok());
+    assert_eq!(event.subscriber_count(), 1);
+}
+
+#[test]
+fn test_subscription_from_other_event_is_ignored() {
+    let first = Event::<i32>::new();
+    let second = Event::<i32>::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+
+    // Both are the first subscription of their event, in the same slot
+    let first_sub = first.subscribe(|_| {});
+    let second_sub = second.subscribe(move |_| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+    });
+    assert_ne!(first_sub, second_sub);
+
+    assert!(!second.unsubscribe(first_sub));
+    assert!(second.subscription_info(first_sub).is_none());
+    second.invoke(1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn test_invoke_batch_delivers_every_item_to_each_handler() {
+    let event = Event::<i32>::new();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    for name in ["a", "b"] {
+        let calls = Arc::clone(&calls);
+        event.subscribe(move |x| calls.lock().unwrap().push((name, x)));
+    }
+    let mirror = event.mirror();
+    let mirrored = Arc::new(AtomicUsize::new(0));
+    let mirrored_clone = Arc::clone(&mirrored);
+    mirror.subscribe(move |_| {
+        mirrored_clone.fetch_add(1, Ordering::SeqCst);
+    });
+
+    event.invoke_batch(&[1, 2, 3]);
+
+    assert_eq!(
+        *calls.lock().unwrap(),
+        vec![("a", 1), ("a", 2), ("a", 3), ("b", 1), ("b", 2), ("b", 3)]
+    );
+    assert_eq!(mirrored.load(Ordering::SeqCst), 3);
+}
+
+#[test]
+fn test_invoke_batch_borrowed() {
+    let event: Event<String, Borrowed> = Event::builder().borrowed().build();
+    let total = Arc::new(AtomicUsize::new(0));
+    let total_clone = Arc::clone(&total);
+    event.subscribe(move |s: &String| {
+        total_clone.fetch_add(s.len(), Ordering::SeqCst);
+    });
+
+    event.invoke_batch(&["ab".to_string(), "cde".to_string()]);
+    assert_eq!(total.load(Ordering::SeqCst), 5);
+}
+
+#[test]
+fn test_invoke_with_builds_args_only_for_subscribers() {
+    let event = Event::<String>::new();
+    let built = Arc::new(AtomicUsize::new(0));
+    let build = |text: &'static str| {
+        let built = Arc::clone(&built);
+        move || {
+            built.fetch_add(1, Ordering::SeqCst);
+            text.to_string()
+        }
+    };
+
+    event.invoke_with(build("unheard"));
+    assert_eq!(built.load(Ordering::SeqCst), 0);
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    for _ in 0..2 {
+        let seen = Arc::clone(&seen);
+        event.subscribe(move |s| seen.lock().unwrap().push(s));
+    }
+    event.invoke_with(build("heard"));
+    assert_eq!(built.load(Ordering::SeqCst), 1);
+    assert_eq!(*seen.lock().unwrap(), vec!["heard", "heard"]);
+}
+
+#[test]
+fn test_invoke_with_borrowed_accepts_owned_payload() {
+    let event: Event<str, Borrowed> = Event::builder().borrowed().build();
+    let seen = Arc::new(Mutex::new(String::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe(move |s: &str| seen_clone.lock().unwrap().push_str(s));
+
+    event.invoke_with(|| format!("{}-{}", "a", 1));
+    assert_eq!(*seen.lock().unwrap(), "a-1");
+}
+
+#[test]
+fn test_invoke_counted_returns_handlers_that_ran() {
+    let event = Event::<i32>::new();
+    assert_eq!(event.invoke_counted(1), 0);
+
+    let event_clone = event.clone();
+    let later = Arc::new(Mutex::new(None));
+    let later_clone = Arc::clone(&later);
+    event.subscribe(move |_| {
+        // Removes the next handler before it gets to run
+        if let Some(sub) = later_clone.lock().unwrap().take() {
+            event_clone.unsubscribe(sub);
+        }
+    });
+    *later.lock().unwrap() = Some(event.subscribe(|_| {}));
+    event.subscribe(|_| {});
+
+    assert_eq!(event.invoke_counted(2), 2);
+    assert_eq!(event.invoke_counted(3), 2);
+}
+
+#[test]
+fn test_with_capacity_and_shrink_to_fit_keep_subscriptions_valid() {
+    let event = Event::<i32>::with_capacity(64);
+    let calls = Arc::new(AtomicUsize::new(0));
+    let subs: Vec<_> = (0..64)
+        .map(|_| {
+            let c = Arc::clone(&calls);
+            event.subscribe(move |_| {
+                c.fetch_add(1, Ordering::SeqCst);
+            })
+        })
+        .collect();
+    for sub in &subs[1..] {
+        event.unsubscribe(*sub);
+    }
+    event.shrink_to_fit();
+
+    event.invoke(1);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // Stale subscriptions don't match handlers that reuse their slots after shrinking
+    let newer = event.subscribe(|_| {});
+    assert!(!event.unsubscribe(subs[1]));
+    assert!(event.unsubscribe(newer));
+    assert!(event.unsubscribe(subs[0]));
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_debug_shows_subscribers_without_handlers() {
+    #[derive(Debug)]
+    struct Player {
+        #[allow(dead_code)]
+        damaged: Event<u32>,
+    }
+
+    let player = Player { damaged: Event::new() };
+    let sub = player.damaged.subscribe_named("health_bar", |_| {});
+    player.damaged.subscribe(|_| {});
+
+    let debug = format!("{:?}", player);
+    assert!(debug.contains("subscribers: 2"), "{}", debug);
+    assert!(
+        debug.contains(&format!("({:?}, Some(\"health_bar\"))", sub)),
+        "{}",
+        debug
+    );
+    assert!(debug.contains("delivery: Immediate"), "{}", debug);
+
+    let pumped: Event<u32> = Event::builder().delivery_mode(blob_event::DeliveryMode::Pumped).build();
+    pumped.invoke(1);
+    pumped.invoke(2);
+    let debug = format!("{:?}", pumped);
+    assert!(debug.contains("delivery: Pumped, pending: 2"), "{}", debug);
+}
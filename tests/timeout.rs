@@ -0,0 +1,84 @@
+use blob_event::{Backpressure, DeliveryMode, Event, TimeoutAction};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+const LIMIT: Duration = Duration::from_millis(20);
+
+#[test]
+fn test_slow_queued_handler_is_reported() {
+    let event: Event<u64> = Event::builder()
+        .delivery_mode(DeliveryMode::Queued {
+            capacity: 4,
+            backpressure: Backpressure::Block,
+        })
+        .handler_timeout(LIMIT, TimeoutAction::Report)
+        .build();
+    let (reported, reports) = mpsc::channel();
+    event
+        .handler_timeouts()
+        .subscribe(move |sub| reported.send(sub).unwrap());
+    let slow = event.subscribe(|millis| thread::sleep(Duration::from_millis(millis)));
+
+    event.invoke(200);
+    assert_eq!(reports.recv_timeout(Duration::from_secs(5)).unwrap(), slow);
+    assert_eq!(event.subscriber_count(), 1);
+}
+
+#[test]
+fn test_stuck_detached_handler_is_unsubscribed() {
+    let event: Event<u64> = Event::builder()
+        .handler_timeout(LIMIT, TimeoutAction::Unsubscribe)
+        .build();
+    let (reported, reports) = mpsc::channel();
+    event
+        .handler_timeouts()
+        .subscribe(move |sub| reported.send(sub).unwrap());
+    let stuck = event.subscribe(|millis| thread::sleep(Duration::from_millis(millis)));
+
+    event.invoke_detached(200);
+    assert_eq!(reports.recv_timeout(Duration::from_secs(5)).unwrap(), stuck);
+    assert_eq!(event.subscriber_count(), 0);
+    event.flush();
+}
+
+#[test]
+fn test_fast_handlers_and_direct_invokes_are_not_watched() {
+    let event: Event<u64> = Event::builder()
+        .handler_timeout(LIMIT, TimeoutAction::Unsubscribe)
+        .build();
+    let (reported, reports) = mpsc::channel();
+    event
+        .handler_timeouts()
+        .subscribe(move |sub| reported.send(sub).unwrap());
+    event.subscribe(|millis| thread::sleep(Duration::from_millis(millis)));
+
+    event.invoke_detached(0);
+    event.flush();
+    // Only the queued and detached paths are watched
+    event.invoke(60);
+    assert!(reports.recv_timeout(Duration::from_millis(60)).is_err());
+    assert_eq!(event.subscriber_count(), 1);
+}
+
+#[test]
+fn test_slow_handler_reported_after_invoke() {
+    let event: Event<u64> = Event::builder().slow_handler_threshold(LIMIT).build();
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let reports_clone = Arc::clone(&reports);
+    event
+        .slow_handlers()
+        .subscribe(move |slow| reports_clone.lock().unwrap().push(slow));
+    event.subscribe(|_| {});
+    let slow = event.subscribe(|millis| thread::sleep(Duration::from_millis(millis)));
+
+    event.invoke(0);
+    assert!(reports.lock().unwrap().is_empty());
+
+    event.invoke(40);
+    let reports = reports.lock().unwrap();
+    assert_eq!(reports.len(), 1);
+    assert_eq!(reports[0].subscription, slow);
+    assert!(reports[0].elapsed >= Duration::from_millis(40));
+}
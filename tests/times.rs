@@ -0,0 +1,58 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+use blob_event::{Borrowed, Event};
+
+#[test]
+fn test_subscribe_times_runs_n_times() {
+    let event = Event::<u32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe_times(2, move |n| seen_clone.lock().unwrap().push(n));
+
+    event.invoke(1);
+    assert_eq!(event.subscriber_count(), 1);
+    event.invoke(2);
+    assert_eq!(event.subscriber_count(), 0);
+    event.invoke(3);
+
+    assert_eq!(*seen.lock().unwrap(), vec![1, 2]);
+}
+
+#[test]
+fn test_subscribe_times_zero_is_never_called() {
+    let event: Event<str, Borrowed> = Event::builder().borrowed().build();
+    event.subscribe_times(0, |_: &str| panic!("called"));
+
+    event.invoke("ignored");
+
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_subscribe_times_is_exact_across_threads() {
+    let event = Event::<u32>::new();
+    let calls = Arc::new(AtomicUsize::new(0));
+    let calls_clone = Arc::clone(&calls);
+    event.subscribe_times(50, move |_| {
+        calls_clone.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let threads: Vec<_> = (0..8)
+        .map(|_| {
+            let event = event.clone();
+            thread::spawn(move || {
+                for n in 0..100 {
+                    event.invoke(n);
+                }
+            })
+        })
+        .collect();
+    for thread in threads {
+        thread.join().unwrap();
+    }
+
+    assert_eq!(calls.load(Ordering::SeqCst), 50);
+    assert_eq!(event.subscriber_count(), 0);
+}
@@ -0,0 +1,83 @@
+#![cfg(feature = "tracing")]
+
+use blob_event::Event;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Metadata, Subscriber};
+
+// Records the names of new spans and the messages of events, in order.
+#[derive(Clone, Default)]
+struct Recorder {
+    seen: Arc<Mutex<Vec<String>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+struct Message(Option<String>);
+
+impl Visit for Message {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.0 = Some(format!("{:?}", value));
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        self.seen
+            .lock()
+            .unwrap()
+            .push(format!("span {}", span.metadata().name()));
+        Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &tracing::Event<'_>) {
+        let mut message = Message(None);
+        event.record(&mut message);
+        self.seen
+            .lock()
+            .unwrap()
+            .push(format!("event {}", message.0.unwrap_or_default()));
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn test_invoke_and_subscription_changes_are_traced() {
+    let recorder = Recorder::default();
+    let seen = Arc::clone(&recorder.seen);
+
+    tracing::subscriber::with_default(recorder, || {
+        let event = Event::<i32>::new();
+        let sub = event.subscribe(|_| {});
+        event.subscribe(|_| {});
+        event.invoke(1);
+        event.unsubscribe(sub);
+    });
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            "event subscribed",
+            "event subscribed",
+            "span invoke",
+            "span handler",
+            "span handler",
+            "event unsubscribed",
+        ]
+    );
+}
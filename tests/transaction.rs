@@ -0,0 +1,81 @@
+use blob_event::{Event, Transaction};
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn test_commit_dispatches_in_queue_order() {
+    let numbers = Event::<i32>::new();
+    let names = Event::<String>::new();
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    let log_clone = Arc::clone(&log);
+    numbers.subscribe(move |x| log_clone.lock().unwrap().push(format!("number {}", x)));
+    let log_clone = Arc::clone(&log);
+    names.subscribe(move |name| log_clone.lock().unwrap().push(format!("name {}", name)));
+
+    let mut tx = Transaction::new();
+    tx.invoke(&numbers, 1);
+    tx.invoke(&names, "alice".to_string());
+    tx.invoke(&numbers, 2);
+    assert_eq!(tx.len(), 3);
+
+    // Nothing is dispatched before commit
+    assert!(log.lock().unwrap().is_empty());
+
+    tx.commit();
+    assert_eq!(*log.lock().unwrap(), vec!["number 1", "name alice", "number 2"]);
+}
+
+#[test]
+fn test_rollback_discards_everything() {
+    let event = Event::<i32>::new();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    event.subscribe(move |x| received_clone.lock().unwrap().push(x));
+
+    let mut tx = Transaction::new();
+    tx.invoke(&event, 1);
+    tx.invoke(&event, 2);
+    tx.rollback();
+
+    event.invoke(3);
+    assert_eq!(*received.lock().unwrap(), vec![3]);
+}
+
+#[test]
+fn test_dropping_transaction_rolls_back() {
+    let event = Event::<i32>::new();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    event.subscribe(move |x| received_clone.lock().unwrap().push(x));
+
+    {
+        let mut tx = Transaction::new();
+        tx.invoke(&event, 1);
+        drop(tx);
+    }
+
+    assert!(received.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_run_commits_on_ok_and_rolls_back_on_err() {
+    let event = Event::<i32>::new();
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let received_clone = Arc::clone(&received);
+    event.subscribe(move |x| received_clone.lock().unwrap().push(x));
+
+    let failed: Result<(), &str> = Transaction::run(|tx| {
+        tx.invoke(&event, 1);
+        Err("state change failed")
+    });
+    assert!(failed.is_err());
+    assert!(received.lock().unwrap().is_empty());
+
+    let succeeded: Result<u32, &str> = Transaction::run(|tx| {
+        tx.invoke(&event, 2);
+        tx.invoke(&event, 3);
+        Ok(42)
+    });
+    assert_eq!(succeeded, Ok(42));
+    assert_eq!(*received.lock().unwrap(), vec![2, 3]);
+}
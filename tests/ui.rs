@@ -0,0 +1,63 @@
+use std::sync::mpsc::{self, Receiver};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, ThreadId};
+
+use blob_event::{Event, UiTask};
+
+fn run_tasks(tasks: &Receiver<UiTask>) -> usize {
+    tasks.try_iter().map(UiTask::run).count()
+}
+
+#[test]
+fn test_subscribe_on_runs_handler_on_ui_thread() {
+    let (ui, tasks) = mpsc::channel::<UiTask>();
+    let event = Event::<u32>::new();
+    let seen: Arc<Mutex<Vec<(u32, ThreadId)>>> = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    event.subscribe_on(ui, move |n| {
+        seen_clone.lock().unwrap().push((n, thread::current().id()))
+    });
+
+    let invoking = event.clone();
+    thread::spawn(move || {
+        invoking.invoke(1);
+        invoking.invoke(2);
+    })
+    .join()
+    .unwrap();
+    assert!(seen.lock().unwrap().is_empty());
+
+    assert_eq!(run_tasks(&tasks), 2);
+    let ui_thread = thread::current().id();
+    assert_eq!(*seen.lock().unwrap(), vec![(1, ui_thread), (2, ui_thread)]);
+}
+
+#[test]
+fn test_subscribe_on_skips_tasks_after_unsubscribe() {
+    let (ui, tasks) = mpsc::channel::<UiTask>();
+    let event = Event::<u32>::new();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let seen_clone = Arc::clone(&seen);
+    let id = event.subscribe_on(ui, move |n| seen_clone.lock().unwrap().push(n));
+
+    event.invoke(1);
+    assert!(event.unsubscribe(id));
+    event.invoke(2);
+
+    assert_eq!(run_tasks(&tasks), 1);
+    assert!(seen.lock().unwrap().is_empty());
+}
+
+#[test]
+fn test_subscribe_on_closed_loop_drops_invocations() {
+    let (ui, tasks) = mpsc::channel::<UiTask>();
+    let event = Event::<u32>::new();
+    let other = Arc::new(Mutex::new(0));
+    let other_clone = Arc::clone(&other);
+    event.subscribe_on(ui, |_| unreachable!());
+    event.subscribe(move |n| *other_clone.lock().unwrap() += n);
+    drop(tasks);
+
+    event.invoke(3);
+    assert_eq!(*other.lock().unwrap(), 3);
+}
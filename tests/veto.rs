@@ -0,0 +1,45 @@
+use std::sync::{Arc, Mutex};
+
+use blob_event::{Verdict, VetoEvent};
+
+#[test]
+fn test_veto_event_allows_when_every_handler_allows() {
+    let event = VetoEvent::<u32>::new();
+    assert!(event.invoke(1));
+
+    event.subscribe(|_| Verdict::Allow);
+    event.subscribe(|n| if *n < 10 { Verdict::Allow } else { Verdict::Deny });
+    assert!(event.invoke(5));
+    assert!(!event.invoke(50));
+}
+
+#[test]
+fn test_veto_event_stops_at_first_deny() {
+    let event = VetoEvent::<()>::new();
+    let asked = Arc::new(Mutex::new(Vec::new()));
+    for (name, verdict) in [
+        ("first", Verdict::Allow),
+        ("second", Verdict::Deny),
+        ("third", Verdict::Allow),
+    ] {
+        let asked_clone = Arc::clone(&asked);
+        event.subscribe(move |_| {
+            asked_clone.lock().unwrap().push(name);
+            verdict
+        });
+    }
+
+    assert!(!event.invoke(()));
+    assert_eq!(*asked.lock().unwrap(), vec!["first", "second"]);
+}
+
+#[test]
+fn test_veto_event_unsubscribe() {
+    let event = VetoEvent::<()>::new();
+    let id = event.clone().subscribe(|_| Verdict::Deny);
+    assert_eq!(event.subscriber_count(), 1);
+    assert!(!event.invoke(()));
+
+    assert!(event.unsubscribe(id));
+    assert!(event.invoke(()));
+}
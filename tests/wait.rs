@@ -0,0 +1,121 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use blob_event::{Borrowed, Event};
+
+#[test]
+fn test_wait_returns_the_next_invocation() {
+    let event = Event::<i32>::new();
+    let producer = event.clone();
+    let handle = thread::spawn(move || {
+        // Keeps invoking until the waiter has subscribed, so the test can't miss it.
+        while producer.subscriber_count() == 0 {
+            thread::yield_now();
+        }
+        producer.invoke(7);
+        producer.invoke(8);
+    });
+
+    assert_eq!(event.wait(), 7);
+    handle.join().unwrap();
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_wait_on_borrowed_event() {
+    let event: Event<String, Borrowed> = Event::builder().borrowed().build();
+    let producer = event.clone();
+    let handle = thread::spawn(move || {
+        while producer.subscriber_count() == 0 {
+            thread::yield_now();
+        }
+        producer.invoke(&"done".to_string());
+    });
+
+    assert_eq!(event.wait(), "done");
+    handle.join().unwrap();
+}
+
+#[test]
+fn test_wait_as_worker_handoff() {
+    let jobs = Event::<u32>::new();
+    let results = Event::<u32>::new();
+    let (worker_jobs, worker_results) = (jobs.clone(), results.clone());
+    let worker = thread::spawn(move || {
+        let job = worker_jobs.wait();
+        while worker_results.subscriber_count() == 0 {
+            thread::sleep(Duration::from_millis(1));
+        }
+        worker_results.invoke(job * 2);
+    });
+
+    while jobs.subscriber_count() == 0 {
+        thread::yield_now();
+    }
+    let (waiter, waiting) = (results.clone(), jobs.clone());
+    let collector = thread::spawn(move || waiter.wait());
+    waiting.invoke(21);
+    assert_eq!(collector.join().unwrap(), 42);
+    worker.join().unwrap();
+}
+
+#[test]
+fn test_wait_timeout_gives_up() {
+    let event = Event::<i32>::new();
+    assert_eq!(event.wait_timeout(Duration::from_millis(20)), None);
+    assert_eq!(event.subscriber_count(), 0);
+}
+
+#[test]
+fn test_wait_until_skips_other_invocations() {
+    let event = Event::<i32>::new();
+    let producer = event.clone();
+    let handle = thread::spawn(move || {
+        while producer.subscriber_count() == 0 {
+            thread::yield_now();
+        }
+        for n in 1..=5 {
+            producer.invoke(n);
+        }
+    });
+
+    assert_eq!(event.wait_until(|n| n % 2 == 0 && *n > 2), 4);
+    handle.join().unwrap();
+    assert_eq!(event.wait_until_timeout(|n| *n > 0, Duration::from_millis(10)), None);
+}
+
+#[test]
+fn test_wait_on_full_event_gives_up_at_once() {
+    let event = Event::<i32>::with_max_subscribers(1);
+    event.subscribe(|_| {});
+
+    let started = Instant::now();
+    assert_eq!(event.wait_timeout(Duration::from_secs(60)), None);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+#[should_panic(expected = "subscriber limit")]
+fn test_wait_on_full_event_panics() {
+    let event = Event::<i32>::with_max_subscribers(1);
+    event.subscribe(|_| {});
+    event.wait();
+}
+
+#[test]
+fn test_wait_until_on_full_event_gives_up_at_once() {
+    let event = Event::<i32>::with_max_subscribers(1);
+    event.subscribe(|_| {});
+
+    let started = Instant::now();
+    assert_eq!(event.wait_until_timeout(|n| *n > 0, Duration::from_secs(60)), None);
+    assert!(started.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+#[should_panic(expected = "subscriber limit")]
+fn test_wait_until_on_full_event_panics() {
+    let event = Event::<i32>::with_max_subscribers(1);
+    event.subscribe(|_| {});
+    event.wait_until(|n| *n > 0);
+}